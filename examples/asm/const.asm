@@ -0,0 +1,6 @@
+def main() -> (y:i4<2>, z:i4, w:i2<3>, v:bool) {
+    y:i4<2> = const[1, -2];
+    z:i4 = const[-3];
+    w:i2<3> = const[1];
+    v:bool = const[-1];
+}
@@ -0,0 +1,8 @@
+def main(a0:i16, a1:i16, a2:i16, b0:i16, b1:i16, b2:i16, c0:i16, c1:i16, c2:i16, d0:i16, d1:i16, d2:i16, m:i16, n:i16, en:bool) -> (y:i16, z:i16) {
+    y:i16 = dmuladdrega_i16i16(a2, b2, t1, en, en, en, en) @dsp(??, ??);
+    t1:i16 = dmuladdrega_i16i16(a1, b1, t0, en, en, en, en) @dsp(??, ??);
+    t0:i16 = dmuladdrega_i16i16(a0, b0, m, en, en, en, en) @dsp(??, ??);
+    z:i16 = dmuladdrega_i16i16(c2, d2, t3, en, en, en, en) @dsp(??, ??);
+    t3:i16 = dmuladdrega_i16i16(c1, d1, t2, en, en, en, en) @dsp(??, ??);
+    t2:i16 = dmuladdrega_i16i16(c0, d0, n, en, en, en, en) @dsp(??, ??);
+}
@@ -0,0 +1,8 @@
+def main(a0:i16, a1:i16, a2:i16, b0:i16, b1:i16, b2:i16, c0:i16, c1:i16, c2:i16, d0:i16, d1:i16, d2:i16, m:i16, n:i16, en:bool) -> (y:i16, z:i16) {
+    y:i16 = dmuladdregaci_i16i16(a2, b2, t1, en, en, en, en) @dsp(x0, y0+2);
+    t1:i16 = dmuladdregacio_i16i16(a1, b1, t0, en, en, en, en) @dsp(x0, y0+1);
+    t0:i16 = dmuladdregaco_i16i16(a0, b0, m, en, en, en, en) @dsp(x0, y0);
+    z:i16 = dmuladdregaci_i16i16(c2, d2, t3, en, en, en, en) @dsp(x1, y1+2);
+    t3:i16 = dmuladdregacio_i16i16(c1, d1, t2, en, en, en, en) @dsp(x1, y1+1);
+    t2:i16 = dmuladdregaco_i16i16(c0, d0, n, en, en, en, en) @dsp(x1, y1);
+}
@@ -0,0 +1,8 @@
+def main(a:i4, b:i4) -> (y:i8, z:i4, w:i4, v:i4, u:bool, s:i2) {
+    y:i8 = cat(a, b);
+    z:i4 = sll[1](a);
+    w:i4 = srl[2](b);
+    v:i4 = sra[3](b);
+    u:bool = ext[3](a);
+    s:i2 = ext[1](b);
+}
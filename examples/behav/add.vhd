@@ -0,0 +1,30 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity main is
+  port (
+    clock : in std_logic;
+    reset : in std_logic;
+    a : in std_logic_vector(7 downto 0);
+    b : in std_logic_vector(7 downto 0);
+    en : in std_logic;
+    y : out std_logic_vector(7 downto 0)
+  );
+end entity main;
+
+architecture rtl of main is
+  signal t0 : std_logic_vector(7 downto 0);
+begin
+  t0 <= std_logic_vector(signed(a) + signed(b));
+  process (clock)
+  begin
+    if rising_edge(clock) then
+      if reset = '1' then
+        y <= "00000000";
+      elsif en = '1' then
+        y <= t0;
+      end if;
+    end if;
+  end process;
+end architecture rtl;
\ No newline at end of file
@@ -0,0 +1,19 @@
+module main (
+    input wire clock,
+    input wire reset,
+    output wire [3:0] y_0,
+    output wire [3:0] y_1,
+    output wire [3:0] z,
+    output wire [1:0] w_0,
+    output wire [1:0] w_1,
+    output wire [1:0] w_2,
+    output wire v
+);
+    assign y_0 = 4'd1;
+    assign y_1 = 4'b1110;
+    assign z = 4'b1101;
+    assign w_0 = 2'd1;
+    assign w_1 = 2'd1;
+    assign w_2 = 2'd1;
+    assign v = 1'b1;
+endmodule
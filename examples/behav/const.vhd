@@ -0,0 +1,28 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity main is
+  port (
+    clock : in std_logic;
+    reset : in std_logic;
+    y_0 : out std_logic_vector(3 downto 0);
+    y_1 : out std_logic_vector(3 downto 0);
+    z : out std_logic_vector(3 downto 0);
+    w_0 : out std_logic_vector(1 downto 0);
+    w_1 : out std_logic_vector(1 downto 0);
+    w_2 : out std_logic_vector(1 downto 0);
+    v : out std_logic
+  );
+end entity main;
+
+architecture rtl of main is
+begin
+  y_0 <= "0001";
+  y_1 <= "1110";
+  z <= "1101";
+  w_0 <= "01";
+  w_1 <= "01";
+  w_2 <= "01";
+  v <= '1';
+end architecture rtl;
\ No newline at end of file
@@ -0,0 +1,61 @@
+module main (
+    input wire clock,
+    input wire reset,
+    input wire [7:0] a,
+    input wire [7:0] b,
+    input wire [7:0] c,
+    input wire [7:0] d,
+    input wire [7:0] e_0,
+    input wire [7:0] e_1,
+    output wire [7:0] y,
+    output wire [15:0] z,
+    output wire p,
+    output wire q,
+    output wire [3:0] r_0,
+    output wire [3:0] r_1
+);
+    wire [7:0] t0;
+    wire [7:0] t1;
+    wire [7:0] t2;
+    wire [7:0] t3;
+    wire [7:0] t4;
+    wire [7:0] t5;
+    wire [7:0] t6;
+    wire [3:0] t7;
+    wire [11:0] t8;
+    wire [15:0] t9;
+    wire t10;
+    wire t11;
+    wire t12;
+    wire t13;
+    wire t14;
+    wire t15;
+    wire [7:0] t16;
+    wire [3:0] t17_0;
+    wire [3:0] t17_1;
+    assign t0 = a - b;
+    assign t1 = t0 ^ 8'b11111111;
+    assign t2 = t1 | a;
+    assign t3 = t2 ^ b;
+    assign t4 = {t3[5:0], 2'd0};
+    assign t5 = {t4[7], t4[7], t4[7], t4[7:3]};
+    assign t6 = {1'd0, c[7:1]};
+    assign t7 = t6[5:2];
+    assign t8 = {d, t7};
+    assign t9 = {t7, t8};
+    assign t10 = $signed(a) > $signed(b);
+    assign t11 = c < d;
+    assign t12 = $signed(a) >= $signed(b);
+    assign t13 = c <= d;
+    assign t14 = t10 != t11;
+    assign t15 = t12 & t13;
+    assign t16 = t5;
+    assign t17_0 = e_0[7:4];
+    assign t17_1 = e_1[7:4];
+    assign y = t16;
+    assign z = t9;
+    assign p = t14;
+    assign q = t15;
+    assign r_0 = t17_0;
+    assign r_1 = t17_1;
+endmodule
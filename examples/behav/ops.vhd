@@ -0,0 +1,70 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity main is
+  port (
+    clock : in std_logic;
+    reset : in std_logic;
+    a : in std_logic_vector(7 downto 0);
+    b : in std_logic_vector(7 downto 0);
+    c : in std_logic_vector(7 downto 0);
+    d : in std_logic_vector(7 downto 0);
+    e_0 : in std_logic_vector(7 downto 0);
+    e_1 : in std_logic_vector(7 downto 0);
+    y : out std_logic_vector(7 downto 0);
+    z : out std_logic_vector(15 downto 0);
+    p : out std_logic;
+    q : out std_logic;
+    r_0 : out std_logic_vector(3 downto 0);
+    r_1 : out std_logic_vector(3 downto 0)
+  );
+end entity main;
+
+architecture rtl of main is
+  signal t0 : std_logic_vector(7 downto 0);
+  signal t1 : std_logic_vector(7 downto 0);
+  signal t2 : std_logic_vector(7 downto 0);
+  signal t3 : std_logic_vector(7 downto 0);
+  signal t4 : std_logic_vector(7 downto 0);
+  signal t5 : std_logic_vector(7 downto 0);
+  signal t6 : std_logic_vector(7 downto 0);
+  signal t7 : std_logic_vector(3 downto 0);
+  signal t8 : std_logic_vector(11 downto 0);
+  signal t9 : std_logic_vector(15 downto 0);
+  signal t10 : std_logic;
+  signal t11 : std_logic;
+  signal t12 : std_logic;
+  signal t13 : std_logic;
+  signal t14 : std_logic;
+  signal t15 : std_logic;
+  signal t16 : std_logic_vector(7 downto 0);
+  signal t17_0 : std_logic_vector(3 downto 0);
+  signal t17_1 : std_logic_vector(3 downto 0);
+begin
+  t0 <= std_logic_vector(signed(a) - signed(b));
+  t1 <= not t0;
+  t2 <= t1 or a;
+  t3 <= t2 xor b;
+  t4 <= std_logic_vector(shift_left(unsigned(t3), 2));
+  t5 <= std_logic_vector(shift_right(signed(t4), 3));
+  t6 <= std_logic_vector(shift_right(unsigned(c), 1));
+  t7 <= t6(5 downto 2);
+  t8 <= d & t7;
+  t9 <= t7 & t8;
+  t10 <= '1' when signed(a) > signed(b) else '0';
+  t11 <= '1' when unsigned(c) < unsigned(d) else '0';
+  t12 <= '1' when signed(a) >= signed(b) else '0';
+  t13 <= '1' when unsigned(c) <= unsigned(d) else '0';
+  t14 <= '1' when t10 /= t11 else '0';
+  t15 <= t12 and t13;
+  t16 <= std_logic_vector(shift_left(unsigned(t5), 0));
+  t17_0 <= e_0(7 downto 4);
+  t17_1 <= e_1(7 downto 4);
+  y <= t16;
+  z <= t9;
+  p <= t14;
+  q <= t15;
+  r_0 <= t17_0;
+  r_1 <= t17_1;
+end architecture rtl;
\ No newline at end of file
@@ -0,0 +1,24 @@
+module main (
+    input wire clock,
+    input wire reset,
+    input wire a,
+    input wire b,
+    input wire en,
+    output reg y,
+    output reg z
+);
+    always @(posedge clock or posedge reset) begin
+        if(reset) begin
+            y <= 0;
+        end else if(en) begin
+            y <= a;
+        end
+    end
+    always @(posedge clock or posedge reset) begin
+        if(reset) begin
+            z <= 1;
+        end else if(en) begin
+            z <= b;
+        end
+    end
+endmodule
@@ -0,0 +1,19 @@
+module main (
+    input wire clock,
+    input wire a,
+    input wire b,
+    input wire en,
+    output reg y,
+    output reg z
+);
+    always @(posedge clock) begin
+        if(en) begin
+            y <= a;
+        end
+    end
+    always @(posedge clock) begin
+        if(en) begin
+            z <= b;
+        end
+    end
+endmodule
@@ -0,0 +1,231 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+library unisim;
+use unisim.vcomponents.all;
+
+entity main is
+  port (
+    clock : in std_logic;
+    reset : in std_logic;
+    a : in std_logic_vector(7 downto 0);
+    b : in std_logic_vector(7 downto 0);
+    en : in std_logic;
+    y : out std_logic_vector(7 downto 0)
+  );
+end entity main;
+
+architecture rtl of main is
+  signal t16 : std_logic;
+  signal t17 : std_logic;
+  signal t18 : std_logic;
+  signal t19 : std_logic;
+  signal t20 : std_logic;
+  signal t21 : std_logic;
+  signal t22 : std_logic;
+  signal t23 : std_logic;
+  signal t25 : std_logic_vector(7 downto 0);
+begin
+  \__t16\ : LUT2
+    generic map (
+      INIT => "0110"
+    )
+    port map (
+      I0 => a(0),
+      I1 => b(0),
+      O => t16
+    );
+  \__t17\ : LUT2
+    generic map (
+      INIT => "0110"
+    )
+    port map (
+      I0 => a(1),
+      I1 => b(1),
+      O => t17
+    );
+  \__t18\ : LUT2
+    generic map (
+      INIT => "0110"
+    )
+    port map (
+      I0 => a(2),
+      I1 => b(2),
+      O => t18
+    );
+  \__t19\ : LUT2
+    generic map (
+      INIT => "0110"
+    )
+    port map (
+      I0 => a(3),
+      I1 => b(3),
+      O => t19
+    );
+  \__t20\ : LUT2
+    generic map (
+      INIT => "0110"
+    )
+    port map (
+      I0 => a(4),
+      I1 => b(4),
+      O => t20
+    );
+  \__t21\ : LUT2
+    generic map (
+      INIT => "0110"
+    )
+    port map (
+      I0 => a(5),
+      I1 => b(5),
+      O => t21
+    );
+  \__t22\ : LUT2
+    generic map (
+      INIT => "0110"
+    )
+    port map (
+      I0 => a(6),
+      I1 => b(6),
+      O => t22
+    );
+  \__t23\ : LUT2
+    generic map (
+      INIT => "0110"
+    )
+    port map (
+      I0 => a(7),
+      I1 => b(7),
+      O => t23
+    );
+  \__t25\ : CARRY8
+    generic map (
+      CARRY_TYPE => "SINGLE_CY8"
+    )
+    port map (
+      CI => '0',
+      CI_TOP => '0',
+      DI => a,
+      O => t25,
+      S(0) => t16,
+      S(1) => t17,
+      S(2) => t18,
+      S(3) => t19,
+      S(4) => t20,
+      S(5) => t21,
+      S(6) => t22,
+      S(7) => t23
+    );
+  \__t34\ : FDRE
+    generic map (
+      INIT => '0',
+      IS_C_INVERTED => '0',
+      IS_D_INVERTED => '0',
+      IS_R_INVERTED => '0'
+    )
+    port map (
+      C => clock,
+      CE => en,
+      D => t25(0),
+      Q => y(0),
+      R => reset
+    );
+  \__t35\ : FDRE
+    generic map (
+      INIT => '0',
+      IS_C_INVERTED => '0',
+      IS_D_INVERTED => '0',
+      IS_R_INVERTED => '0'
+    )
+    port map (
+      C => clock,
+      CE => en,
+      D => t25(1),
+      Q => y(1),
+      R => reset
+    );
+  \__t36\ : FDRE
+    generic map (
+      INIT => '0',
+      IS_C_INVERTED => '0',
+      IS_D_INVERTED => '0',
+      IS_R_INVERTED => '0'
+    )
+    port map (
+      C => clock,
+      CE => en,
+      D => t25(2),
+      Q => y(2),
+      R => reset
+    );
+  \__t37\ : FDRE
+    generic map (
+      INIT => '0',
+      IS_C_INVERTED => '0',
+      IS_D_INVERTED => '0',
+      IS_R_INVERTED => '0'
+    )
+    port map (
+      C => clock,
+      CE => en,
+      D => t25(3),
+      Q => y(3),
+      R => reset
+    );
+  \__t38\ : FDRE
+    generic map (
+      INIT => '0',
+      IS_C_INVERTED => '0',
+      IS_D_INVERTED => '0',
+      IS_R_INVERTED => '0'
+    )
+    port map (
+      C => clock,
+      CE => en,
+      D => t25(4),
+      Q => y(4),
+      R => reset
+    );
+  \__t39\ : FDRE
+    generic map (
+      INIT => '0',
+      IS_C_INVERTED => '0',
+      IS_D_INVERTED => '0',
+      IS_R_INVERTED => '0'
+    )
+    port map (
+      C => clock,
+      CE => en,
+      D => t25(5),
+      Q => y(5),
+      R => reset
+    );
+  \__t40\ : FDRE
+    generic map (
+      INIT => '0',
+      IS_C_INVERTED => '0',
+      IS_D_INVERTED => '0',
+      IS_R_INVERTED => '0'
+    )
+    port map (
+      C => clock,
+      CE => en,
+      D => t25(6),
+      Q => y(6),
+      R => reset
+    );
+  \__t41\ : FDRE
+    generic map (
+      INIT => '0',
+      IS_C_INVERTED => '0',
+      IS_D_INVERTED => '0',
+      IS_R_INVERTED => '0'
+    )
+    port map (
+      C => clock,
+      CE => en,
+      D => t25(7),
+      Q => y(7),
+      R => reset
+    );
+end architecture rtl;
\ No newline at end of file
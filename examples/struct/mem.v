@@ -0,0 +1,356 @@
+module main (
+    input wire clock,
+    input wire reset,
+    input wire [3:0] ra,
+    input wire [3:0] wa,
+    input wire [7:0] wd,
+    input wire we,
+    output wire [7:0] y,
+    output wire [7:0] z,
+    output wire [7:0] w
+);
+    wire gnd;
+    wire vcc;
+    wire [15:0] _z;
+    wire [1:0] _z_p;
+    wire [71:0] _w;
+    GND _gnd (
+        .G(gnd)
+    );
+    VCC _vcc (
+        .P(vcc)
+    );
+    RAM64M8 # (
+        .INIT_A(64'h0000000000000005),
+        .INIT_B(64'h0000000000000006),
+        .INIT_C(64'h0000000000000008),
+        .INIT_D(64'h0000000000000000),
+        .INIT_E(64'h0000000000000000),
+        .INIT_F(64'h0000000000000000),
+        .INIT_G(64'h0000000000000000),
+        .INIT_H(64'h0000000000000000),
+        .IS_WCLK_INVERTED(1'b0)
+    ) __y_0 (
+        .ADDRA({gnd, gnd, ra[3], ra[2], ra[1], ra[0]}),
+        .ADDRB({gnd, gnd, ra[3], ra[2], ra[1], ra[0]}),
+        .ADDRC({gnd, gnd, ra[3], ra[2], ra[1], ra[0]}),
+        .ADDRD({gnd, gnd, ra[3], ra[2], ra[1], ra[0]}),
+        .ADDRE({gnd, gnd, ra[3], ra[2], ra[1], ra[0]}),
+        .ADDRF({gnd, gnd, ra[3], ra[2], ra[1], ra[0]}),
+        .ADDRG({gnd, gnd, ra[3], ra[2], ra[1], ra[0]}),
+        .ADDRH({gnd, gnd, wa[3], wa[2], wa[1], wa[0]}),
+        .DIA(wd[0]),
+        .DIB(wd[1]),
+        .DIC(wd[2]),
+        .DID(wd[3]),
+        .DIE(wd[4]),
+        .DIF(wd[5]),
+        .DIG(wd[6]),
+        .DIH(gnd),
+        .DOA(y[0]),
+        .DOB(y[1]),
+        .DOC(y[2]),
+        .DOD(y[3]),
+        .DOE(y[4]),
+        .DOF(y[5]),
+        .DOG(y[6]),
+        .DOH(),
+        .WCLK(clock),
+        .WE(we)
+    );
+    RAM64M8 # (
+        .INIT_A(64'h0000000000000000),
+        .INIT_B(64'h0000000000000000),
+        .INIT_C(64'h0000000000000000),
+        .INIT_D(64'h0000000000000000),
+        .INIT_E(64'h0000000000000000),
+        .INIT_F(64'h0000000000000000),
+        .INIT_G(64'h0000000000000000),
+        .INIT_H(64'h0000000000000000),
+        .IS_WCLK_INVERTED(1'b0)
+    ) __y_1 (
+        .ADDRA({gnd, gnd, ra[3], ra[2], ra[1], ra[0]}),
+        .ADDRB({gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ADDRC({gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ADDRD({gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ADDRE({gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ADDRF({gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ADDRG({gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ADDRH({gnd, gnd, wa[3], wa[2], wa[1], wa[0]}),
+        .DIA(wd[7]),
+        .DIB(gnd),
+        .DIC(gnd),
+        .DID(gnd),
+        .DIE(gnd),
+        .DIF(gnd),
+        .DIG(gnd),
+        .DIH(gnd),
+        .DOA(y[7]),
+        .DOB(),
+        .DOC(),
+        .DOD(),
+        .DOE(),
+        .DOF(),
+        .DOG(),
+        .DOH(),
+        .WCLK(clock),
+        .WE(we)
+    );
+    (*LOC = "RAMB18_X0Y0"*)
+    RAMB18E2 # (
+        .CASCADE_ORDER_A("NONE"),
+        .CASCADE_ORDER_B("NONE"),
+        .CLOCK_DOMAINS("COMMON"),
+        .DOA_REG(0),
+        .DOB_REG(0),
+        .ENADDRENA("FALSE"),
+        .ENADDRENB("FALSE"),
+        .INITP_00(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INITP_01(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INITP_02(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INITP_03(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INITP_04(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INITP_05(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INITP_06(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INITP_07(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_00(256'h0000000000000000000000000000000000000000000000000000000004030201),
+        .INIT_01(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_02(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_03(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_04(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_05(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_06(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_07(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_08(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_09(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_0A(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_0B(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_0C(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_0D(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_0E(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_0F(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_10(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_11(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_12(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_13(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_14(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_15(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_16(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_17(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_18(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_19(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_1A(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_1B(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_1C(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_1D(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_1E(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_1F(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_20(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_21(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_22(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_23(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_24(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_25(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_26(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_27(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_28(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_29(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_2A(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_2B(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_2C(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_2D(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_2E(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_2F(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_30(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_31(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_32(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_33(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_34(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_35(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_36(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_37(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_38(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_39(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_3A(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_3B(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_3C(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_3D(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_3E(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_3F(256'h0000000000000000000000000000000000000000000000000000000000000000),
+        .INIT_A(18'h0),
+        .INIT_B(18'h0),
+        .INIT_FILE("NONE"),
+        .IS_CLKARDCLK_INVERTED(1'b0),
+        .IS_CLKBWRCLK_INVERTED(1'b0),
+        .IS_ENARDEN_INVERTED(1'b0),
+        .IS_ENBWREN_INVERTED(1'b0),
+        .IS_RSTRAMARSTRAM_INVERTED(1'b0),
+        .IS_RSTRAMB_INVERTED(1'b0),
+        .IS_RSTREGARSTREG_INVERTED(1'b0),
+        .IS_RSTREGB_INVERTED(1'b0),
+        .RDADDRCHANGEA("FALSE"),
+        .RDADDRCHANGEB("FALSE"),
+        .READ_WIDTH_A(9),
+        .READ_WIDTH_B(0),
+        .RSTREG_PRIORITY_A("RSTREG"),
+        .RSTREG_PRIORITY_B("RSTREG"),
+        .SIM_COLLISION_CHECK("ALL"),
+        .SLEEP_ASYNC("FALSE"),
+        .SRVAL_A(18'h0),
+        .SRVAL_B(18'h0),
+        .WRITE_MODE_A("READ_FIRST"),
+        .WRITE_MODE_B("READ_FIRST"),
+        .WRITE_WIDTH_A(0),
+        .WRITE_WIDTH_B(9)
+    ) __z (
+        .ADDRARDADDR({gnd, gnd, gnd, gnd, gnd, gnd, gnd, ra[3], ra[2], ra[1], ra[0], gnd, gnd, gnd}),
+        .ADDRBWRADDR({gnd, gnd, gnd, gnd, gnd, gnd, gnd, wa[3], wa[2], wa[1], wa[0], gnd, gnd, gnd}),
+        .ADDRENA(vcc),
+        .ADDRENB(vcc),
+        .CASDIMUXA(gnd),
+        .CASDIMUXB(gnd),
+        .CASDINA({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CASDINB({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CASDINPA({gnd, gnd}),
+        .CASDINPB({gnd, gnd}),
+        .CASDOMUXA(gnd),
+        .CASDOMUXB(gnd),
+        .CASDOMUXEN_A(gnd),
+        .CASDOMUXEN_B(gnd),
+        .CASDOUTA(),
+        .CASDOUTB(),
+        .CASDOUTPA(),
+        .CASDOUTPB(),
+        .CASOREGIMUXA(gnd),
+        .CASOREGIMUXB(gnd),
+        .CASOREGIMUXEN_A(gnd),
+        .CASOREGIMUXEN_B(gnd),
+        .CLKARDCLK(clock),
+        .CLKBWRCLK(clock),
+        .DINADIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .DINBDIN({wd[7], wd[6], wd[5], wd[4], wd[3], wd[2], wd[1], wd[0]}),
+        .DINPADINP({gnd, gnd}),
+        .DINPBDINP({gnd}),
+        .DOUTADOUT(_z),
+        .DOUTBDOUT(),
+        .DOUTPADOUTP(_z_p),
+        .DOUTPBDOUTP(),
+        .ENARDEN(vcc),
+        .ENBWREN(vcc),
+        .REGCEAREGCE(gnd),
+        .REGCEB(gnd),
+        .RSTRAMARSTRAM(reset),
+        .RSTRAMB(reset),
+        .RSTREGARSTREG(reset),
+        .RSTREGB(reset),
+        .SLEEP(gnd),
+        .WEA({gnd, gnd}),
+        .WEBWE({gnd, gnd, we, we})
+    );
+    assign z = {_z[7], _z[6], _z[5], _z[4], _z[3], _z[2], _z[1], _z[0]};
+    (*LOC = "URAM288_X0Y0"*)
+    URAM288 # (
+        .AUTO_SLEEP_LATENCY(8),
+        .AVG_CONS_INACTIVE_CYCLES(10),
+        .BWE_MODE_A("PARITY_INTERLEAVED"),
+        .BWE_MODE_B("PARITY_INTERLEAVED"),
+        .CASCADE_ORDER_A("NONE"),
+        .CASCADE_ORDER_B("NONE"),
+        .EN_AUTO_SLEEP_MODE("FALSE"),
+        .EN_ECC_RD_A("FALSE"),
+        .EN_ECC_RD_B("FALSE"),
+        .EN_ECC_WR_A("FALSE"),
+        .EN_ECC_WR_B("FALSE"),
+        .IREG_PRE_A("FALSE"),
+        .IREG_PRE_B("FALSE"),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_EN_A_INVERTED(1'b0),
+        .IS_EN_B_INVERTED(1'b0),
+        .IS_RDB_WR_A_INVERTED(1'b0),
+        .IS_RDB_WR_B_INVERTED(1'b0),
+        .IS_RST_A_INVERTED(1'b0),
+        .IS_RST_B_INVERTED(1'b0),
+        .OREG_A("FALSE"),
+        .OREG_B("FALSE"),
+        .OREG_ECC_A("FALSE"),
+        .OREG_ECC_B("FALSE"),
+        .REG_CAS_A("FALSE"),
+        .REG_CAS_B("FALSE"),
+        .RST_MODE_A("SYNC"),
+        .RST_MODE_B("SYNC"),
+        .SELF_ADDR_A(11'h0),
+        .SELF_ADDR_B(11'h0),
+        .SELF_MASK_A(11'h7ff),
+        .SELF_MASK_B(11'h7ff),
+        .USE_EXT_CE_A("FALSE"),
+        .USE_EXT_CE_B("FALSE")
+    ) __w (
+        .ADDR_A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, ra[3], ra[2], ra[1], ra[0]}),
+        .ADDR_B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, wa[3], wa[2], wa[1], wa[0]}),
+        .BWE_A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BWE_B({vcc, vcc, vcc, vcc, vcc, vcc, vcc, vcc, vcc}),
+        .CAS_IN_ADDR_A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CAS_IN_ADDR_B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CAS_IN_BWE_A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CAS_IN_BWE_B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CAS_IN_DBITERR_A(gnd),
+        .CAS_IN_DBITERR_B(gnd),
+        .CAS_IN_DIN_A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CAS_IN_DIN_B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CAS_IN_DOUT_A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CAS_IN_DOUT_B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CAS_IN_EN_A(gnd),
+        .CAS_IN_EN_B(gnd),
+        .CAS_IN_RDACCESS_A(gnd),
+        .CAS_IN_RDACCESS_B(gnd),
+        .CAS_IN_RDB_WR_A(gnd),
+        .CAS_IN_RDB_WR_B(gnd),
+        .CAS_IN_SBITERR_A(gnd),
+        .CAS_IN_SBITERR_B(gnd),
+        .CAS_OUT_ADDR_A(),
+        .CAS_OUT_ADDR_B(),
+        .CAS_OUT_BWE_A(),
+        .CAS_OUT_BWE_B(),
+        .CAS_OUT_DBITERR_A(),
+        .CAS_OUT_DBITERR_B(),
+        .CAS_OUT_DIN_A(),
+        .CAS_OUT_DIN_B(),
+        .CAS_OUT_DOUT_A(),
+        .CAS_OUT_DOUT_B(),
+        .CAS_OUT_EN_A(),
+        .CAS_OUT_EN_B(),
+        .CAS_OUT_RDACCESS_A(),
+        .CAS_OUT_RDACCESS_B(),
+        .CAS_OUT_RDB_WR_A(),
+        .CAS_OUT_RDB_WR_B(),
+        .CAS_OUT_SBITERR_A(),
+        .CAS_OUT_SBITERR_B(),
+        .CLK(clock),
+        .DBITERR_A(),
+        .DBITERR_B(),
+        .DIN_A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .DIN_B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, wd[7], wd[6], wd[5], wd[4], wd[3], wd[2], wd[1], wd[0]}),
+        .DOUT_A(_w),
+        .DOUT_B(),
+        .EN_A(vcc),
+        .EN_B(we),
+        .INJECT_DBITERR_A(gnd),
+        .INJECT_DBITERR_B(gnd),
+        .INJECT_SBITERR_A(gnd),
+        .INJECT_SBITERR_B(gnd),
+        .OREG_CE_A(gnd),
+        .OREG_CE_B(gnd),
+        .OREG_ECC_CE_A(gnd),
+        .OREG_ECC_CE_B(gnd),
+        .RDACCESS_A(),
+        .RDACCESS_B(),
+        .RDB_WR_A(gnd),
+        .RDB_WR_B(vcc),
+        .RST_A(reset),
+        .RST_B(reset),
+        .SBITERR_A(),
+        .SBITERR_B(),
+        .SLEEP(gnd)
+    );
+    assign w = {_w[7], _w[6], _w[5], _w[4], _w[3], _w[2], _w[1], _w[0]};
+endmodule
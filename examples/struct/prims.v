@@ -0,0 +1,762 @@
+module main (
+    input wire clock,
+    input wire reset,
+    input wire [7:0] a,
+    input wire [7:0] b,
+    input wire [7:0] c_0,
+    input wire [7:0] c_1,
+    input wire [7:0] c_2,
+    input wire [7:0] c_3,
+    input wire [7:0] d_0,
+    input wire [7:0] d_1,
+    input wire [7:0] d_2,
+    input wire [7:0] d_3,
+    input wire [7:0] f_0,
+    input wire [7:0] g_0,
+    input wire [7:0] h,
+    input wire e,
+    input wire en,
+    output wire [7:0] y,
+    output wire [7:0] z_0,
+    output wire [7:0] z_1,
+    output wire [7:0] z_2,
+    output wire [7:0] z_3,
+    output wire [7:0] u_0,
+    output wire [7:0] u_1,
+    output wire [7:0] u_2,
+    output wire [7:0] u_3,
+    output wire [7:0] m,
+    output wire [7:0] n,
+    output wire [7:0] v_0,
+    output wire o,
+    output wire [7:0] s
+);
+    wire gnd;
+    wire vcc;
+    wire t0;
+    wire t1;
+    wire t2;
+    wire t3;
+    wire t4;
+    wire t5;
+    wire t6;
+    wire t7;
+    wire t8;
+    wire t9;
+    wire t10;
+    wire t11;
+    wire t12;
+    wire t13;
+    wire t14;
+    wire t15;
+    wire t16;
+    wire t17;
+    wire t18;
+    wire t19;
+    wire t20;
+    wire t21;
+    wire t22;
+    wire t23;
+    wire [7:0] t24;
+    wire [47:0] _z;
+    wire [47:0] _u;
+    wire [47:0] _m;
+    wire [47:0] _n;
+    wire [47:0] _v;
+    wire [47:0] _s;
+    GND _gnd (
+        .G(gnd)
+    );
+    VCC _vcc (
+        .P(vcc)
+    );
+    assign t0 = a[0];
+    assign t1 = a[1];
+    assign t2 = a[2];
+    assign t3 = a[3];
+    assign t4 = a[4];
+    assign t5 = a[5];
+    assign t6 = a[6];
+    assign t7 = a[7];
+    assign t8 = b[0];
+    assign t9 = b[1];
+    assign t10 = b[2];
+    assign t11 = b[3];
+    assign t12 = b[4];
+    assign t13 = b[5];
+    assign t14 = b[6];
+    assign t15 = b[7];
+    LUT2 # (
+        .INIT(4'h9)
+    ) __t16 (
+        .I0(t0),
+        .I1(t8),
+        .O(t16)
+    );
+    LUT2 # (
+        .INIT(4'h9)
+    ) __t17 (
+        .I0(t1),
+        .I1(t9),
+        .O(t17)
+    );
+    LUT2 # (
+        .INIT(4'h9)
+    ) __t18 (
+        .I0(t2),
+        .I1(t10),
+        .O(t18)
+    );
+    LUT2 # (
+        .INIT(4'h9)
+    ) __t19 (
+        .I0(t3),
+        .I1(t11),
+        .O(t19)
+    );
+    LUT2 # (
+        .INIT(4'h9)
+    ) __t20 (
+        .I0(t4),
+        .I1(t12),
+        .O(t20)
+    );
+    LUT2 # (
+        .INIT(4'h9)
+    ) __t21 (
+        .I0(t5),
+        .I1(t13),
+        .O(t21)
+    );
+    LUT2 # (
+        .INIT(4'h9)
+    ) __t22 (
+        .I0(t6),
+        .I1(t14),
+        .O(t22)
+    );
+    LUT2 # (
+        .INIT(4'h9)
+    ) __t23 (
+        .I0(t7),
+        .I1(t15),
+        .O(t23)
+    );
+    assign t24 = {t23, t22, t21, t20, t19, t18, t17, t16};
+    CARRY8 # (
+        .CARRY_TYPE("SINGLE_CY8")
+    ) __y (
+        .CI(1'b1),
+        .CI_TOP(1'b0),
+        .CO(),
+        .DI(a),
+        .O(y),
+        .S(t24)
+    );
+    DSP48E2 # (
+        .ACASCREG(0),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(0),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(0),
+        .BMULTSEL("B"),
+        .BREG(0),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(0),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(0),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("NONE"),
+        .USE_SIMD("FOUR12"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __z (
+        .A({gnd, gnd, gnd, gnd, d_3[7], d_3[6], d_3[5], d_3[4], d_3[3], d_3[2], d_3[1], d_3[0], gnd, gnd, gnd, gnd, d_2[7], d_2[6], d_2[5], d_2[4], d_2[3], d_2[2], d_2[1], d_2[0], gnd, gnd, gnd, gnd, d_1[7], d_1[6]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, vcc, vcc}),
+        .B({d_1[5], d_1[4], d_1[3], d_1[2], d_1[1], d_1[0], gnd, gnd, gnd, gnd, d_0[7], d_0[6], d_0[5], d_0[4], d_0[3], d_0[2], d_0[1], d_0[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, c_3[7], c_3[6], c_3[5], c_3[4], c_3[3], c_3[2], c_3[1], c_3[0], gnd, gnd, gnd, gnd, c_2[7], c_2[6], c_2[5], c_2[4], c_2[3], c_2[2], c_2[1], c_2[0], gnd, gnd, gnd, gnd, c_1[7], c_1[6], c_1[5], c_1[4], c_1[3], c_1[2], c_1[1], c_1[0], gnd, gnd, gnd, gnd, c_0[7], c_0[6], c_0[5], c_0[4], c_0[3], c_0[2], c_0[1], c_0[0]}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(gnd),
+        .CEA2(gnd),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(gnd),
+        .CEB2(gnd),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(gnd),
+        .CEP(gnd),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, vcc, vcc, gnd, gnd, vcc, vcc}),
+        .OVERFLOW(),
+        .P(_z),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .PCOUT(),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign z_0 = _z[7:0];
+    assign z_1 = _z[19:12];
+    assign z_2 = _z[31:24];
+    assign z_3 = _z[43:36];
+    DSP48E2 # (
+        .ACASCREG(0),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(0),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(0),
+        .BMULTSEL("B"),
+        .BREG(0),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(0),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(0),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("NONE"),
+        .USE_SIMD("FOUR12"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __u (
+        .A({gnd, gnd, gnd, gnd, d_3[7], d_3[6], d_3[5], d_3[4], d_3[3], d_3[2], d_3[1], d_3[0], gnd, gnd, gnd, gnd, d_2[7], d_2[6], d_2[5], d_2[4], d_2[3], d_2[2], d_2[1], d_2[0], gnd, gnd, gnd, gnd, d_1[7], d_1[6]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({d_1[5], d_1[4], d_1[3], d_1[2], d_1[1], d_1[0], gnd, gnd, gnd, gnd, d_0[7], d_0[6], d_0[5], d_0[4], d_0[3], d_0[2], d_0[1], d_0[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, c_3[7], c_3[6], c_3[5], c_3[4], c_3[3], c_3[2], c_3[1], c_3[0], gnd, gnd, gnd, gnd, c_2[7], c_2[6], c_2[5], c_2[4], c_2[3], c_2[2], c_2[1], c_2[0], gnd, gnd, gnd, gnd, c_1[7], c_1[6], c_1[5], c_1[4], c_1[3], c_1[2], c_1[1], c_1[0], gnd, gnd, gnd, gnd, c_0[7], c_0[6], c_0[5], c_0[4], c_0[3], c_0[2], c_0[1], c_0[0]}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(gnd),
+        .CEA2(gnd),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(gnd),
+        .CEB2(gnd),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(gnd),
+        .CEP(gnd),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, vcc, vcc, gnd, gnd, vcc, vcc}),
+        .OVERFLOW(),
+        .P(_u),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .PCOUT(),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign u_0 = _u[7:0];
+    assign u_1 = _u[19:12];
+    assign u_2 = _u[31:24];
+    assign u_3 = _u[43:36];
+    DSP48E2 # (
+        .ACASCREG(0),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(0),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(0),
+        .BMULTSEL("B"),
+        .BREG(0),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(0),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(0),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __m (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, a[7], a[6], a[5], a[4], a[3], a[2], a[1], a[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, b[7], b[6], b[5], b[4], b[3], b[2], b[1], b[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(gnd),
+        .CEA2(gnd),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(gnd),
+        .CEB2(gnd),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(gnd),
+        .CEP(gnd),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, gnd, gnd, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_m),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .PCOUT(),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign m = _m[7:0];
+    DSP48E2 # (
+        .ACASCREG(0),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(0),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(0),
+        .BMULTSEL("B"),
+        .BREG(0),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(0),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(0),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __n (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, a[7], a[6], a[5], a[4], a[3], a[2], a[1], a[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, b[7], b[6], b[5], b[4], b[3], b[2], b[1], b[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, a[7], a[6], a[5], a[4], a[3], a[2], a[1], a[0]}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(gnd),
+        .CEA2(gnd),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(gnd),
+        .CEB2(gnd),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(gnd),
+        .CEP(gnd),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, vcc, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_n),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .PCOUT(),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign n = _n[7:0];
+    DSP48E2 # (
+        .ACASCREG(0),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(0),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(0),
+        .BMULTSEL("B"),
+        .BREG(0),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(0),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(0),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __v (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, f_0[7], f_0[6], f_0[5], f_0[4], f_0[3], f_0[2], f_0[1], f_0[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, g_0[7], g_0[6], g_0[5], g_0[4], g_0[3], g_0[2], g_0[1], g_0[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(gnd),
+        .CEA2(gnd),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(gnd),
+        .CEB2(gnd),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(gnd),
+        .CEP(gnd),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, gnd, gnd, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_v),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .PCOUT(),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign v_0 = _v[7:0];
+    LUT1 # (
+        .INIT(2'h1)
+    ) __o (
+        .I0(e),
+        .O(o)
+    );
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __s (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, a[7], a[6], a[5], a[4], a[3], a[2], a[1], a[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, h[7], h[6], h[5], h[4], h[3], h[2], h[1], h[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, b[7], b[6], b[5], b[4], b[3], b[2], b[1], b[0]}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, vcc, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_s),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .PCOUT(),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign s = _s[7:0];
+endmodule
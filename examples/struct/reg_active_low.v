@@ -0,0 +1,42 @@
+module main (
+    input wire clock,
+    input wire reset,
+    input wire a,
+    input wire b,
+    input wire en,
+    output wire y,
+    output wire z
+);
+    wire gnd;
+    wire vcc;
+    GND _gnd (
+        .G(gnd)
+    );
+    VCC _vcc (
+        .P(vcc)
+    );
+    FDRE # (
+        .INIT(1'b0),
+        .IS_C_INVERTED(1'b0),
+        .IS_D_INVERTED(1'b0),
+        .IS_R_INVERTED(1'b1)
+    ) __y (
+        .C(clock),
+        .CE(en),
+        .D(a),
+        .Q(y),
+        .R(reset)
+    );
+    FDSE # (
+        .INIT(1'b0),
+        .IS_C_INVERTED(1'b0),
+        .IS_D_INVERTED(1'b0),
+        .IS_S_INVERTED(1'b1)
+    ) __z (
+        .C(clock),
+        .CE(en),
+        .D(b),
+        .Q(z),
+        .S(reset)
+    );
+endmodule
@@ -0,0 +1,42 @@
+module main (
+    input wire clock,
+    input wire reset,
+    input wire a,
+    input wire b,
+    input wire en,
+    output wire y,
+    output wire z
+);
+    wire gnd;
+    wire vcc;
+    GND _gnd (
+        .G(gnd)
+    );
+    VCC _vcc (
+        .P(vcc)
+    );
+    FDCE # (
+        .INIT(1'b0),
+        .IS_CLR_INVERTED(1'b0),
+        .IS_C_INVERTED(1'b0),
+        .IS_D_INVERTED(1'b0)
+    ) __y (
+        .C(clock),
+        .CE(en),
+        .CLR(reset),
+        .D(a),
+        .Q(y)
+    );
+    FDPE # (
+        .INIT(1'b0),
+        .IS_C_INVERTED(1'b0),
+        .IS_D_INVERTED(1'b0),
+        .IS_PRE_INVERTED(1'b0)
+    ) __z (
+        .C(clock),
+        .CE(en),
+        .D(b),
+        .PRE(reset),
+        .Q(z)
+    );
+endmodule
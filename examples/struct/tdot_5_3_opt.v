@@ -0,0 +1,1574 @@
+module main (
+    input wire clock,
+    input wire reset,
+    input wire [7:0] a0,
+    input wire [7:0] c0,
+    input wire [7:0] e0,
+    input wire [7:0] g0,
+    input wire [7:0] i0,
+    input wire [7:0] a1,
+    input wire [7:0] c1,
+    input wire [7:0] e1,
+    input wire [7:0] g1,
+    input wire [7:0] i1,
+    input wire [7:0] a2,
+    input wire [7:0] c2,
+    input wire [7:0] e2,
+    input wire [7:0] g2,
+    input wire [7:0] i2,
+    input wire [7:0] b0,
+    input wire [7:0] d0,
+    input wire [7:0] f0,
+    input wire [7:0] h0,
+    input wire [7:0] j0,
+    input wire [7:0] b1,
+    input wire [7:0] d1,
+    input wire [7:0] f1,
+    input wire [7:0] h1,
+    input wire [7:0] j1,
+    input wire [7:0] b2,
+    input wire [7:0] d2,
+    input wire [7:0] f2,
+    input wire [7:0] h2,
+    input wire [7:0] j2,
+    input wire [7:0] m,
+    input wire [7:0] n,
+    input wire [7:0] o,
+    input wire [7:0] p,
+    input wire [7:0] q,
+    input wire en,
+    output wire [7:0] v,
+    output wire [7:0] w,
+    output wire [7:0] x,
+    output wire [7:0] y,
+    output wire [7:0] z
+);
+    wire gnd;
+    wire vcc;
+    wire [47:0] _v;
+    wire [7:0] t0;
+    wire [47:0] _t0;
+    wire [47:0] _t0_pcout;
+    wire [7:0] t1;
+    wire [47:0] _t1;
+    wire [47:0] _t1_pcout;
+    wire [47:0] _w;
+    wire [7:0] t2;
+    wire [47:0] _t2;
+    wire [47:0] _t2_pcout;
+    wire [7:0] t3;
+    wire [47:0] _t3;
+    wire [47:0] _t3_pcout;
+    wire [47:0] _x;
+    wire [7:0] t4;
+    wire [47:0] _t4;
+    wire [47:0] _t4_pcout;
+    wire [7:0] t5;
+    wire [47:0] _t5;
+    wire [47:0] _t5_pcout;
+    wire [47:0] _y;
+    wire [7:0] t6;
+    wire [47:0] _t6;
+    wire [47:0] _t6_pcout;
+    wire [7:0] t7;
+    wire [47:0] _t7;
+    wire [47:0] _t7_pcout;
+    wire [47:0] _z;
+    wire [7:0] t8;
+    wire [47:0] _t8;
+    wire [47:0] _t8_pcout;
+    wire [7:0] t9;
+    wire [47:0] _t9;
+    wire [47:0] _t9_pcout;
+    GND _gnd (
+        .G(gnd)
+    );
+    VCC _vcc (
+        .P(vcc)
+    );
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __v (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, a2[7], a2[6], a2[5], a2[4], a2[3], a2[2], a2[1], a2[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, b2[7], b2[6], b2[5], b2[4], b2[3], b2[2], b2[1], b2[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, gnd, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_v),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN(_t0_pcout),
+        .PCOUT(),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign v = _v[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __t0 (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, a1[7], a1[6], a1[5], a1[4], a1[3], a1[2], a1[1], a1[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, b1[7], b1[6], b1[5], b1[4], b1[3], b1[2], b1[1], b1[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, gnd, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_t0),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN(_t1_pcout),
+        .PCOUT(_t0_pcout),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign t0 = _t0[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __t1 (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, a0[7], a0[6], a0[5], a0[4], a0[3], a0[2], a0[1], a0[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, b0[7], b0[6], b0[5], b0[4], b0[3], b0[2], b0[1], b0[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, m[7], m[6], m[5], m[4], m[3], m[2], m[1], m[0]}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, vcc, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_t1),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .PCOUT(_t1_pcout),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign t1 = _t1[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __w (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, c2[7], c2[6], c2[5], c2[4], c2[3], c2[2], c2[1], c2[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, d2[7], d2[6], d2[5], d2[4], d2[3], d2[2], d2[1], d2[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, gnd, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_w),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN(_t2_pcout),
+        .PCOUT(),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign w = _w[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __t2 (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, c1[7], c1[6], c1[5], c1[4], c1[3], c1[2], c1[1], c1[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, d1[7], d1[6], d1[5], d1[4], d1[3], d1[2], d1[1], d1[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, gnd, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_t2),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN(_t3_pcout),
+        .PCOUT(_t2_pcout),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign t2 = _t2[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __t3 (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, c0[7], c0[6], c0[5], c0[4], c0[3], c0[2], c0[1], c0[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, d0[7], d0[6], d0[5], d0[4], d0[3], d0[2], d0[1], d0[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, n[7], n[6], n[5], n[4], n[3], n[2], n[1], n[0]}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, vcc, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_t3),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .PCOUT(_t3_pcout),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign t3 = _t3[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __x (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, e2[7], e2[6], e2[5], e2[4], e2[3], e2[2], e2[1], e2[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, f2[7], f2[6], f2[5], f2[4], f2[3], f2[2], f2[1], f2[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, gnd, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_x),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN(_t4_pcout),
+        .PCOUT(),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign x = _x[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __t4 (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, e1[7], e1[6], e1[5], e1[4], e1[3], e1[2], e1[1], e1[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, f1[7], f1[6], f1[5], f1[4], f1[3], f1[2], f1[1], f1[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, gnd, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_t4),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN(_t5_pcout),
+        .PCOUT(_t4_pcout),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign t4 = _t4[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __t5 (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, e0[7], e0[6], e0[5], e0[4], e0[3], e0[2], e0[1], e0[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, f0[7], f0[6], f0[5], f0[4], f0[3], f0[2], f0[1], f0[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, o[7], o[6], o[5], o[4], o[3], o[2], o[1], o[0]}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, vcc, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_t5),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .PCOUT(_t5_pcout),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign t5 = _t5[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __y (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, g2[7], g2[6], g2[5], g2[4], g2[3], g2[2], g2[1], g2[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, h2[7], h2[6], h2[5], h2[4], h2[3], h2[2], h2[1], h2[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, gnd, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_y),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN(_t6_pcout),
+        .PCOUT(),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign y = _y[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __t6 (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, g1[7], g1[6], g1[5], g1[4], g1[3], g1[2], g1[1], g1[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, h1[7], h1[6], h1[5], h1[4], h1[3], h1[2], h1[1], h1[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, gnd, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_t6),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN(_t7_pcout),
+        .PCOUT(_t6_pcout),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign t6 = _t6[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __t7 (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, g0[7], g0[6], g0[5], g0[4], g0[3], g0[2], g0[1], g0[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, h0[7], h0[6], h0[5], h0[4], h0[3], h0[2], h0[1], h0[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, p[7], p[6], p[5], p[4], p[3], p[2], p[1], p[0]}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, vcc, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_t7),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .PCOUT(_t7_pcout),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign t7 = _t7[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __z (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, i2[7], i2[6], i2[5], i2[4], i2[3], i2[2], i2[1], i2[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, j2[7], j2[6], j2[5], j2[4], j2[3], j2[2], j2[1], j2[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, gnd, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_z),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN(_t8_pcout),
+        .PCOUT(),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign z = _z[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __t8 (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, i1[7], i1[6], i1[5], i1[4], i1[3], i1[2], i1[1], i1[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, j1[7], j1[6], j1[5], j1[4], j1[3], j1[2], j1[1], j1[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, gnd, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_t8),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN(_t9_pcout),
+        .PCOUT(_t8_pcout),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign t8 = _t8[7:0];
+    DSP48E2 # (
+        .ACASCREG(1),
+        .ADREG(0),
+        .ALUMODEREG(0),
+        .AMULTSEL("A"),
+        .AREG(1),
+        .AUTORESET_PATDET("NO_RESET"),
+        .AUTORESET_PRIORITY("RESET"),
+        .A_INPUT("DIRECT"),
+        .BCASCREG(1),
+        .BMULTSEL("B"),
+        .BREG(1),
+        .B_INPUT("DIRECT"),
+        .CARRYINREG(0),
+        .CARRYINSELREG(0),
+        .CREG(0),
+        .DREG(0),
+        .INMODEREG(0),
+        .IS_ALUMODE_INVERTED(4'h0),
+        .IS_CARRYIN_INVERTED(1'b0),
+        .IS_CLK_INVERTED(1'b0),
+        .IS_INMODE_INVERTED(5'h0),
+        .IS_OPMODE_INVERTED(9'h0),
+        .IS_RSTALLCARRYIN_INVERTED(1'b0),
+        .IS_RSTALUMODE_INVERTED(1'b0),
+        .IS_RSTA_INVERTED(1'b0),
+        .IS_RSTB_INVERTED(1'b0),
+        .IS_RSTCTRL_INVERTED(1'b0),
+        .IS_RSTC_INVERTED(1'b0),
+        .IS_RSTD_INVERTED(1'b0),
+        .IS_RSTINMODE_INVERTED(1'b0),
+        .IS_RSTM_INVERTED(1'b0),
+        .IS_RSTP_INVERTED(1'b0),
+        .MASK(48'h3fffffffffff),
+        .MREG(1),
+        .OPMODEREG(0),
+        .PATTERN(48'h0),
+        .PREADDINSEL("A"),
+        .PREG(1),
+        .RND(48'h0),
+        .SEL_MASK("MASK"),
+        .SEL_PATTERN("PATTERN"),
+        .USE_MULT("MULTIPLY"),
+        .USE_SIMD("ONE48"),
+        .USE_WIDEXOR("FALSE"),
+        .XORSIMD("XOR24_48_96")
+    ) __t9 (
+        .A({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, i0[7], i0[6], i0[5], i0[4], i0[3], i0[2], i0[1], i0[0]}),
+        .ACIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .ACOUT(),
+        .ALUMODE({gnd, gnd, gnd, gnd}),
+        .B({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, j0[7], j0[6], j0[5], j0[4], j0[3], j0[2], j0[1], j0[0]}),
+        .BCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .BCOUT(),
+        .C({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, q[7], q[6], q[5], q[4], q[3], q[2], q[1], q[0]}),
+        .CARRYCASCIN(gnd),
+        .CARRYCASCOUT(),
+        .CARRYIN(gnd),
+        .CARRYINSEL({gnd, gnd, gnd}),
+        .CARRYOUT(),
+        .CEA1(en),
+        .CEA2(en),
+        .CEAD(gnd),
+        .CEALUMODE(gnd),
+        .CEB1(en),
+        .CEB2(en),
+        .CEC(gnd),
+        .CECARRYIN(gnd),
+        .CECTRL(gnd),
+        .CED(gnd),
+        .CEINMODE(gnd),
+        .CEM(en),
+        .CEP(en),
+        .CLK(clock),
+        .D({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .INMODE({gnd, gnd, gnd, gnd, gnd}),
+        .MULTSIGNIN(gnd),
+        .MULTSIGNOUT(),
+        .OPMODE({gnd, gnd, gnd, vcc, vcc, gnd, vcc, gnd, vcc}),
+        .OVERFLOW(),
+        .P(_t9),
+        .PATTERNBDETECT(),
+        .PATTERNDETECT(),
+        .PCIN({gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd, gnd}),
+        .PCOUT(_t9_pcout),
+        .RSTA(reset),
+        .RSTALLCARRYIN(reset),
+        .RSTALUMODE(reset),
+        .RSTB(reset),
+        .RSTC(reset),
+        .RSTCTRL(reset),
+        .RSTD(reset),
+        .RSTINMODE(reset),
+        .RSTM(reset),
+        .RSTP(reset),
+        .UNDERFLOW(),
+        .XOROUT()
+    );
+    assign t9 = _t9[7:0];
+endmodule
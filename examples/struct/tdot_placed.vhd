@@ -0,0 +1,317 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+library unisim;
+use unisim.vcomponents.all;
+
+entity main is
+  port (
+    clock : in std_logic;
+    reset : in std_logic;
+    a0 : in std_logic_vector(7 downto 0);
+    a1 : in std_logic_vector(7 downto 0);
+    a2 : in std_logic_vector(7 downto 0);
+    b0 : in std_logic_vector(7 downto 0);
+    b1 : in std_logic_vector(7 downto 0);
+    b2 : in std_logic_vector(7 downto 0);
+    c : in std_logic_vector(7 downto 0);
+    en : in std_logic;
+    y : out std_logic_vector(7 downto 0)
+  );
+end entity main;
+
+architecture rtl of main is
+  signal t0 : std_logic_vector(7 downto 0);
+  signal t1 : std_logic_vector(7 downto 0);
+  signal \__t0_P\ : std_logic_vector(47 downto 0);
+  signal \__t1_P\ : std_logic_vector(47 downto 0);
+  signal \__y_P\ : std_logic_vector(47 downto 0);
+  attribute BEL : string;
+  attribute LOC : string;
+  attribute BEL of \__t0\ : label is "DSP_ALU";
+  attribute LOC of \__t0\ : label is "DSP48E2_X0Y1";
+  attribute BEL of \__t1\ : label is "DSP_ALU";
+  attribute LOC of \__t1\ : label is "DSP48E2_X0Y0";
+  attribute BEL of \__y\ : label is "DSP_ALU";
+  attribute LOC of \__y\ : label is "DSP48E2_X0Y2";
+begin
+  \__t0\ : DSP48E2
+    generic map (
+      ACASCREG => 1,
+      ADREG => 0,
+      ALUMODEREG => 0,
+      AMULTSEL => "A",
+      AREG => 1,
+      AUTORESET_PATDET => "NO_RESET",
+      AUTORESET_PRIORITY => "RESET",
+      A_INPUT => "DIRECT",
+      BCASCREG => 1,
+      BMULTSEL => "B",
+      BREG => 1,
+      B_INPUT => "DIRECT",
+      CARRYINREG => 0,
+      CARRYINSELREG => 0,
+      CREG => 0,
+      DREG => 0,
+      INMODEREG => 0,
+      IS_ALUMODE_INVERTED => "0000",
+      IS_CARRYIN_INVERTED => '0',
+      IS_CLK_INVERTED => '0',
+      IS_INMODE_INVERTED => "00000",
+      IS_OPMODE_INVERTED => "000000000",
+      IS_RSTALLCARRYIN_INVERTED => '0',
+      IS_RSTALUMODE_INVERTED => '0',
+      IS_RSTA_INVERTED => '0',
+      IS_RSTB_INVERTED => '0',
+      IS_RSTCTRL_INVERTED => '0',
+      IS_RSTC_INVERTED => '0',
+      IS_RSTD_INVERTED => '0',
+      IS_RSTINMODE_INVERTED => '0',
+      IS_RSTM_INVERTED => '0',
+      IS_RSTP_INVERTED => '0',
+      MASK => "001111111111111111111111111111111111111111111111",
+      MREG => 1,
+      OPMODEREG => 0,
+      PATTERN => "000000000000000000000000000000000000000000000000",
+      PREADDINSEL => "A",
+      PREG => 1,
+      RND => "000000000000000000000000000000000000000000000000",
+      SEL_MASK => "MASK",
+      SEL_PATTERN => "PATTERN",
+      USE_MULT => "MULTIPLY",
+      USE_SIMD => "ONE48",
+      USE_WIDEXOR => "FALSE",
+      XORSIMD => "XOR24_48_96"
+    )
+    port map (
+      A(7 downto 0) => a1,
+      A(29 downto 8) => "0000000000000000000000",
+      ACIN => "000000000000000000000000000000",
+      ALUMODE => "0000",
+      B(7 downto 0) => b1,
+      B(17 downto 8) => "0000000000",
+      BCIN => "000000000000000000",
+      C(7 downto 0) => t1,
+      C(47 downto 8) => "0000000000000000000000000000000000000000",
+      CARRYCASCIN => '0',
+      CARRYIN => '0',
+      CARRYINSEL => "000",
+      CEA1 => en,
+      CEA2 => en,
+      CEAD => '0',
+      CEALUMODE => '0',
+      CEB1 => en,
+      CEB2 => en,
+      CEC => '0',
+      CECARRYIN => '0',
+      CECTRL => '0',
+      CED => '0',
+      CEINMODE => '0',
+      CEM => en,
+      CEP => en,
+      CLK => clock,
+      D => "000000000000000000000000000",
+      INMODE => "00000",
+      MULTSIGNIN => '0',
+      OPMODE => "000110101",
+      P(7 downto 0) => t0,
+      P(47 downto 8) => \__t0_P\(47 downto 8),
+      PCIN => "000000000000000000000000000000000000000000000000",
+      RSTA => reset,
+      RSTALLCARRYIN => reset,
+      RSTALUMODE => reset,
+      RSTB => reset,
+      RSTC => reset,
+      RSTCTRL => reset,
+      RSTD => reset,
+      RSTINMODE => reset,
+      RSTM => reset,
+      RSTP => reset
+    );
+  \__t1\ : DSP48E2
+    generic map (
+      ACASCREG => 1,
+      ADREG => 0,
+      ALUMODEREG => 0,
+      AMULTSEL => "A",
+      AREG => 1,
+      AUTORESET_PATDET => "NO_RESET",
+      AUTORESET_PRIORITY => "RESET",
+      A_INPUT => "DIRECT",
+      BCASCREG => 1,
+      BMULTSEL => "B",
+      BREG => 1,
+      B_INPUT => "DIRECT",
+      CARRYINREG => 0,
+      CARRYINSELREG => 0,
+      CREG => 0,
+      DREG => 0,
+      INMODEREG => 0,
+      IS_ALUMODE_INVERTED => "0000",
+      IS_CARRYIN_INVERTED => '0',
+      IS_CLK_INVERTED => '0',
+      IS_INMODE_INVERTED => "00000",
+      IS_OPMODE_INVERTED => "000000000",
+      IS_RSTALLCARRYIN_INVERTED => '0',
+      IS_RSTALUMODE_INVERTED => '0',
+      IS_RSTA_INVERTED => '0',
+      IS_RSTB_INVERTED => '0',
+      IS_RSTCTRL_INVERTED => '0',
+      IS_RSTC_INVERTED => '0',
+      IS_RSTD_INVERTED => '0',
+      IS_RSTINMODE_INVERTED => '0',
+      IS_RSTM_INVERTED => '0',
+      IS_RSTP_INVERTED => '0',
+      MASK => "001111111111111111111111111111111111111111111111",
+      MREG => 1,
+      OPMODEREG => 0,
+      PATTERN => "000000000000000000000000000000000000000000000000",
+      PREADDINSEL => "A",
+      PREG => 1,
+      RND => "000000000000000000000000000000000000000000000000",
+      SEL_MASK => "MASK",
+      SEL_PATTERN => "PATTERN",
+      USE_MULT => "MULTIPLY",
+      USE_SIMD => "ONE48",
+      USE_WIDEXOR => "FALSE",
+      XORSIMD => "XOR24_48_96"
+    )
+    port map (
+      A(7 downto 0) => a0,
+      A(29 downto 8) => "0000000000000000000000",
+      ACIN => "000000000000000000000000000000",
+      ALUMODE => "0000",
+      B(7 downto 0) => b0,
+      B(17 downto 8) => "0000000000",
+      BCIN => "000000000000000000",
+      C(7 downto 0) => c,
+      C(47 downto 8) => "0000000000000000000000000000000000000000",
+      CARRYCASCIN => '0',
+      CARRYIN => '0',
+      CARRYINSEL => "000",
+      CEA1 => en,
+      CEA2 => en,
+      CEAD => '0',
+      CEALUMODE => '0',
+      CEB1 => en,
+      CEB2 => en,
+      CEC => '0',
+      CECARRYIN => '0',
+      CECTRL => '0',
+      CED => '0',
+      CEINMODE => '0',
+      CEM => en,
+      CEP => en,
+      CLK => clock,
+      D => "000000000000000000000000000",
+      INMODE => "00000",
+      MULTSIGNIN => '0',
+      OPMODE => "000110101",
+      P(7 downto 0) => t1,
+      P(47 downto 8) => \__t1_P\(47 downto 8),
+      PCIN => "000000000000000000000000000000000000000000000000",
+      RSTA => reset,
+      RSTALLCARRYIN => reset,
+      RSTALUMODE => reset,
+      RSTB => reset,
+      RSTC => reset,
+      RSTCTRL => reset,
+      RSTD => reset,
+      RSTINMODE => reset,
+      RSTM => reset,
+      RSTP => reset
+    );
+  \__y\ : DSP48E2
+    generic map (
+      ACASCREG => 1,
+      ADREG => 0,
+      ALUMODEREG => 0,
+      AMULTSEL => "A",
+      AREG => 1,
+      AUTORESET_PATDET => "NO_RESET",
+      AUTORESET_PRIORITY => "RESET",
+      A_INPUT => "DIRECT",
+      BCASCREG => 1,
+      BMULTSEL => "B",
+      BREG => 1,
+      B_INPUT => "DIRECT",
+      CARRYINREG => 0,
+      CARRYINSELREG => 0,
+      CREG => 0,
+      DREG => 0,
+      INMODEREG => 0,
+      IS_ALUMODE_INVERTED => "0000",
+      IS_CARRYIN_INVERTED => '0',
+      IS_CLK_INVERTED => '0',
+      IS_INMODE_INVERTED => "00000",
+      IS_OPMODE_INVERTED => "000000000",
+      IS_RSTALLCARRYIN_INVERTED => '0',
+      IS_RSTALUMODE_INVERTED => '0',
+      IS_RSTA_INVERTED => '0',
+      IS_RSTB_INVERTED => '0',
+      IS_RSTCTRL_INVERTED => '0',
+      IS_RSTC_INVERTED => '0',
+      IS_RSTD_INVERTED => '0',
+      IS_RSTINMODE_INVERTED => '0',
+      IS_RSTM_INVERTED => '0',
+      IS_RSTP_INVERTED => '0',
+      MASK => "001111111111111111111111111111111111111111111111",
+      MREG => 1,
+      OPMODEREG => 0,
+      PATTERN => "000000000000000000000000000000000000000000000000",
+      PREADDINSEL => "A",
+      PREG => 1,
+      RND => "000000000000000000000000000000000000000000000000",
+      SEL_MASK => "MASK",
+      SEL_PATTERN => "PATTERN",
+      USE_MULT => "MULTIPLY",
+      USE_SIMD => "ONE48",
+      USE_WIDEXOR => "FALSE",
+      XORSIMD => "XOR24_48_96"
+    )
+    port map (
+      A(7 downto 0) => a2,
+      A(29 downto 8) => "0000000000000000000000",
+      ACIN => "000000000000000000000000000000",
+      ALUMODE => "0000",
+      B(7 downto 0) => b2,
+      B(17 downto 8) => "0000000000",
+      BCIN => "000000000000000000",
+      C(7 downto 0) => t0,
+      C(47 downto 8) => "0000000000000000000000000000000000000000",
+      CARRYCASCIN => '0',
+      CARRYIN => '0',
+      CARRYINSEL => "000",
+      CEA1 => en,
+      CEA2 => en,
+      CEAD => '0',
+      CEALUMODE => '0',
+      CEB1 => en,
+      CEB2 => en,
+      CEC => '0',
+      CECARRYIN => '0',
+      CECTRL => '0',
+      CED => '0',
+      CEINMODE => '0',
+      CEM => en,
+      CEP => en,
+      CLK => clock,
+      D => "000000000000000000000000000",
+      INMODE => "00000",
+      MULTSIGNIN => '0',
+      OPMODE => "000110101",
+      P(7 downto 0) => y,
+      P(47 downto 8) => \__y_P\(47 downto 8),
+      PCIN => "000000000000000000000000000000000000000000000000",
+      RSTA => reset,
+      RSTALLCARRYIN => reset,
+      RSTALUMODE => reset,
+      RSTB => reset,
+      RSTC => reset,
+      RSTCTRL => reset,
+      RSTD => reset,
+      RSTINMODE => reset,
+      RSTM => reset,
+      RSTP => reset
+    );
+end architecture rtl;
\ No newline at end of file
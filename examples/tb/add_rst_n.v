@@ -0,0 +1,85 @@
+module testbench;
+    reg clk = 1'b0;
+    reg rst_n = 1'b0;
+    reg [7:0] a = 8'h0;
+    reg [7:0] b = 8'h0;
+    reg en = 1'h0;
+    wire [7:0] y;
+    integer errors = 0;
+
+    main dut (
+        .clk(clk),
+        .rst_n(rst_n),
+        .a(a),
+        .b(b),
+        .en(en),
+        .y(y)
+    );
+
+    always #5 clk = ~clk;
+
+    initial begin
+        repeat (2) @(posedge clk);
+        #1 rst_n = 1'b1;
+        // cycle 0
+        a = 8'h1;
+        b = 8'h2;
+        en = 1'h1;
+        #1;
+        if (y !== 8'h0) begin
+            $display("cycle 0: y is %h, expected 8'h0", y);
+            errors = errors + 1;
+        end
+        @(posedge clk);
+        #1;
+        // cycle 1
+        a = 8'h3;
+        b = 8'h4;
+        en = 1'h0;
+        #1;
+        if (y !== 8'h3) begin
+            $display("cycle 1: y is %h, expected 8'h3", y);
+            errors = errors + 1;
+        end
+        @(posedge clk);
+        #1;
+        // cycle 2
+        a = 8'h64;
+        b = 8'h32;
+        en = 1'h1;
+        #1;
+        if (y !== 8'h3) begin
+            $display("cycle 2: y is %h, expected 8'h3", y);
+            errors = errors + 1;
+        end
+        @(posedge clk);
+        #1;
+        // cycle 3
+        a = 8'hff;
+        b = 8'h1;
+        en = 1'h1;
+        #1;
+        if (y !== 8'h96) begin
+            $display("cycle 3: y is %h, expected 8'h96", y);
+            errors = errors + 1;
+        end
+        @(posedge clk);
+        #1;
+        // cycle 4
+        a = 8'h0;
+        b = 8'h0;
+        en = 1'h0;
+        #1;
+        if (y !== 8'h0) begin
+            $display("cycle 4: y is %h, expected 8'h0", y);
+            errors = errors + 1;
+        end
+        @(posedge clk);
+        #1;
+        if (errors == 0)
+            $display("PASS: 5 cycles");
+        else
+            $display("FAIL: %0d errors in 5 cycles", errors);
+        $finish;
+    end
+endmodule
\ No newline at end of file
@@ -0,0 +1,87 @@
+use crate::errors::Error;
+use std::str::FromStr;
+
+pub const CLOCK: &str = "clock";
+pub const RESET: &str = "reset";
+
+/// how registers are reset
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ResetTy {
+    Sync,
+    Async,
+    None,
+}
+
+/// clock and reset ports shared by every backend
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Clock {
+    pub clock: String,
+    pub reset: String,
+    pub reset_ty: ResetTy,
+    pub active_low: bool,
+}
+
+impl Default for ResetTy {
+    fn default() -> Self {
+        ResetTy::Sync
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Clock {
+            clock: CLOCK.to_string(),
+            reset: RESET.to_string(),
+            reset_ty: ResetTy::default(),
+            active_low: false,
+        }
+    }
+}
+
+impl FromStr for ResetTy {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "sync" => Ok(ResetTy::Sync),
+            "async" => Ok(ResetTy::Async),
+            "none" => Ok(ResetTy::None),
+            _ => Err(Error::new_conv_error(&format!(
+                "invalid reset style {}, expected sync, async or none",
+                input
+            ))),
+        }
+    }
+}
+
+impl Clock {
+    pub fn clock(&self) -> String {
+        self.clock.to_string()
+    }
+    pub fn reset(&self) -> String {
+        self.reset.to_string()
+    }
+    pub fn reset_ty(&self) -> &ResetTy {
+        &self.reset_ty
+    }
+    pub fn is_active_low(&self) -> bool {
+        self.active_low
+    }
+    pub fn has_reset(&self) -> bool {
+        self.reset_ty != ResetTy::None
+    }
+    pub fn is_async(&self) -> bool {
+        self.reset_ty == ResetTy::Async
+    }
+    pub fn set_clock(&mut self, name: &str) {
+        self.clock = name.to_string();
+    }
+    pub fn set_reset(&mut self, name: &str) {
+        self.reset = name.to_string();
+    }
+    pub fn set_reset_ty(&mut self, ty: ResetTy) {
+        self.reset_ty = ty;
+    }
+    pub fn set_active_low(&mut self, active_low: bool) {
+        self.active_low = active_low;
+    }
+}
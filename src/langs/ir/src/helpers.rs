@@ -265,6 +265,32 @@ impl InstrWire {
     pub fn set_arg(&mut self, arg: Expr) {
         self.arg = arg;
     }
+    /// values of a const instr, one per lane of dst.
+    /// A single value is broadcasted to every lane, and every value
+    /// must fit in the lane width as either a signed or unsigned number
+    pub fn const_values(&self) -> Result<Vec<i64>, Error> {
+        let dst = self.dst().get_term(0)?;
+        let lanes = dst.length().unwrap_or(1);
+        let width = dst.width().unwrap_or(64);
+        let attr: Vec<ExprTerm> = self.attr().clone().into();
+        let mut values: Vec<i64> = Vec::new();
+        for a in attr.iter() {
+            let val = a.get_val()?;
+            if width > 0 && width < 64 && (val < -(1 << (width - 1)) || val >= 1 << width) {
+                let err = format!("const value {} does not fit in {} bits", val, width);
+                return Err(Error::new_conv_error(&err));
+            }
+            values.push(val);
+        }
+        if values.len() == 1 {
+            Ok(vec![values[0]; lanes.try_into()?])
+        } else if values.len() as u64 == lanes {
+            Ok(values)
+        } else {
+            let err = format!("const must have either one or {} values", lanes);
+            Err(Error::new_conv_error(&err))
+        }
+    }
 }
 
 impl Instr {
@@ -0,0 +1,295 @@
+use crate::ast::*;
+use crate::errors::Error;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+/// value of a term, one number per lane
+pub type Value = Vec<i64>;
+pub type Env = HashMap<Id, Value>;
+
+// a reg instruction, its state is the value of dst
+#[derive(Clone, Debug)]
+struct Reg {
+    pub dst: ExprTerm,
+    pub arg: ExprTerm,
+    pub en: ExprTerm,
+    pub init: i64,
+}
+
+/// Cycle based interpreter for a def. Every call to step evaluates the
+/// outputs for the current inputs and then clocks the registers, which
+/// is what a behavioral or structural module does between two edges.
+#[derive(Clone, Debug)]
+pub struct Interp {
+    def: Def,
+    reg: Vec<Reg>,
+    state: Env,
+}
+
+fn width_try_from_term(term: &ExprTerm) -> Result<u64, Error> {
+    if let Some(width) = term.width() {
+        Ok(width)
+    } else {
+        let err = format!("{} must have a width", term);
+        Err(Error::new_conv_error(&err))
+    }
+}
+
+fn mask(width: u64) -> i64 {
+    if width >= 64 {
+        -1
+    } else {
+        (1 << width) - 1
+    }
+}
+
+/// value truncated to the width of ty, sign extended if ty is signed
+pub fn norm(ty: &Ty, value: i64) -> i64 {
+    let width = ty.width().unwrap_or(64);
+    if width >= 64 {
+        value
+    } else {
+        let v = value & mask(width);
+        if ty.is_signed() && (v >> (width - 1)) & 1 == 1 {
+            v - (1 << width)
+        } else {
+            v
+        }
+    }
+}
+
+fn norm_term(term: &ExprTerm, value: i64) -> Result<i64, Error> {
+    Ok(norm(term.get_ty()?, value))
+}
+
+fn lanes_try_from_term(term: &ExprTerm) -> Result<usize, Error> {
+    Ok(usize::try_from(term.length().unwrap_or(1))?)
+}
+
+fn value_try_from_term(env: &Env, term: &ExprTerm) -> Result<Value, Error> {
+    match term {
+        ExprTerm::Val(v) => Ok(vec![*v]),
+        _ => {
+            let id = term.get_id()?;
+            if let Some(value) = env.get(&id) {
+                Ok(value.clone())
+            } else {
+                let err = format!("{} is not defined", id);
+                Err(Error::new_conv_error(&err))
+            }
+        }
+    }
+}
+
+// operand values, or none if some of them are not computed yet
+fn args_from_expr(env: &Env, expr: &Expr) -> Option<Vec<Value>> {
+    let term: Vec<ExprTerm> = expr.clone().into();
+    term.iter()
+        .map(|t| value_try_from_term(env, t).ok())
+        .collect()
+}
+
+fn eval_instr_wire(instr: &InstrWire, arg: &[Value]) -> Result<Value, Error> {
+    let dst = instr.dst().get_term(0)?;
+    let lanes = lanes_try_from_term(dst)?;
+    let width = width_try_from_term(dst)?;
+    let mut value: Value = Vec::new();
+    match instr.op() {
+        OpWire::Id => value = arg[0].clone(),
+        OpWire::Con => value = instr.const_values()?,
+        OpWire::Sll | OpWire::Srl | OpWire::Sra => {
+            let shift = u32::try_from(instr.attr().get_val(0)?)?.min(63);
+            for a in arg[0].iter() {
+                let v = match instr.op() {
+                    OpWire::Sll => a << shift,
+                    OpWire::Srl => (a & mask(width)) >> shift,
+                    _ => norm(&Ty::SInt(width), *a) >> shift,
+                };
+                value.push(v);
+            }
+        }
+        OpWire::Ext => {
+            let lsb = u32::try_from(instr.attr().get_val(0)?)?.min(63);
+            let src = instr.arg().get_term(0)?;
+            let src_width = width_try_from_term(src)?;
+            for a in arg[0].iter() {
+                value.push((a & mask(src_width)) >> lsb);
+            }
+        }
+        OpWire::Cat => {
+            let term: Vec<ExprTerm> = instr.arg().clone().into();
+            let mut acc: i64 = 0;
+            let mut offset: u64 = 0;
+            for (t, a) in term.iter().zip(arg.iter()) {
+                let w = width_try_from_term(t)?;
+                if offset < 64 {
+                    acc |= (a[0] & mask(w)) << offset;
+                }
+                offset += w;
+            }
+            value.push(acc);
+        }
+    }
+    if value.len() != lanes {
+        let err = format!("{} must have {} lanes", dst, lanes);
+        return Err(Error::new_conv_error(&err));
+    }
+    value
+        .iter()
+        .map(|v| norm_term(dst, *v))
+        .collect::<Result<Value, Error>>()
+}
+
+// lane i of a value, scalars are broadcasted
+fn lane(value: &[i64], i: usize) -> i64 {
+    if value.len() == 1 {
+        value[0]
+    } else {
+        value[i]
+    }
+}
+
+fn eval_instr_prim(instr: &InstrPrim, arg: &[Value]) -> Result<Value, Error> {
+    let dst = instr.dst().get_term(0)?;
+    let lanes = lanes_try_from_term(dst)?;
+    let mut value: Value = Vec::new();
+    for i in 0..lanes {
+        let a = lane(&arg[0], i);
+        let v = match instr.op() {
+            OpPrim::Not => !a,
+            OpPrim::Mux => {
+                if a & 1 == 1 {
+                    lane(&arg[1], i)
+                } else {
+                    lane(&arg[2], i)
+                }
+            }
+            op => {
+                let b = lane(&arg[1], i);
+                match op {
+                    OpPrim::Add => a.wrapping_add(b),
+                    OpPrim::Sub => a.wrapping_sub(b),
+                    OpPrim::Mul => a.wrapping_mul(b),
+                    OpPrim::And => a & b,
+                    OpPrim::Or => a | b,
+                    OpPrim::Xor => a ^ b,
+                    OpPrim::Eql => i64::from(a == b),
+                    OpPrim::Neql => i64::from(a != b),
+                    OpPrim::Gt => i64::from(a > b),
+                    OpPrim::Lt => i64::from(a < b),
+                    OpPrim::Ge => i64::from(a >= b),
+                    OpPrim::Le => i64::from(a <= b),
+                    _ => {
+                        let err = format!("{} is not a combinational op", op);
+                        return Err(Error::new_conv_error(&err));
+                    }
+                }
+            }
+        };
+        value.push(norm_term(dst, v)?);
+    }
+    Ok(value)
+}
+
+impl Interp {
+    pub fn new(def: &Def) -> Result<Interp, Error> {
+        let mut reg: Vec<Reg> = Vec::new();
+        for instr in def.body() {
+            match instr {
+                Instr::Prim(instr) if instr.is_reg() => {
+                    let init = instr.attr().get_val(0).unwrap_or(0);
+                    reg.push(Reg {
+                        dst: instr.dst().get_term(0)?.clone(),
+                        arg: instr.arg().get_term(0)?.clone(),
+                        en: instr.arg().get_term(1)?.clone(),
+                        init,
+                    });
+                }
+                Instr::Prim(instr) if *instr.op() == OpPrim::Ram => {
+                    return Err(Error::new_conv_error("ram is not supported"));
+                }
+                Instr::Call(instr) => {
+                    let err = format!("call to {} is not supported", instr.op().op());
+                    return Err(Error::new_conv_error(&err));
+                }
+                _ => (),
+            }
+        }
+        let mut interp = Interp {
+            def: def.clone(),
+            reg,
+            state: Env::new(),
+        };
+        interp.reset()?;
+        Ok(interp)
+    }
+    /// set every register to its init value
+    pub fn reset(&mut self) -> Result<(), Error> {
+        self.state.clear();
+        for r in self.reg.iter() {
+            let lanes = lanes_try_from_term(&r.dst)?;
+            let init = norm_term(&r.dst, r.init)?;
+            self.state.insert(r.dst.get_id()?, vec![init; lanes]);
+        }
+        Ok(())
+    }
+    /// values of every term for the inputs in the current state
+    pub fn eval(&self, input: &Env) -> Result<Env, Error> {
+        let mut env = self.state.clone();
+        let sig: Vec<ExprTerm> = self.def.sig().input().clone().into();
+        for term in sig.iter() {
+            let id = term.get_id()?;
+            if let Some(value) = input.get(&id) {
+                let value = value
+                    .iter()
+                    .map(|v| norm_term(term, *v))
+                    .collect::<Result<Value, Error>>()?;
+                env.insert(id, value);
+            } else {
+                let err = format!("missing value for input {}", id);
+                return Err(Error::new_conv_error(&err));
+            }
+        }
+        let mut pending: Vec<&Instr> = self.def.body().iter().filter(|i| !i.is_reg()).collect();
+        while !pending.is_empty() {
+            let mut next: Vec<&Instr> = Vec::new();
+            for instr in pending.iter() {
+                if let Some(arg) = args_from_expr(&env, instr.arg()) {
+                    let value = match instr {
+                        Instr::Wire(instr) => eval_instr_wire(instr, &arg)?,
+                        Instr::Prim(instr) => eval_instr_prim(instr, &arg)?,
+                        Instr::Call(_) => unreachable!(),
+                    };
+                    env.insert(instr.dst().get_id(0)?, value);
+                } else {
+                    next.push(instr);
+                }
+            }
+            if next.len() == pending.len() {
+                return Err(Error::new_conv_error(
+                    "combinational loop or undefined operand",
+                ));
+            }
+            pending = next;
+        }
+        Ok(env)
+    }
+    /// outputs for the inputs, then a clock edge
+    pub fn step(&mut self, input: &Env) -> Result<Env, Error> {
+        let env = self.eval(input)?;
+        let mut output = Env::new();
+        let sig: Vec<ExprTerm> = self.def.sig().output().clone().into();
+        for term in sig.iter() {
+            let id = term.get_id()?;
+            output.insert(id.clone(), value_try_from_term(&env, term)?);
+        }
+        for r in self.reg.iter() {
+            let en = value_try_from_term(&env, &r.en)?;
+            if en[0] & 1 == 1 {
+                let value = value_try_from_term(&env, &r.arg)?;
+                self.state.insert(r.dst.get_id()?, value);
+            }
+        }
+        Ok(output)
+    }
+}
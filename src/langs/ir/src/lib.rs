@@ -1,4 +1,5 @@
 pub mod ast;
+pub mod clock;
 pub mod default;
 pub mod display;
 pub mod errors;
@@ -6,6 +7,7 @@ pub mod from;
 pub mod from_str;
 pub mod helpers;
 pub mod infer;
+pub mod interp;
 pub mod parser;
 pub mod pretty_print;
 pub mod try_from;
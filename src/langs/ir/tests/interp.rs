@@ -0,0 +1,69 @@
+use ir::errors::Error;
+use ir::interp::{Env, Interp};
+use ir::parser::Parser;
+
+fn env(value: &[(&str, i64)]) -> Env {
+    value
+        .iter()
+        .map(|(k, v)| (k.to_string(), vec![*v]))
+        .collect()
+}
+
+#[test]
+fn add() -> Result<(), Error> {
+    let prog = Parser::parse_from_file("../../../examples/ir/add.ir")?;
+    let mut interp = Interp::new(prog.get("main").unwrap())?;
+    let y0 = interp.step(&env(&[("a", 100), ("b", 50), ("en", 1)]))?;
+    let y1 = interp.step(&env(&[("a", 1), ("b", 2), ("en", 0)]))?;
+    let y2 = interp.step(&env(&[("a", 1), ("b", 2), ("en", 1)]))?;
+    let y3 = interp.step(&env(&[("a", 0), ("b", 0), ("en", 0)]))?;
+    assert_eq!(y0, env(&[("y", 0)]));
+    assert_eq!(y1, env(&[("y", -106)]));
+    assert_eq!(y2, env(&[("y", -106)]));
+    assert_eq!(y3, env(&[("y", 3)]));
+    Ok(())
+}
+
+#[test]
+fn fsm_3() -> Result<(), Error> {
+    let prog = Parser::parse_from_file("../../../examples/ir/fsm_3.ir")?;
+    let mut interp = Interp::new(prog.get("main").unwrap())?;
+    let stay = env(&[("i0", 0), ("i1", 0), ("i2", 0)]);
+    assert_eq!(
+        interp.step(&env(&[("i0", 1), ("i1", 0), ("i2", 0)]))?,
+        env(&[("y", 0)])
+    );
+    assert_eq!(interp.step(&stay)?, env(&[("y", 1)]));
+    assert_eq!(
+        interp.step(&env(&[("i0", 0), ("i1", 1), ("i2", 0)]))?,
+        env(&[("y", 1)])
+    );
+    assert_eq!(
+        interp.step(&env(&[("i0", 0), ("i1", 0), ("i2", 1)]))?,
+        env(&[("y", 2)])
+    );
+    assert_eq!(interp.step(&stay)?, env(&[("y", 0)]));
+    Ok(())
+}
+
+#[test]
+fn ops() -> Result<(), Error> {
+    let prog = Parser::parse_from_file("../../../examples/ir/ops.ir")?;
+    let mut interp = Interp::new(prog.get("main").unwrap())?;
+    let mut input = env(&[("a", -3), ("b", 5), ("c", 200), ("d", 7)]);
+    input.insert("e".to_string(), vec![0xab, 0x12]);
+    let mut exp = env(&[("y", -3), ("z", 36985), ("p", 0), ("q", 0)]);
+    exp.insert("r".to_string(), vec![10, 1]);
+    assert_eq!(interp.step(&input)?, exp);
+    Ok(())
+}
+
+#[test]
+fn negative_attr() {
+    for op in ["sll", "srl", "sra", "ext"].iter() {
+        let src = format!("def main(a:i8) -> (y:i8) {{ y:i8 = {}[-1](a); }}", op);
+        let prog = Parser::parse_from_str(&src).unwrap();
+        let interp = Interp::new(prog.get("main").unwrap()).unwrap();
+        assert!(interp.eval(&env(&[("a", 3)])).is_err());
+    }
+}
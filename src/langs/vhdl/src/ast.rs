@@ -0,0 +1,82 @@
+pub type Id = String;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Ty {
+    Logic,
+    Vector(u64),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Dir {
+    In,
+    Out,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Port {
+    pub id: Id,
+    pub dir: Dir,
+    pub ty: Ty,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signal {
+    pub id: Id,
+    pub ty: Ty,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Ref(Id),
+    Index(Id, u64),
+    Slice(Id, u64, u64),
+    // '0' or '1'
+    Logic(bool),
+    // bit string, msb first
+    Bits(String),
+    Int(i64),
+    Str(String),
+    // type conversions and function calls, e.g. signed(a)
+    Call(Id, Vec<Expr>),
+    Binop(String, Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    // value when cond else other
+    Cond(Box<Expr>, Box<Expr>, Box<Expr>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Seq {
+    Assign(Expr, Expr),
+    If(Vec<(Expr, Vec<Seq>)>, Vec<Seq>),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Process {
+    pub sensitivity: Vec<Id>,
+    pub body: Vec<Seq>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Instance {
+    pub id: Id,
+    pub prim: Id,
+    pub generic: Vec<(Id, Expr)>,
+    pub port: Vec<(Expr, Expr)>,
+    pub attr: Vec<(Id, String)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Stmt {
+    Assign(Expr, Expr),
+    Process(Process),
+    Instance(Instance),
+}
+
+/// an entity and its only architecture
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Module {
+    pub id: Id,
+    pub port: Vec<Port>,
+    pub signal: Vec<Signal>,
+    pub stmt: Vec<Stmt>,
+}
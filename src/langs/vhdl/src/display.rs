@@ -0,0 +1,297 @@
+use crate::ast::*;
+use std::collections::BTreeSet;
+use std::fmt;
+
+// reserved words of VHDL-2008
+const RESERVED: &[&str] = &[
+    "abs",
+    "access",
+    "after",
+    "alias",
+    "all",
+    "and",
+    "architecture",
+    "array",
+    "assert",
+    "assume",
+    "attribute",
+    "begin",
+    "block",
+    "body",
+    "buffer",
+    "bus",
+    "case",
+    "component",
+    "configuration",
+    "constant",
+    "context",
+    "cover",
+    "default",
+    "disconnect",
+    "downto",
+    "else",
+    "elsif",
+    "end",
+    "entity",
+    "exit",
+    "fairness",
+    "file",
+    "for",
+    "force",
+    "function",
+    "generate",
+    "generic",
+    "group",
+    "guarded",
+    "if",
+    "impure",
+    "in",
+    "inertial",
+    "inout",
+    "is",
+    "label",
+    "library",
+    "linkage",
+    "literal",
+    "loop",
+    "map",
+    "mod",
+    "nand",
+    "new",
+    "next",
+    "nor",
+    "not",
+    "null",
+    "of",
+    "on",
+    "open",
+    "or",
+    "others",
+    "out",
+    "package",
+    "parameter",
+    "port",
+    "postponed",
+    "procedure",
+    "process",
+    "property",
+    "protected",
+    "pure",
+    "range",
+    "record",
+    "register",
+    "reject",
+    "release",
+    "rem",
+    "report",
+    "restrict",
+    "return",
+    "rol",
+    "ror",
+    "select",
+    "sequence",
+    "severity",
+    "shared",
+    "signal",
+    "sla",
+    "sll",
+    "sra",
+    "srl",
+    "strong",
+    "subtype",
+    "then",
+    "to",
+    "transport",
+    "type",
+    "unaffected",
+    "units",
+    "until",
+    "use",
+    "variable",
+    "vmode",
+    "wait",
+    "when",
+    "while",
+    "with",
+    "xnor",
+    "xor",
+];
+
+fn is_reserved(id: &str) -> bool {
+    RESERVED.contains(&id.to_lowercase().as_str())
+}
+
+fn is_basic_id(id: &str) -> bool {
+    let first = id.chars().next().map_or(false, |c| c.is_ascii_alphabetic());
+    first
+        && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !id.contains("__")
+        && !id.ends_with('_')
+        && !is_reserved(id)
+}
+
+/// names that are not basic identifiers, e.g. _t0, become extended ones
+pub fn name(id: &str) -> String {
+    if is_basic_id(id) {
+        id.to_string()
+    } else {
+        format!("\\{}\\", id.replace('\\', "\\\\"))
+    }
+}
+
+impl fmt::Display for Ty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ty::Logic => write!(f, "std_logic"),
+            Ty::Vector(width) => write!(f, "std_logic_vector({} downto 0)", width - 1),
+        }
+    }
+}
+
+impl fmt::Display for Dir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Dir::In => write!(f, "in"),
+            Dir::Out => write!(f, "out"),
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Ref(id) => write!(f, "{}", name(id)),
+            Expr::Index(id, index) => write!(f, "{}({})", name(id), index),
+            Expr::Slice(id, msb, lsb) => write!(f, "{}({} downto {})", name(id), msb, lsb),
+            Expr::Logic(value) => write!(f, "'{}'", u8::from(*value)),
+            Expr::Bits(bits) => write!(f, "\"{}\"", bits),
+            Expr::Int(value) => write!(f, "{}", value),
+            Expr::Str(value) => write!(f, "\"{}\"", value),
+            Expr::Call(func, arg) => {
+                let arg: Vec<String> = arg.iter().map(|a| a.to_string()).collect();
+                write!(f, "{}({})", func, arg.join(", "))
+            }
+            Expr::Binop(op, lhs, rhs) => write!(f, "{} {} {}", lhs, op, rhs),
+            Expr::Not(expr) => write!(f, "not {}", expr),
+            Expr::Cond(value, cond, other) => write!(f, "{} when {} else {}", value, cond, other),
+        }
+    }
+}
+
+fn write_seq(f: &mut fmt::Formatter<'_>, seq: &[Seq], indent: usize) -> fmt::Result {
+    let pad = " ".repeat(indent);
+    for s in seq.iter() {
+        match s {
+            Seq::Assign(lhs, rhs) => writeln!(f, "{}{} <= {};", pad, lhs, rhs)?,
+            Seq::If(branch, other) => {
+                for (i, (cond, body)) in branch.iter().enumerate() {
+                    let kw = if i == 0 { "if" } else { "elsif" };
+                    writeln!(f, "{}{} {} then", pad, kw, cond)?;
+                    write_seq(f, body, indent + 2)?;
+                }
+                if !other.is_empty() {
+                    writeln!(f, "{}else", pad)?;
+                    write_seq(f, other, indent + 2)?;
+                }
+                writeln!(f, "{}end if;", pad)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_map(f: &mut fmt::Formatter<'_>, kw: &str, map: &[(String, String)]) -> fmt::Result {
+    writeln!(f, "    {} map (", kw)?;
+    for (i, (k, v)) in map.iter().enumerate() {
+        let sep = if i + 1 < map.len() { "," } else { "" };
+        writeln!(f, "      {} => {}{}", k, v, sep)?;
+    }
+    write!(f, "    )")
+}
+
+impl fmt::Display for Instance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "  {} : {}", name(&self.id), self.prim)?;
+        if !self.generic.is_empty() {
+            let generic: Vec<(String, String)> = self
+                .generic
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            write_map(f, "generic", &generic)?;
+            writeln!(f)?;
+        }
+        let port: Vec<(String, String)> = self
+            .port
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        write_map(f, "port", &port)?;
+        write!(f, ";")
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "library ieee;")?;
+        writeln!(f, "use ieee.std_logic_1164.all;")?;
+        writeln!(f, "use ieee.numeric_std.all;")?;
+        if self.has_instance() {
+            writeln!(f, "library unisim;")?;
+            writeln!(f, "use unisim.vcomponents.all;")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "entity {} is", name(&self.id))?;
+        writeln!(f, "  port (")?;
+        for (i, p) in self.port.iter().enumerate() {
+            let sep = if i + 1 < self.port.len() { ";" } else { "" };
+            writeln!(f, "    {} : {} {}{}", name(&p.id), p.dir, p.ty, sep)?;
+        }
+        writeln!(f, "  );")?;
+        writeln!(f, "end entity {};", name(&self.id))?;
+        writeln!(f)?;
+        writeln!(f, "architecture rtl of {} is", name(&self.id))?;
+        for s in self.signal.iter() {
+            writeln!(f, "  signal {} : {};", name(&s.id), s.ty)?;
+        }
+        // placement attributes of instances
+        let mut attr: BTreeSet<String> = BTreeSet::new();
+        for s in self.stmt.iter() {
+            if let Stmt::Instance(inst) = s {
+                attr.extend(inst.attr.iter().map(|(k, _)| k.to_string()));
+            }
+        }
+        for a in attr.iter() {
+            writeln!(f, "  attribute {} : string;", a)?;
+        }
+        for s in self.stmt.iter() {
+            if let Stmt::Instance(inst) = s {
+                for (k, v) in inst.attr.iter() {
+                    writeln!(
+                        f,
+                        "  attribute {} of {} : label is \"{}\";",
+                        k,
+                        name(&inst.id),
+                        v
+                    )?;
+                }
+            }
+        }
+        writeln!(f, "begin")?;
+        for s in self.stmt.iter() {
+            match s {
+                Stmt::Assign(lhs, rhs) => writeln!(f, "  {} <= {};", lhs, rhs)?,
+                Stmt::Instance(inst) => writeln!(f, "{}", inst)?,
+                Stmt::Process(proc) => {
+                    let sensitivity: Vec<String> =
+                        proc.sensitivity.iter().map(|s| name(s)).collect();
+                    writeln!(f, "  process ({})", sensitivity.join(", "))?;
+                    writeln!(f, "  begin")?;
+                    write_seq(f, &proc.body, 4)?;
+                    writeln!(f, "  end process;")?;
+                }
+            }
+        }
+        write!(f, "end architecture rtl;")
+    }
+}
@@ -0,0 +1,154 @@
+use crate::ast::*;
+
+impl Ty {
+    // single bits are std_logic and anything wider is a vector
+    pub fn from_width(width: u64) -> Ty {
+        if width == 1 {
+            Ty::Logic
+        } else {
+            Ty::Vector(width)
+        }
+    }
+    pub fn width(&self) -> u64 {
+        match self {
+            Ty::Logic => 1,
+            Ty::Vector(width) => *width,
+        }
+    }
+}
+
+impl Port {
+    pub fn new_input(id: &str, ty: Ty) -> Self {
+        Port {
+            id: id.to_string(),
+            dir: Dir::In,
+            ty,
+        }
+    }
+    pub fn new_output(id: &str, ty: Ty) -> Self {
+        Port {
+            id: id.to_string(),
+            dir: Dir::Out,
+            ty,
+        }
+    }
+}
+
+impl Signal {
+    pub fn new(id: &str, ty: Ty) -> Self {
+        Signal {
+            id: id.to_string(),
+            ty,
+        }
+    }
+}
+
+impl Expr {
+    pub fn new_ref(id: &str) -> Self {
+        Expr::Ref(id.to_string())
+    }
+    pub fn new_index(id: &str, index: u64) -> Self {
+        Expr::Index(id.to_string(), index)
+    }
+    pub fn new_slice(id: &str, msb: u64, lsb: u64) -> Self {
+        Expr::Slice(id.to_string(), msb, lsb)
+    }
+    pub fn new_logic(value: bool) -> Self {
+        Expr::Logic(value)
+    }
+    // two's complement bit string of width bits
+    pub fn new_bits(width: u64, value: i64) -> Self {
+        let bits: String = (0..width)
+            .rev()
+            .map(|i| {
+                if (value >> i.min(63)) & 1 == 1 {
+                    '1'
+                } else {
+                    '0'
+                }
+            })
+            .collect();
+        Expr::Bits(bits)
+    }
+    pub fn new_call(func: &str, arg: Vec<Expr>) -> Self {
+        Expr::Call(func.to_string(), arg)
+    }
+    pub fn new_binop(op: &str, lhs: Expr, rhs: Expr) -> Self {
+        Expr::Binop(op.to_string(), Box::new(lhs), Box::new(rhs))
+    }
+    pub fn new_not(expr: Expr) -> Self {
+        Expr::Not(Box::new(expr))
+    }
+    pub fn new_cond(value: Expr, cond: Expr, other: Expr) -> Self {
+        Expr::Cond(Box::new(value), Box::new(cond), Box::new(other))
+    }
+    // '1' when cond else '0'
+    pub fn new_logic_from_cond(cond: Expr) -> Self {
+        Expr::new_cond(Expr::new_logic(true), cond, Expr::new_logic(false))
+    }
+    // cond = '1'
+    pub fn new_is_one(cond: Expr) -> Self {
+        Expr::new_binop("=", cond, Expr::new_logic(true))
+    }
+}
+
+impl Process {
+    pub fn new(sensitivity: &[&str]) -> Self {
+        Process {
+            sensitivity: sensitivity.iter().map(|s| s.to_string()).collect(),
+            body: Vec::new(),
+        }
+    }
+    pub fn add_seq(&mut self, seq: Seq) {
+        self.body.push(seq);
+    }
+}
+
+impl Instance {
+    pub fn new(id: &str, prim: &str) -> Self {
+        Instance {
+            id: id.to_string(),
+            prim: prim.to_string(),
+            ..Instance::default()
+        }
+    }
+    pub fn add_generic(&mut self, id: &str, value: Expr) {
+        self.generic.push((id.to_string(), value));
+    }
+    pub fn connect(&mut self, formal: Expr, actual: Expr) {
+        self.port.push((formal, actual));
+    }
+    pub fn add_attr(&mut self, id: &str, value: &str) {
+        self.attr.push((id.to_string(), value.to_string()));
+    }
+}
+
+impl Module {
+    pub fn new(id: &str) -> Self {
+        Module {
+            id: id.to_string(),
+            ..Module::default()
+        }
+    }
+    pub fn port(&self) -> &Vec<Port> {
+        &self.port
+    }
+    pub fn signal(&self) -> &Vec<Signal> {
+        &self.signal
+    }
+    pub fn stmt(&self) -> &Vec<Stmt> {
+        &self.stmt
+    }
+    pub fn add_port(&mut self, port: Port) {
+        self.port.push(port);
+    }
+    pub fn add_signal(&mut self, signal: Signal) {
+        self.signal.push(signal);
+    }
+    pub fn add_stmt(&mut self, stmt: Stmt) {
+        self.stmt.push(stmt);
+    }
+    pub fn has_instance(&self) -> bool {
+        self.stmt.iter().any(|s| matches!(s, Stmt::Instance(_)))
+    }
+}
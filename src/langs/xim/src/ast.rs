@@ -16,6 +16,9 @@ pub type BelLut = xir::BelLut;
 pub type BelReg = xir::BelReg;
 pub type BelCarry = xir::BelCarry;
 pub type BelDsp = xir::BelDsp;
+pub type BelLram = xir::BelLram;
+pub type BelBram = xir::BelBram;
+pub type BelUram = xir::BelUram;
 pub type Bel = xir::Bel;
 pub type Loc = xir::Loc;
 pub type InstrMach = xir::InstrMach;
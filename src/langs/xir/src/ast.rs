@@ -31,7 +31,9 @@ pub enum OpMach {
     Lut6,
     Fdre,
     Fdse,
+    // arguments are DI and S, a + b needs S = a xor b
     CarryAdd,
+    // carry in set, a - b needs S = a xnor b
     CarrySub,
     VecAddRegA,
     VecAdd,
@@ -43,6 +45,9 @@ pub enum OpMach {
     MulAddRegACi,
     MulAddRegACo,
     MulAddRegACio,
+    Lram,
+    Bram,
+    Uram,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Eq, Hash)]
@@ -96,12 +101,30 @@ pub enum BelDsp {
     Alu,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Eq, Hash)]
+pub enum BelLram {
+    Ram64M8,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Eq, Hash)]
+pub enum BelBram {
+    Ramb18,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Eq, Hash)]
+pub enum BelUram {
+    Uram288,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Eq, Hash)]
 pub enum Bel {
     Lut(BelLut),
     Reg(BelReg),
     Carry(BelCarry),
     Dsp(BelDsp),
+    Lram(BelLram),
+    Bram(BelBram),
+    Uram(BelUram),
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Eq, Hash)]
@@ -18,6 +18,9 @@ display!(BelLut);
 display!(BelReg);
 display!(BelCarry);
 display!(BelDsp);
+display!(BelLram);
+display!(BelBram);
+display!(BelUram);
 display!(Bel);
 display!(Loc);
 display!(InstrBasc);
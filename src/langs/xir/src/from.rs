@@ -24,6 +24,24 @@ impl From<BelDsp> for Bel {
     }
 }
 
+impl From<BelLram> for Bel {
+    fn from(bel: BelLram) -> Self {
+        Bel::Lram(bel)
+    }
+}
+
+impl From<BelBram> for Bel {
+    fn from(bel: BelBram) -> Self {
+        Bel::Bram(bel)
+    }
+}
+
+impl From<BelUram> for Bel {
+    fn from(bel: BelUram) -> Self {
+        Bel::Uram(bel)
+    }
+}
+
 impl From<InstrBasc> for Instr {
     fn from(instr: InstrBasc) -> Self {
         Instr::Basc(instr)
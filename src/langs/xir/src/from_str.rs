@@ -42,6 +42,9 @@ impl FromStr for OpMach {
             "muladdregaci" => Ok(OpMach::MulAddRegACi),
             "muladdregaco" => Ok(OpMach::MulAddRegACo),
             "muladdregacio" => Ok(OpMach::MulAddRegACio),
+            "lram" => Ok(OpMach::Lram),
+            "bram" => Ok(OpMach::Bram),
+            "uram" => Ok(OpMach::Uram),
             _ => Err(Error::new_conv_error(&err)),
         }
     }
@@ -122,6 +125,39 @@ impl FromStr for BelDsp {
     }
 }
 
+impl FromStr for BelLram {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let err = format!("Error: {} is not valid lram bel", input);
+        match input {
+            "ram64m8" => Ok(BelLram::Ram64M8),
+            _ => Err(Error::new_conv_error(&err)),
+        }
+    }
+}
+
+impl FromStr for BelBram {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let err = format!("Error: {} is not valid bram bel", input);
+        match input {
+            "ramb18" => Ok(BelBram::Ramb18),
+            _ => Err(Error::new_conv_error(&err)),
+        }
+    }
+}
+
+impl FromStr for BelUram {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let err = format!("Error: {} is not valid uram bel", input);
+        match input {
+            "uram288" => Ok(BelUram::Uram288),
+            _ => Err(Error::new_conv_error(&err)),
+        }
+    }
+}
+
 impl FromStr for Bel {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
@@ -134,6 +170,12 @@ impl FromStr for Bel {
             Ok(Bel::from(bel))
         } else if let Ok(bel) = BelDsp::from_str(input) {
             Ok(Bel::from(bel))
+        } else if let Ok(bel) = BelLram::from_str(input) {
+            Ok(Bel::from(bel))
+        } else if let Ok(bel) = BelBram::from_str(input) {
+            Ok(Bel::from(bel))
+        } else if let Ok(bel) = BelUram::from_str(input) {
+            Ok(Bel::from(bel))
         } else {
             Err(Error::new_conv_error(&err))
         }
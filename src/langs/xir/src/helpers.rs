@@ -16,6 +16,9 @@ impl OpMach {
                 | OpMach::MulAddRegACio
         )
     }
+    pub fn is_ram(&self) -> bool {
+        matches!(self, OpMach::Lram | OpMach::Bram | OpMach::Uram)
+    }
 }
 
 impl Loc {
@@ -82,6 +85,9 @@ impl InstrMach {
     pub fn set_loc(&mut self, loc: Loc) {
         self.loc = Some(loc);
     }
+    pub fn clear_loc(&mut self) {
+        self.loc = None;
+    }
     pub fn set_dst(&mut self, dst: Expr) {
         self.dst = dst;
     }
@@ -37,6 +37,9 @@ impl PrettyPrint for OpMach {
             OpMach::MulAddRegACi => RcDoc::text("muladdregaci"),
             OpMach::MulAddRegACo => RcDoc::text("muladdregaco"),
             OpMach::MulAddRegACio => RcDoc::text("muladdregacio"),
+            OpMach::Lram => RcDoc::text("lram"),
+            OpMach::Bram => RcDoc::text("bram"),
+            OpMach::Uram => RcDoc::text("uram"),
         }
     }
 }
@@ -104,6 +107,30 @@ impl PrettyPrint for BelDsp {
     }
 }
 
+impl PrettyPrint for BelLram {
+    fn to_doc(&self) -> RcDoc<()> {
+        match self {
+            BelLram::Ram64M8 => RcDoc::text("ram64m8"),
+        }
+    }
+}
+
+impl PrettyPrint for BelBram {
+    fn to_doc(&self) -> RcDoc<()> {
+        match self {
+            BelBram::Ramb18 => RcDoc::text("ramb18"),
+        }
+    }
+}
+
+impl PrettyPrint for BelUram {
+    fn to_doc(&self) -> RcDoc<()> {
+        match self {
+            BelUram::Uram288 => RcDoc::text("uram288"),
+        }
+    }
+}
+
 impl PrettyPrint for Bel {
     fn to_doc(&self) -> RcDoc<()> {
         match self {
@@ -111,6 +138,9 @@ impl PrettyPrint for Bel {
             Bel::Reg(reg) => reg.to_doc(),
             Bel::Carry(carry) => carry.to_doc(),
             Bel::Dsp(dsp) => dsp.to_doc(),
+            Bel::Lram(lram) => lram.to_doc(),
+            Bel::Bram(bram) => bram.to_doc(),
+            Bel::Uram(uram) => uram.to_doc(),
         }
     }
 }
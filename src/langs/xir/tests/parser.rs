@@ -8,3 +8,10 @@ fn simple_prog() {
     exp.set_id("main");
     assert_eq!(res, exp);
 }
+
+#[test]
+fn mem_prog() {
+    let prog = Parser::parse_from_file("../../../examples/xir/mem.xir").unwrap();
+    let res = Parser::parse_from_str(&prog.to_string()).unwrap();
+    assert_eq!(res, prog);
+}
@@ -0,0 +1,3 @@
+pub use compiler::compiler::{Compiler, Options};
+pub use compiler::errors::Error;
+pub use compiler::session::{Diagnostic, Level, Session, Stage, Stats};
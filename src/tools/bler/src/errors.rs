@@ -1,6 +1,7 @@
 use asm::errors::Error as AsmError;
 use ir::errors::Error as IrError;
 use std::fmt;
+use std::num::TryFromIntError;
 use xir::errors::Error as XirError;
 
 #[derive(Debug)]
@@ -8,6 +9,7 @@ pub enum Error {
     Ir(IrError),
     Asm(AsmError),
     Xir(XirError),
+    TryFromInt(TryFromIntError),
     Bler(String),
 }
 
@@ -35,12 +37,19 @@ impl From<IrError> for Error {
     }
 }
 
+impl From<TryFromIntError> for Error {
+    fn from(e: TryFromIntError) -> Self {
+        Error::TryFromInt(e)
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::Ir(msg) => write!(f, "{}", msg),
             Error::Asm(msg) => write!(f, "{}", msg),
             Error::Xir(msg) => write!(f, "{}", msg),
+            Error::TryFromInt(msg) => write!(f, "{}", msg),
             Error::Bler(msg) => write!(f, "{}", msg),
         }
     }
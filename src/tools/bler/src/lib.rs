@@ -3,12 +3,20 @@ pub mod errors;
 use crate::errors::Error;
 use asm::ast as asm;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::path::Path;
 use xim::ast as xim;
 use xir::ast as xir;
 
 type Scope = HashMap<xir::ExprTerm, xir::ExprTerm>;
 
+// a single bit, either taken from a term at some index or tied to ground
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Bit {
+    Ext(xir::ExprTerm, u64),
+    Gnd,
+}
+
 pub fn scope_from_expr(left: &xir::Expr, right: &xir::Expr) -> Scope {
     let mut scope = Scope::new();
     let left: Vec<xir::ExprTerm> = left.clone().into();
@@ -19,6 +27,12 @@ pub fn scope_from_expr(left: &xir::Expr, right: &xir::Expr) -> Scope {
     scope
 }
 
+fn expr_tup_from_term(term: &xir::ExprTerm) -> xir::Expr {
+    let mut tup = xir::ExprTup::default();
+    tup.add_term(term.clone());
+    xir::Expr::from(tup)
+}
+
 #[derive(Clone, Debug)]
 pub struct Assembler {
     pub count: u64,
@@ -139,20 +153,26 @@ impl Assembler {
         Ok(())
     }
     pub fn expand_instr_const(&mut self, instr: &asm::InstrWire) -> Result<(), Error> {
-        let attr_term = instr.attr().get_term(0)?;
-        let value = attr_term.get_val()?;
+        let value = instr.const_values()?;
         let dst_term = instr.dst().get_term(0)?;
         let mut arg_tup = xir::ExprTup::default();
         if let Some(width) = dst_term.width() {
-            for i in 0..width {
-                let lsb = value >> i;
-                let mask = lsb & 1;
-                let op = if mask == 1 {
+            // two's complement bits of every lane, lsb first
+            let mut bits: Vec<bool> = Vec::new();
+            for v in value.iter() {
+                for i in 0..width {
+                    let lsb = v >> i.min(63);
+                    bits.push(lsb & 1 == 1);
+                }
+            }
+            let single = bits.len() == 1;
+            for bit in bits {
+                let op = if bit {
                     xir::OpBasc::Vcc
                 } else {
                     xir::OpBasc::Gnd
                 };
-                let name = if width == 1 {
+                let name = if single {
                     let old_id = dst_term.get_id()?;
                     if let Some(id) = self.get_var(&old_id) {
                         id.to_string()
@@ -174,7 +194,7 @@ impl Assembler {
                 };
                 self.add_instr(xir::Instr::from(instr_basc));
             }
-            if width > 1 {
+            if !single {
                 let old_id = dst_term.get_id()?;
                 let dst_id = if let Some(id) = self.get_var(&old_id) {
                     id.to_string()
@@ -195,6 +215,153 @@ impl Assembler {
         }
         Ok(())
     }
+    fn add_instr_basc(&mut self, op: xir::OpBasc, attr: xir::Expr, dst: xir::Expr, arg: xir::Expr) {
+        let instr = xir::InstrBasc { op, attr, dst, arg };
+        self.add_instr(xir::Instr::from(instr));
+    }
+    // create a term for bit, using dst if there is one
+    fn expand_bit(
+        &mut self,
+        bit: &Bit,
+        dst: Option<xir::ExprTerm>,
+    ) -> Result<xir::ExprTerm, Error> {
+        let new = if let Some(term) = dst.clone() {
+            term
+        } else {
+            xir::ExprTerm::Var(self.new_var(), xir::Ty::Bool)
+        };
+        match bit {
+            Bit::Ext(term, _) if term.width() == Some(1) => {
+                if dst.is_some() {
+                    let arg = expr_tup_from_term(term);
+                    self.add_instr_basc(
+                        xir::OpBasc::Id,
+                        xir::Expr::default(),
+                        xir::Expr::from(new.clone()),
+                        arg,
+                    );
+                    Ok(new)
+                } else {
+                    Ok(term.clone())
+                }
+            }
+            Bit::Ext(term, index) => {
+                let mut attr = xir::ExprTup::default();
+                attr.add_term(xir::ExprTerm::Val(i64::try_from(*index)?));
+                let arg = expr_tup_from_term(term);
+                self.add_instr_basc(
+                    xir::OpBasc::Ext,
+                    xir::Expr::from(attr),
+                    xir::Expr::from(new.clone()),
+                    arg,
+                );
+                Ok(new)
+            }
+            Bit::Gnd => {
+                self.add_instr_basc(
+                    xir::OpBasc::Gnd,
+                    xir::Expr::default(),
+                    xir::Expr::from(new.clone()),
+                    xir::Expr::default(),
+                );
+                Ok(new)
+            }
+        }
+    }
+    // assign bits to dst, lsb first
+    fn expand_bits(&mut self, dst: &xir::ExprTerm, bits: &[Bit]) -> Result<(), Error> {
+        if dst.width() != Some(bits.len() as u64) {
+            let err = format!("{} must have width {}", dst, bits.len());
+            return Err(Error::new_bler_error(&err));
+        }
+        if bits.len() == 1 {
+            self.expand_bit(&bits[0], Some(dst.clone()))?;
+        } else {
+            // bits used more than once are only created once
+            let mut expanded: HashMap<Bit, xir::ExprTerm> = HashMap::new();
+            let mut arg = xir::ExprTup::default();
+            for b in bits {
+                let term = if let Some(term) = expanded.get(b) {
+                    term.clone()
+                } else {
+                    let term = self.expand_bit(b, None)?;
+                    expanded.insert(b.clone(), term.clone());
+                    term
+                };
+                arg.add_term(term);
+            }
+            self.add_instr_basc(
+                xir::OpBasc::Cat,
+                xir::Expr::default(),
+                xir::Expr::from(dst.clone()),
+                xir::Expr::from(arg),
+            );
+        }
+        Ok(())
+    }
+    fn scalar_width(term: &xir::ExprTerm) -> Result<u64, Error> {
+        match term.width() {
+            Some(width) if !term.is_vector() => Ok(width),
+            _ => {
+                let err = format!(
+                    "{} must be a scalar, vectors are not supported in wire ops",
+                    term
+                );
+                Err(Error::new_bler_error(&err))
+            }
+        }
+    }
+    pub fn expand_instr_wire(&mut self, instr: &asm::InstrWire) -> Result<(), Error> {
+        let arg: Vec<xir::ExprTerm> = self.rename_expr(instr.arg())?.into();
+        let dst = self.rename_term(instr.dst().get_term(0)?)?;
+        let width = Assembler::scalar_width(&dst)?;
+        let mut bits: Vec<Bit> = Vec::new();
+        match instr.op() {
+            asm::OpWire::Ext => {
+                let src = instr.arg().get_term(0)?;
+                let lsb = u64::try_from(instr.attr().get_val(0)?)?;
+                let src_width = Assembler::scalar_width(src)?;
+                if lsb + width > src_width {
+                    let err = format!("ext[{}] of {} is out of range", lsb, src);
+                    return Err(Error::new_bler_error(&err));
+                }
+                for i in 0..width {
+                    bits.push(Bit::Ext(arg[0].clone(), lsb + i));
+                }
+            }
+            asm::OpWire::Cat => {
+                for a in arg.iter() {
+                    for i in 0..Assembler::scalar_width(a)? {
+                        bits.push(Bit::Ext(a.clone(), i));
+                    }
+                }
+            }
+            asm::OpWire::Sll | asm::OpWire::Srl | asm::OpWire::Sra => {
+                let src = instr.arg().get_term(0)?;
+                let shift = u64::try_from(instr.attr().get_val(0)?)?;
+                if Assembler::scalar_width(src)? != width {
+                    let err = format!("{} and {} must have the same width", src, dst);
+                    return Err(Error::new_bler_error(&err));
+                }
+                for i in 0..width {
+                    let bit = match instr.op() {
+                        asm::OpWire::Sll if i >= shift => Bit::Ext(arg[0].clone(), i - shift),
+                        asm::OpWire::Srl | asm::OpWire::Sra if i + shift < width => {
+                            Bit::Ext(arg[0].clone(), i + shift)
+                        }
+                        asm::OpWire::Sra => Bit::Ext(arg[0].clone(), width - 1),
+                        _ => Bit::Gnd,
+                    };
+                    bits.push(bit);
+                }
+            }
+            asm::OpWire::Id | asm::OpWire::Con => {
+                let err = format!("{} is not a bit wire op", instr.op());
+                return Err(Error::new_bler_error(&err));
+            }
+        }
+        self.expand_bits(&dst, &bits)
+    }
     pub fn expand_instr_asm(&mut self, instr: &asm::InstrAsm) -> Result<(), Error> {
         let instr = self.rename_instr_asm(instr)?;
         if let Some(imp) = self.get_target_imp(&instr.op().to_string()) {
@@ -234,18 +401,18 @@ impl Assembler {
                 };
                 match i {
                     xir::Instr::Mach(mach) => {
+                        let mut instr_mach = mach.clone();
                         if let Some(loc) = mach.loc() {
                             let mut loc = loc.clone();
                             let x = instr.loc().x().clone();
                             let y = instr.loc().y().clone();
                             loc.set_x(x);
                             loc.set_y(y);
-                            let mut instr_mach = mach.clone();
                             instr_mach.set_loc(loc);
-                            instr_mach.set_arg(arg_expr);
-                            instr_mach.set_dst(dst_expr);
-                            self.add_instr(xir::Instr::from(instr_mach));
                         }
+                        instr_mach.set_arg(arg_expr);
+                        instr_mach.set_dst(dst_expr);
+                        self.add_instr(xir::Instr::from(instr_mach));
                     }
                     _ => {
                         let mut instr_xir = i.clone();
@@ -255,8 +422,11 @@ impl Assembler {
                     }
                 }
             }
+            Ok(())
+        } else {
+            let err = format!("{} is not supported by the target", instr.op());
+            Err(Error::new_bler_error(&err))
         }
-        Ok(())
     }
 }
 
@@ -285,8 +455,8 @@ pub fn try_from_asm_prog(input: &asm::Prog) -> Result<xir::Prog, Error> {
             asm::Instr::Wire(instr) if instr.op() == &asm::OpWire::Id => {
                 assembler.expand_instr_id(instr)?;
             }
+            asm::Instr::Wire(instr) => assembler.expand_instr_wire(instr)?,
             asm::Instr::Asm(instr) => assembler.expand_instr_asm(instr)?,
-            _ => (),
         }
     }
     let mut prog = xir::Prog::default();
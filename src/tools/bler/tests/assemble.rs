@@ -95,3 +95,13 @@ fn tdot_5_18() -> Result<(), Error> {
 fn tdot_5_36() -> Result<(), Error> {
     test("tdot_5_36")
 }
+
+#[test]
+fn wire() -> Result<(), Error> {
+    test("wire")
+}
+
+#[test]
+fn const_vector() -> Result<(), Error> {
+    test("const")
+}
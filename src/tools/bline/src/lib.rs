@@ -1,6 +1,8 @@
 pub mod errors;
+pub mod vhdl;
 
 use crate::errors::Error;
+use ::ir::clock::Clock;
 use ir::ast as ir;
 use itertools::izip;
 use std::collections::HashSet;
@@ -8,9 +10,6 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use verilog::ast as vl;
 
-const CLOCK: &str = "clock";
-const RESET: &str = "reset";
-
 pub fn vec_expr_try_from_term(term: &ir::ExprTerm) -> Result<Vec<vl::Expr>, Error> {
     match term {
         ir::ExprTerm::Any => Ok(vec![vl::Expr::new_ref("")]),
@@ -125,10 +124,12 @@ pub fn reg_try_from_expr(expr: &ir::Expr) -> Result<Vec<vl::Decl>, Error> {
     }
 }
 
-pub fn input_try_from_sig(sig: &ir::Sig) -> Result<Vec<vl::Port>, Error> {
+pub fn input_try_from_sig(sig: &ir::Sig, clock: &Clock) -> Result<Vec<vl::Port>, Error> {
     let mut port: Vec<vl::Port> = Vec::new();
-    port.push(vl::Port::Input(vl::Decl::new_wire(CLOCK, 1)));
-    port.push(vl::Port::Input(vl::Decl::new_wire(RESET, 1)));
+    port.push(vl::Port::Input(vl::Decl::new_wire(&clock.clock(), 1)));
+    if clock.has_reset() {
+        port.push(vl::Port::Input(vl::Decl::new_wire(&clock.reset(), 1)));
+    }
     let input: Vec<vl::Decl> = wire_try_from_expr(sig.input())?;
     for decl in input {
         port.push(vl::Port::Input(decl.clone()));
@@ -155,7 +156,67 @@ pub fn vec_decl_try_from_instr(instr: &ir::Instr) -> Result<Vec<vl::Decl>, Error
     }
 }
 
-fn vec_stmt_try_from_instr_prim(instr: &ir::InstrPrim) -> Result<Vec<vl::Stmt>, Error> {
+// clock event of registers, vast events are single edge so the
+// asynchronous reset edge is part of the signal name
+fn event_from_clock(clock: &Clock) -> vl::Sequential {
+    if clock.is_async() {
+        let edge = if clock.is_active_low() {
+            "negedge"
+        } else {
+            "posedge"
+        };
+        let event = format!("{} or {} {}", clock.clock(), edge, clock.reset());
+        vl::Sequential::new_posedge(&event)
+    } else {
+        vl::Sequential::new_posedge(&clock.clock())
+    }
+}
+
+fn reset_expr_from_clock(clock: &Clock) -> vl::Expr {
+    let reset = vl::Expr::new_ref(&clock.reset());
+    if clock.is_active_low() {
+        vl::Expr::new_eq(reset, vl::Expr::new_ulit_bin(1, "0"))
+    } else {
+        reset
+    }
+}
+
+// lanes of a binary op, signed operands are wrapped in $signed so
+// comparisons follow their type
+fn vec_stmt_try_from_binop<F>(
+    instr: &ir::InstrPrim,
+    signed: bool,
+    op: F,
+) -> Result<Vec<vl::Stmt>, Error>
+where
+    F: Fn(vl::Expr, vl::Expr) -> vl::Expr,
+{
+    let term_y = instr.dst().get_term(0)?;
+    let term_a = instr.arg().get_term(0)?;
+    let term_b = instr.arg().get_term(1)?;
+    let expr_y: Vec<vl::Expr> = vec_expr_try_from_term(term_y)?;
+    let (expr_a, expr_b) = if signed {
+        (
+            sign_expr_try_from_term(term_a.clone())?,
+            sign_expr_try_from_term(term_b.clone())?,
+        )
+    } else {
+        (
+            vec_expr_try_from_term(term_a)?,
+            vec_expr_try_from_term(term_b)?,
+        )
+    };
+    let mut stmt: Vec<vl::Stmt> = Vec::new();
+    for (y, a, b) in izip!(expr_y, expr_a, expr_b) {
+        stmt.push(vl::Stmt::from(vl::Parallel::Assign(y, op(a, b))));
+    }
+    Ok(stmt)
+}
+
+fn vec_stmt_try_from_instr_prim(
+    instr: &ir::InstrPrim,
+    clock: &Clock,
+) -> Result<Vec<vl::Stmt>, Error> {
     match instr.op() {
         ir::OpPrim::Reg => {
             let attr: Vec<i32> = instr.attr().clone().try_into()?;
@@ -163,8 +224,8 @@ fn vec_stmt_try_from_instr_prim(instr: &ir::InstrPrim) -> Result<Vec<vl::Stmt>,
                 if let Some(a0) = instr.arg().idx(0) {
                     if let Some(en) = instr.arg().idx(1) {
                         let v0: i32 = if let Some(v) = attr.get(0) { *v } else { 0 };
-                        let event = vl::Sequential::new_posedge(CLOCK);
-                        let rst_expr = vl::Expr::new_ref(RESET);
+                        let event = event_from_clock(clock);
+                        let rst_expr = reset_expr_from_clock(clock);
                         let ena_id: vl::Id = en.clone().try_into()?;
                         let ena_expr = vl::Expr::new_ref(&ena_id);
                         let dst: Vec<vl::Expr> = vec_expr_try_from_term(d0)?;
@@ -175,13 +236,17 @@ fn vec_stmt_try_from_instr_prim(instr: &ir::InstrPrim) -> Result<Vec<vl::Stmt>,
                             let mut always = vl::ParallelProcess::new_always();
                             let s0 = vl::Sequential::new_nonblk_assign(d.clone(), val_expr.clone());
                             let s1 = vl::Sequential::new_nonblk_assign(d.clone(), a.clone());
-                            let mut i0 = vl::SequentialIfElse::new(rst_expr.clone());
                             let mut i1 = vl::SequentialIfElse::new(ena_expr.clone());
-                            i0.add_seq(s0);
                             i1.add_seq(s1);
-                            i0.set_else(i1.into());
                             always.set_event(event.clone());
-                            always.add_seq(i0.into());
+                            if clock.has_reset() {
+                                let mut i0 = vl::SequentialIfElse::new(rst_expr.clone());
+                                i0.add_seq(s0);
+                                i0.set_else(i1.into());
+                                always.add_seq(i0.into());
+                            } else {
+                                always.add_seq(i1.into());
+                            }
                             stmt.push(vl::Stmt::from(always));
                         }
                         Ok(stmt)
@@ -289,32 +354,119 @@ fn vec_stmt_try_from_instr_prim(instr: &ir::InstrPrim) -> Result<Vec<vl::Stmt>,
                 Err(Error::new_bline_error("mux sel do not have right type"))
             }
         }
-        _ => Err(Error::new_bline_error("comp op not implemented yet")),
+        ir::OpPrim::Sub => vec_stmt_try_from_binop(instr, false, vl::Expr::new_sub),
+        ir::OpPrim::Or => vec_stmt_try_from_binop(instr, false, vl::Expr::new_bit_or),
+        ir::OpPrim::Xor => vec_stmt_try_from_binop(instr, false, vl::Expr::new_bit_xor),
+        ir::OpPrim::Neql => vec_stmt_try_from_binop(instr, false, vl::Expr::new_neq),
+        ir::OpPrim::Gt => vec_stmt_try_from_binop(instr, true, vl::Expr::new_gt),
+        ir::OpPrim::Lt => vec_stmt_try_from_binop(instr, true, vl::Expr::new_lt),
+        ir::OpPrim::Ge => vec_stmt_try_from_binop(instr, true, vl::Expr::new_geq),
+        ir::OpPrim::Le => vec_stmt_try_from_binop(instr, true, vl::Expr::new_leq),
+        // bitwise not, as an xor with all ones
+        ir::OpPrim::Not => {
+            let term_y = instr.dst().get_term(0)?;
+            let term_a = instr.arg().get_term(0)?;
+            let width = width_try_from_term(term_a)?;
+            let ones = literal_from_val(width, -1);
+            let expr_y: Vec<vl::Expr> = vec_expr_try_from_term(term_y)?;
+            let expr_a: Vec<vl::Expr> = vec_expr_try_from_term(term_a)?;
+            let mut stmt: Vec<vl::Stmt> = Vec::new();
+            for (y, a) in izip!(expr_y, expr_a) {
+                let not = vl::Expr::new_bit_xor(a, ones.clone());
+                stmt.push(vl::Stmt::from(vl::Parallel::Assign(y, not)));
+            }
+            Ok(stmt)
+        }
+        ir::OpPrim::Ram => Err(Error::new_bline_error(
+            "ram is not supported in behavioral Verilog yet",
+        )),
+    }
+}
+
+// negative values are written in binary, as two's complement
+fn literal_from_val(width: u32, value: i64) -> vl::Expr {
+    if value < 0 {
+        let bits: String = (0..width)
+            .rev()
+            .map(|i| {
+                if (value >> i.min(63)) & 1 == 1 {
+                    '1'
+                } else {
+                    '0'
+                }
+            })
+            .collect();
+        vl::Expr::new_ulit_bin(width, &bits)
+    } else {
+        vl::Expr::new_ulit_dec(width, &value.to_string())
     }
 }
 
+fn width_try_from_term(term: &ir::ExprTerm) -> Result<u32, Error> {
+    if let Some(width) = term.width() {
+        Ok(u32::try_from(width)?)
+    } else {
+        Err(Error::new_bline_error("type does not have width"))
+    }
+}
+
+// shifts by a constant are concats of a slice and the fill bits, zeros
+// or copies of the sign bit for sra
+fn shift_expr_from_name(op: &ir::OpWire, name: &str, width: u32, shift: u32) -> vl::Expr {
+    if shift == 0 {
+        return vl::Expr::new_ref(name);
+    }
+    let msb = width - 1;
+    let mut concat = vl::ExprConcat::default();
+    if shift >= width {
+        if *op == ir::OpWire::Sra {
+            for _ in 0..width {
+                concat.add_expr(vl::Expr::new_index_bit(name, msb as i32));
+            }
+            return vl::Expr::from(concat);
+        }
+        return literal_from_val(width, 0);
+    }
+    let zero = literal_from_val(shift, 0);
+    match op {
+        ir::OpWire::Sll => {
+            let hi = vl::Expr::new_int((msb - shift) as i32);
+            concat.add_expr(zero);
+            concat.add_expr(vl::Expr::new_slice(name, hi, vl::Expr::new_int(0)));
+        }
+        ir::OpWire::Srl => {
+            let lo = vl::Expr::new_int(shift as i32);
+            concat.add_expr(vl::Expr::new_slice(name, vl::Expr::new_int(msb as i32), lo));
+            concat.add_expr(zero);
+        }
+        _ => {
+            let lo = vl::Expr::new_int(shift as i32);
+            concat.add_expr(vl::Expr::new_slice(name, vl::Expr::new_int(msb as i32), lo));
+            for _ in 0..shift {
+                concat.add_expr(vl::Expr::new_index_bit(name, msb as i32));
+            }
+        }
+    }
+    vl::Expr::from(concat)
+}
+
 fn vec_stmt_try_from_instr_wire(instr: &ir::InstrWire) -> Result<Vec<vl::Stmt>, Error> {
     match instr.op() {
-        // TODO: impl vector constant
-        // TOOD: impl negative constant
         ir::OpWire::Con => {
             let dst = instr.dst().get_term(0)?;
             let ty = dst.get_ty()?;
             let dst: Vec<vl::Expr> = vec_expr_try_from_term(dst)?;
-            let mut stmt: Vec<vl::Stmt> = Vec::new();
-            if dst.len() == 1 {
-                let attr = instr.attr().get_term(0)?;
-                let val = u32::try_from(attr.get_val()?)?;
-                if let Some(width) = ty.width() {
-                    let width = u32::try_from(width)?;
-                    let num = vl::Expr::new_ulit_dec(width, &val.to_string());
-                    stmt.push(vl::Stmt::from(vl::Parallel::Assign(dst[0].clone(), num)));
-                    Ok(stmt)
-                } else {
-                    Err(Error::new_bline_error("type does not have width"))
+            let value = instr.const_values()?;
+            if let Some(width) = ty.width() {
+                let width = u32::try_from(width)?;
+                let mut stmt: Vec<vl::Stmt> = Vec::new();
+                for (d, v) in izip!(dst, value) {
+                    let num = literal_from_val(width, v);
+                    stmt.push(vl::Stmt::from(vl::Parallel::Assign(d, num)));
                 }
+                Ok(stmt)
             } else {
-                Err(Error::new_bline_error("vector not supported yet"))
+                Err(Error::new_bline_error("type does not have width"))
             }
         }
         ir::OpWire::Id => {
@@ -328,22 +480,81 @@ fn vec_stmt_try_from_instr_wire(instr: &ir::InstrWire) -> Result<Vec<vl::Stmt>,
             }
             Ok(stmt)
         }
-        _ => Err(Error::new_bline_error("wire op not implemented yet")),
+        ir::OpWire::Sll | ir::OpWire::Srl | ir::OpWire::Sra => {
+            let term_y = instr.dst().get_term(0)?;
+            let term_a = instr.arg().get_term(0)?;
+            let width = width_try_from_term(term_a)?;
+            let shift = u32::try_from(instr.attr().get_val(0)?)?;
+            let expr_y: Vec<vl::Expr> = vec_expr_try_from_term(term_y)?;
+            let expr_a: Vec<vl::Expr> = vec_expr_try_from_term(term_a)?;
+            let mut stmt: Vec<vl::Stmt> = Vec::new();
+            for (y, a) in izip!(expr_y, expr_a) {
+                let shift = shift_expr_from_name(instr.op(), &a.id(), width, shift);
+                stmt.push(vl::Stmt::from(vl::Parallel::Assign(y, shift)));
+            }
+            Ok(stmt)
+        }
+        // bits of every lane of the argument starting at lsb, zero past
+        // its msb
+        ir::OpWire::Ext => {
+            let term_y = instr.dst().get_term(0)?;
+            let term_a = instr.arg().get_term(0)?;
+            let width_y = width_try_from_term(term_y)?;
+            let width_a = width_try_from_term(term_a)?;
+            let lsb = u32::try_from(instr.attr().get_val(0)?)?;
+            let expr_y: Vec<vl::Expr> = vec_expr_try_from_term(term_y)?;
+            let expr_a: Vec<vl::Expr> = vec_expr_try_from_term(term_a)?;
+            let mut stmt: Vec<vl::Stmt> = Vec::new();
+            for (y, a) in izip!(expr_y, expr_a) {
+                let expr = if lsb >= width_a {
+                    literal_from_val(width_y, 0)
+                } else {
+                    let msb = (lsb + width_y - 1).min(width_a - 1);
+                    vl::Expr::new_slice(
+                        &a.id(),
+                        vl::Expr::new_int(msb as i32),
+                        vl::Expr::new_int(lsb as i32),
+                    )
+                };
+                stmt.push(vl::Stmt::from(vl::Parallel::Assign(y, expr)));
+            }
+            Ok(stmt)
+        }
+        // the first argument is the least significant one
+        ir::OpWire::Cat => {
+            let term_y = instr.dst().get_term(0)?;
+            let y = vec_expr_try_from_term(term_y)?[0].clone();
+            let mut concat = vl::ExprConcat::default();
+            let arg: Vec<ir::ExprTerm> = instr.arg().clone().into();
+            for term in arg.iter() {
+                for a in vec_expr_try_from_term(term)? {
+                    concat.add_expr(a);
+                }
+            }
+            Ok(vec![vl::Stmt::from(vl::Parallel::Assign(
+                y,
+                vl::Expr::from(concat),
+            ))])
+        }
     }
 }
 
-fn vec_stmt_try_from_instr(instr: &ir::Instr) -> Result<Vec<vl::Stmt>, Error> {
+fn vec_stmt_try_from_instr(instr: &ir::Instr, clock: &Clock) -> Result<Vec<vl::Stmt>, Error> {
     match instr {
-        ir::Instr::Prim(instr) => Ok(vec_stmt_try_from_instr_prim(instr)?),
+        ir::Instr::Prim(instr) => Ok(vec_stmt_try_from_instr_prim(instr, clock)?),
         ir::Instr::Wire(instr) => Ok(vec_stmt_try_from_instr_wire(instr)?),
         ir::Instr::Call(_) => Err(Error::new_bline_error("call instr not implemented yet")),
     }
 }
 
 pub fn behav_try_from_ir_def(def: &ir::Def) -> Result<vl::Module, Error> {
+    behav_try_from_ir_def_with_clock(def, &Clock::default())
+}
+
+pub fn behav_try_from_ir_def_with_clock(def: &ir::Def, clock: &Clock) -> Result<vl::Module, Error> {
     let id = def.sig().id();
     let mut module = vl::Module::new(&id);
-    let input = input_try_from_sig(def.sig())?;
+    let input = input_try_from_sig(def.sig(), clock)?;
     for i in input {
         module.add_port(i.clone());
     }
@@ -362,7 +573,7 @@ pub fn behav_try_from_ir_def(def: &ir::Def) -> Result<vl::Module, Error> {
                 }
             }
         }
-        let s: Vec<vl::Stmt> = vec_stmt_try_from_instr(instr)?;
+        let s: Vec<vl::Stmt> = vec_stmt_try_from_instr(instr, clock)?;
         stmt.extend(s);
     }
     for s in stmt {
@@ -372,8 +583,12 @@ pub fn behav_try_from_ir_def(def: &ir::Def) -> Result<vl::Module, Error> {
 }
 
 pub fn try_from_ir_prog(prog: &ir::Prog) -> Result<vl::Module, Error> {
+    try_from_ir_prog_with_clock(prog, &Clock::default())
+}
+
+pub fn try_from_ir_prog_with_clock(prog: &ir::Prog, clock: &Clock) -> Result<vl::Module, Error> {
     if let Some(def) = prog.get("main") {
-        Ok(behav_try_from_ir_def(def)?)
+        Ok(behav_try_from_ir_def_with_clock(def, clock)?)
     } else {
         Err(Error::new_bline_error("main not found"))
     }
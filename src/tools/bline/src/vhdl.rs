@@ -0,0 +1,369 @@
+use crate::errors::Error;
+use ::ir::clock::{Clock, ResetTy};
+use ir::ast as ir;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use vhdl::ast as vh;
+
+// single bits are std_logic and anything wider is a vector, the same
+// as wires in the Verilog backend
+fn ty_try_from_term(term: &ir::ExprTerm) -> Result<vh::Ty, Error> {
+    if let Some(width) = term.width() {
+        Ok(vh::Ty::from_width(width))
+    } else {
+        let err = format!("{} must have a width", term);
+        Err(Error::new_bline_error(&err))
+    }
+}
+
+// names of the lanes of a term, vector lanes are separate signals
+fn names_try_from_term(term: &ir::ExprTerm) -> Result<Vec<String>, Error> {
+    let id = term.get_id()?;
+    if let Some(length) = term.length() {
+        Ok((0..length).map(|n| format!("{}_{}", id, n)).collect())
+    } else {
+        Ok(vec![id])
+    }
+}
+
+fn exprs_try_from_term(term: &ir::ExprTerm) -> Result<Vec<vh::Expr>, Error> {
+    Ok(names_try_from_term(term)?
+        .iter()
+        .map(|n| vh::Expr::new_ref(n))
+        .collect())
+}
+
+// numeric_std view of a vector, following the sign of its type
+fn num_try_from_term(term: &ir::ExprTerm, expr: vh::Expr) -> Result<vh::Expr, Error> {
+    match ty_try_from_term(term)? {
+        vh::Ty::Logic => Ok(expr),
+        _ if term.get_ty()?.is_signed() => Ok(vh::Expr::new_call("signed", vec![expr])),
+        _ => Ok(vh::Expr::new_call("unsigned", vec![expr])),
+    }
+}
+
+fn slv_from_expr(expr: vh::Expr) -> vh::Expr {
+    vh::Expr::new_call("std_logic_vector", vec![expr])
+}
+
+fn literal_from_val(ty: &vh::Ty, value: i64) -> vh::Expr {
+    match ty {
+        vh::Ty::Logic => vh::Expr::new_logic(value & 1 == 1),
+        vh::Ty::Vector(width) => vh::Expr::new_bits(*width, value),
+    }
+}
+
+fn assign(y: vh::Expr, expr: vh::Expr) -> vh::Stmt {
+    vh::Stmt::Assign(y, expr)
+}
+
+// lanes of a binary op
+fn binop_try_from_instr<F>(instr: &ir::InstrPrim, op: F) -> Result<Vec<vh::Stmt>, Error>
+where
+    F: Fn(vh::Expr, vh::Expr) -> Result<vh::Expr, Error>,
+{
+    let y = exprs_try_from_term(instr.dst().get_term(0)?)?;
+    let a = exprs_try_from_term(instr.arg().get_term(0)?)?;
+    let b = exprs_try_from_term(instr.arg().get_term(1)?)?;
+    let mut stmt: Vec<vh::Stmt> = Vec::new();
+    for ((y, a), b) in y.into_iter().zip(a).zip(b) {
+        stmt.push(assign(y, op(a, b)?));
+    }
+    Ok(stmt)
+}
+
+// add, sub and mul wrap around like the interpreter
+fn arith_try_from_instr(instr: &ir::InstrPrim, op: &str) -> Result<Vec<vh::Stmt>, Error> {
+    let term_y = instr.dst().get_term(0)?;
+    let term_a = instr.arg().get_term(0)?;
+    let term_b = instr.arg().get_term(1)?;
+    let width = if let vh::Ty::Vector(width) = ty_try_from_term(term_y)? {
+        width
+    } else {
+        let err = format!("{} is not supported on single bits", instr.op());
+        return Err(Error::new_bline_error(&err));
+    };
+    binop_try_from_instr(instr, |a, b| {
+        let a = num_try_from_term(term_a, a)?;
+        let b = num_try_from_term(term_b, b)?;
+        let expr = vh::Expr::new_binop(op, a, b);
+        if op == "*" {
+            let resize = vh::Expr::new_call("resize", vec![expr, vh::Expr::Int(width as i64)]);
+            Ok(slv_from_expr(resize))
+        } else {
+            Ok(slv_from_expr(expr))
+        }
+    })
+}
+
+// comparisons are signed when their operands are
+fn cmp_try_from_instr(instr: &ir::InstrPrim, op: &str) -> Result<Vec<vh::Stmt>, Error> {
+    let term_a = instr.arg().get_term(0)?;
+    let term_b = instr.arg().get_term(1)?;
+    binop_try_from_instr(instr, |a, b| {
+        let a = num_try_from_term(term_a, a)?;
+        let b = num_try_from_term(term_b, b)?;
+        Ok(vh::Expr::new_logic_from_cond(vh::Expr::new_binop(op, a, b)))
+    })
+}
+
+fn reg_try_from_instr(instr: &ir::InstrPrim, clock: &Clock) -> Result<Vec<vh::Stmt>, Error> {
+    let term_y = instr.dst().get_term(0)?;
+    let ty = ty_try_from_term(term_y)?;
+    let init = instr.attr().get_val(0).unwrap_or(0);
+    let en = exprs_try_from_term(instr.arg().get_term(1)?)?;
+    let en = if let Some(en) = en.first() {
+        vh::Expr::new_is_one(en.clone())
+    } else {
+        return Err(Error::new_bline_error("reg instr must have en arg"));
+    };
+    let reset = vh::Expr::new_binop(
+        "=",
+        vh::Expr::new_ref(&clock.reset()),
+        vh::Expr::new_logic(!clock.is_active_low()),
+    );
+    let edge = vh::Expr::new_call("rising_edge", vec![vh::Expr::new_ref(&clock.clock())]);
+    let y = exprs_try_from_term(term_y)?;
+    let a = exprs_try_from_term(instr.arg().get_term(0)?)?;
+    let mut stmt: Vec<vh::Stmt> = Vec::new();
+    for (y, a) in y.into_iter().zip(a) {
+        let load = vh::Seq::Assign(y.clone(), a);
+        let init = vh::Seq::Assign(y, literal_from_val(&ty, init));
+        let enable = (en.clone(), vec![load]);
+        let body = match clock.reset_ty() {
+            ResetTy::Sync => vec![vh::Seq::If(
+                vec![(
+                    edge.clone(),
+                    vec![vh::Seq::If(
+                        vec![(reset.clone(), vec![init]), enable],
+                        vec![],
+                    )],
+                )],
+                vec![],
+            )],
+            ResetTy::Async => vec![vh::Seq::If(
+                vec![
+                    (reset.clone(), vec![init]),
+                    (edge.clone(), vec![vh::Seq::If(vec![enable], vec![])]),
+                ],
+                vec![],
+            )],
+            ResetTy::None => vec![vh::Seq::If(
+                vec![(edge.clone(), vec![vh::Seq::If(vec![enable], vec![])])],
+                vec![],
+            )],
+        };
+        let clk = clock.clock();
+        let rst = clock.reset();
+        let mut proc = if clock.is_async() {
+            vh::Process::new(&[&clk, &rst])
+        } else {
+            vh::Process::new(&[&clk])
+        };
+        for s in body {
+            proc.add_seq(s);
+        }
+        stmt.push(vh::Stmt::Process(proc));
+    }
+    Ok(stmt)
+}
+
+fn stmt_try_from_instr_prim(instr: &ir::InstrPrim, clock: &Clock) -> Result<Vec<vh::Stmt>, Error> {
+    match instr.op() {
+        ir::OpPrim::Reg => reg_try_from_instr(instr, clock),
+        ir::OpPrim::Add => arith_try_from_instr(instr, "+"),
+        ir::OpPrim::Sub => arith_try_from_instr(instr, "-"),
+        ir::OpPrim::Mul => arith_try_from_instr(instr, "*"),
+        ir::OpPrim::And => binop_try_from_instr(instr, |a, b| Ok(vh::Expr::new_binop("and", a, b))),
+        ir::OpPrim::Or => binop_try_from_instr(instr, |a, b| Ok(vh::Expr::new_binop("or", a, b))),
+        ir::OpPrim::Xor => binop_try_from_instr(instr, |a, b| Ok(vh::Expr::new_binop("xor", a, b))),
+        ir::OpPrim::Eql => cmp_try_from_instr(instr, "="),
+        ir::OpPrim::Neql => cmp_try_from_instr(instr, "/="),
+        ir::OpPrim::Gt => cmp_try_from_instr(instr, ">"),
+        ir::OpPrim::Lt => cmp_try_from_instr(instr, "<"),
+        ir::OpPrim::Ge => cmp_try_from_instr(instr, ">="),
+        ir::OpPrim::Le => cmp_try_from_instr(instr, "<="),
+        ir::OpPrim::Not => {
+            let y = exprs_try_from_term(instr.dst().get_term(0)?)?;
+            let a = exprs_try_from_term(instr.arg().get_term(0)?)?;
+            Ok(y.into_iter()
+                .zip(a)
+                .map(|(y, a)| assign(y, vh::Expr::new_not(a)))
+                .collect())
+        }
+        ir::OpPrim::Mux => {
+            let y = exprs_try_from_term(instr.dst().get_term(0)?)?;
+            let s = exprs_try_from_term(instr.arg().get_term(0)?)?;
+            let a = exprs_try_from_term(instr.arg().get_term(1)?)?;
+            let b = exprs_try_from_term(instr.arg().get_term(2)?)?;
+            if let Some(s) = s.first() {
+                let cond = vh::Expr::new_is_one(s.clone());
+                Ok(y.into_iter()
+                    .zip(a)
+                    .zip(b)
+                    .map(|((y, a), b)| assign(y, vh::Expr::new_cond(a, cond.clone(), b)))
+                    .collect())
+            } else {
+                Err(Error::new_bline_error("mux sel do not have right type"))
+            }
+        }
+        ir::OpPrim::Ram => Err(Error::new_bline_error(
+            "ram is not supported in behavioral VHDL yet",
+        )),
+    }
+}
+
+// shifts by a constant, sra keeps the sign
+fn shift_from_expr(op: &ir::OpWire, ty: &vh::Ty, a: vh::Expr, shift: u64) -> vh::Expr {
+    match ty {
+        vh::Ty::Logic if shift == 0 || *op == ir::OpWire::Sra => a,
+        vh::Ty::Logic => vh::Expr::new_logic(false),
+        _ => {
+            let (func, num) = match op {
+                ir::OpWire::Sll => ("shift_left", "unsigned"),
+                ir::OpWire::Srl => ("shift_right", "unsigned"),
+                _ => ("shift_right", "signed"),
+            };
+            let num = vh::Expr::new_call(num, vec![a]);
+            let amount = vh::Expr::Int(shift as i64);
+            slv_from_expr(vh::Expr::new_call(func, vec![num, amount]))
+        }
+    }
+}
+
+fn stmt_try_from_instr_wire(instr: &ir::InstrWire) -> Result<Vec<vh::Stmt>, Error> {
+    let term_y = instr.dst().get_term(0)?;
+    let ty = ty_try_from_term(term_y)?;
+    let y = exprs_try_from_term(term_y)?;
+    match instr.op() {
+        ir::OpWire::Con => {
+            let value = instr.const_values()?;
+            Ok(y.into_iter()
+                .zip(value)
+                .map(|(y, v)| assign(y, literal_from_val(&ty, v)))
+                .collect())
+        }
+        ir::OpWire::Id => {
+            let a = exprs_try_from_term(instr.arg().get_term(0)?)?;
+            Ok(y.into_iter().zip(a).map(|(y, a)| assign(y, a)).collect())
+        }
+        ir::OpWire::Sll | ir::OpWire::Srl | ir::OpWire::Sra => {
+            let a = exprs_try_from_term(instr.arg().get_term(0)?)?;
+            let shift = u64::try_from(instr.attr().get_val(0)?)?;
+            Ok(y.into_iter()
+                .zip(a)
+                .map(|(y, a)| assign(y, shift_from_expr(instr.op(), &ty, a, shift)))
+                .collect())
+        }
+        // bits of every lane of the argument starting at lsb, zero past
+        // its msb
+        ir::OpWire::Ext => {
+            let term_a = instr.arg().get_term(0)?;
+            let width_a = ty_try_from_term(term_a)?.width();
+            let lsb = u64::try_from(instr.attr().get_val(0)?)?;
+            let mut stmt: Vec<vh::Stmt> = Vec::new();
+            for (y, name) in y.into_iter().zip(names_try_from_term(term_a)?) {
+                let expr = if lsb >= width_a {
+                    literal_from_val(&ty, 0)
+                } else if width_a == 1 {
+                    vh::Expr::new_ref(&name)
+                } else if ty == vh::Ty::Logic {
+                    vh::Expr::new_index(&name, lsb)
+                } else {
+                    let msb = (lsb + ty.width() - 1).min(width_a - 1);
+                    let slice = vh::Expr::new_slice(&name, msb, lsb);
+                    if msb - lsb + 1 < ty.width() {
+                        let num = vh::Expr::new_call("unsigned", vec![slice]);
+                        let width = vh::Expr::Int(ty.width() as i64);
+                        slv_from_expr(vh::Expr::new_call("resize", vec![num, width]))
+                    } else {
+                        slice
+                    }
+                };
+                stmt.push(assign(y, expr));
+            }
+            Ok(stmt)
+        }
+        // the first argument is the least significant one
+        ir::OpWire::Cat => {
+            let arg: Vec<ir::ExprTerm> = instr.arg().clone().into();
+            let mut expr: Option<vh::Expr> = None;
+            for term in arg.iter() {
+                for a in exprs_try_from_term(term)? {
+                    expr = Some(match expr {
+                        Some(e) => vh::Expr::new_binop("&", a, e),
+                        None => a,
+                    });
+                }
+            }
+            if let Some(expr) = expr {
+                Ok(vec![assign(y[0].clone(), expr)])
+            } else {
+                Err(Error::new_bline_error("cat instr must have args"))
+            }
+        }
+    }
+}
+
+fn stmt_try_from_instr(instr: &ir::Instr, clock: &Clock) -> Result<Vec<vh::Stmt>, Error> {
+    match instr {
+        ir::Instr::Prim(instr) => stmt_try_from_instr_prim(instr, clock),
+        ir::Instr::Wire(instr) => stmt_try_from_instr_wire(instr),
+        ir::Instr::Call(_) => Err(Error::new_bline_error("call instr not implemented yet")),
+    }
+}
+
+fn ports_try_from_expr(expr: &ir::Expr) -> Result<Vec<(String, vh::Ty)>, Error> {
+    let term: Vec<ir::ExprTerm> = expr.clone().into();
+    let mut port: Vec<(String, vh::Ty)> = Vec::new();
+    for t in term.iter() {
+        let ty = ty_try_from_term(t)?;
+        for n in names_try_from_term(t)? {
+            port.push((n, ty.clone()));
+        }
+    }
+    Ok(port)
+}
+
+pub fn behav_try_from_ir_def_with_clock(def: &ir::Def, clock: &Clock) -> Result<vh::Module, Error> {
+    let mut module = vh::Module::new(&def.sig().id());
+    module.add_port(vh::Port::new_input(&clock.clock(), vh::Ty::Logic));
+    if clock.has_reset() {
+        module.add_port(vh::Port::new_input(&clock.reset(), vh::Ty::Logic));
+    }
+    for (id, ty) in ports_try_from_expr(def.sig().input())? {
+        module.add_port(vh::Port::new_input(&id, ty));
+    }
+    let output = ports_try_from_expr(def.sig().output())?;
+    let output_set: HashSet<String> = output.iter().map(|(id, _)| id.to_string()).collect();
+    for (id, ty) in output {
+        module.add_port(vh::Port::new_output(&id, ty));
+    }
+    for instr in def.body() {
+        for (id, ty) in ports_try_from_expr(instr.dst())? {
+            if !output_set.contains(&id) {
+                module.add_signal(vh::Signal::new(&id, ty));
+            }
+        }
+        for s in stmt_try_from_instr(instr, clock)? {
+            module.add_stmt(s);
+        }
+    }
+    Ok(module)
+}
+
+pub fn behav_try_from_ir_def(def: &ir::Def) -> Result<vh::Module, Error> {
+    behav_try_from_ir_def_with_clock(def, &Clock::default())
+}
+
+pub fn try_from_ir_prog_with_clock(prog: &ir::Prog, clock: &Clock) -> Result<vh::Module, Error> {
+    if let Some(def) = prog.get("main") {
+        behav_try_from_ir_def_with_clock(def, clock)
+    } else {
+        Err(Error::new_bline_error("main not found"))
+    }
+}
+
+pub fn try_from_ir_prog(prog: &ir::Prog) -> Result<vh::Module, Error> {
+    try_from_ir_prog_with_clock(prog, &Clock::default())
+}
@@ -1,7 +1,20 @@
-use bline::try_from_ir_prog;
+use bline::{try_from_ir_prog, try_from_ir_prog_with_clock, vhdl};
 use io::file::read_to_string;
+use ir::clock::{Clock, ResetTy};
 use ir::parser::Parser as IrParser;
 
+fn test_with_clock(golden: &str, reset_ty: ResetTy, active_low: bool) {
+    let prog = IrParser::parse_from_file("../../../examples/ir/reg.ir").unwrap();
+    let exp = read_to_string(format!("../../../examples/behav/{}.v", golden));
+    let mut clock = Clock::default();
+    clock.set_reset_ty(reset_ty);
+    clock.set_active_low(active_low);
+    let res = try_from_ir_prog_with_clock(&prog, &clock)
+        .unwrap()
+        .to_string();
+    assert_eq!(res, exp);
+}
+
 #[test]
 fn add() {
     let prog = IrParser::parse_from_file("../../../examples/ir/add.ir").unwrap();
@@ -9,3 +22,67 @@ fn add() {
     let res = try_from_ir_prog(&prog).unwrap().to_string();
     assert_eq!(res, exp);
 }
+
+#[test]
+fn ops() {
+    let prog = IrParser::parse_from_file("../../../examples/ir/ops.ir").unwrap();
+    let exp = read_to_string("../../../examples/behav/ops.v");
+    let res = try_from_ir_prog(&prog).unwrap().to_string();
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn const_lanes() {
+    let prog = IrParser::parse_from_file("../../../examples/ir/const.ir").unwrap();
+    let exp = read_to_string("../../../examples/behav/const.v");
+    let res = try_from_ir_prog(&prog).unwrap().to_string();
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn const_overflow() {
+    let prog = IrParser::parse_from_str("def main() -> (y:i4) { y:i4 = const[16]; }").unwrap();
+    assert!(try_from_ir_prog(&prog).is_err());
+    assert!(vhdl::try_from_ir_prog(&prog).is_err());
+    let prog = IrParser::parse_from_str("def main() -> (y:i4) { y:i4 = const[-9]; }").unwrap();
+    assert!(try_from_ir_prog(&prog).is_err());
+}
+
+#[test]
+fn add_vhdl() {
+    let prog = IrParser::parse_from_file("../../../examples/ir/add.ir").unwrap();
+    let exp = read_to_string("../../../examples/behav/add.vhd");
+    let res = vhdl::try_from_ir_prog(&prog).unwrap().to_string();
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn ops_vhdl() {
+    let prog = IrParser::parse_from_file("../../../examples/ir/ops.ir").unwrap();
+    let exp = read_to_string("../../../examples/behav/ops.vhd");
+    let res = vhdl::try_from_ir_prog(&prog).unwrap().to_string();
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn const_lanes_vhdl() {
+    let prog = IrParser::parse_from_file("../../../examples/ir/const.ir").unwrap();
+    let exp = read_to_string("../../../examples/behav/const.vhd");
+    let res = vhdl::try_from_ir_prog(&prog).unwrap().to_string();
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn reg_active_low() {
+    test_with_clock("reg_active_low", ResetTy::Sync, true);
+}
+
+#[test]
+fn reg_async() {
+    test_with_clock("reg_async", ResetTy::Async, false);
+}
+
+#[test]
+fn reg_no_reset() {
+    test_with_clock("reg_no_reset", ResetTy::None, false);
+}
@@ -0,0 +1,223 @@
+use crate::errors::Error;
+use crate::session::{Diagnostic, Session, Stage, Stats};
+use asm::ast::Prim;
+use bler::try_from_asm_prog as asm_try_into_xir;
+use device::ast::Device;
+use ir::clock::Clock;
+use ir::parser::Parser as IrParser;
+use isel::try_from_ir_prog as ir_try_into_asm;
+use optimizer::cascader::cascader_with_height;
+use optimizer::opt::Optimization;
+use optimizer::target::{Target, CASCADE};
+use placer::pack::pack;
+use placer::place::place;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::str::FromStr;
+use std::time::Instant;
+use verilog::ast as vl;
+use xpand::prog_without_loc;
+use xpand::site::check_prog;
+use xpand::try_from_xir_prog_with_device as xir_try_into_struct;
+
+/// options of a compilation, the same ones rt takes as flags
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    /// optimizations, run in order before placement
+    pub passes: Vec<Optimization>,
+    /// cascades used by the cascade pass, the default ones if there is none
+    pub target: Option<Target>,
+    /// device used for placement, packing and site checks
+    pub device: Option<Device>,
+    /// cascade height, otherwise the one of the device if any
+    pub max_chain: Option<usize>,
+    pub clock: Clock,
+    pub no_loc_attr: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Compiler {
+    opts: Options,
+}
+
+fn count_asm(prog: &asm::ast::Prog, f: impl Fn(&asm::ast::InstrAsm) -> bool) -> usize {
+    prog.body()
+        .iter()
+        .filter(|instr| match instr {
+            asm::ast::Instr::Asm(instr) => f(instr),
+            _ => false,
+        })
+        .count()
+}
+
+// instructions of after that are not in before, new or rewritten ones
+fn count_changed(before: &asm::ast::Prog, after: &asm::ast::Prog) -> usize {
+    let before: HashSet<&asm::ast::Instr> = before.body().iter().collect();
+    after
+        .body()
+        .iter()
+        .filter(|instr| !before.contains(instr))
+        .count()
+}
+
+impl Compiler {
+    pub fn new(opts: Options) -> Compiler {
+        Compiler { opts }
+    }
+    pub fn opts(&self) -> &Options {
+        &self.opts
+    }
+    pub fn device(&self) -> Option<&Device> {
+        self.opts.device.as_ref()
+    }
+    pub fn clock(&self) -> &Clock {
+        &self.opts.clock
+    }
+    // cascades from the options, or the default ones
+    pub fn target(&self) -> Result<Target, Error> {
+        if let Some(target) = &self.opts.target {
+            Ok(target.clone())
+        } else {
+            Ok(Target::from_str(CASCADE)?)
+        }
+    }
+    // cascade height from the options, otherwise from the device if any
+    pub fn height(&self) -> Result<Option<usize>, Error> {
+        if let Some(max) = self.opts.max_chain {
+            Ok(Some(max))
+        } else if let Some(device) = self.device() {
+            let height = device.max_cascade_height(&Prim::Dsp);
+            match usize::try_from(height) {
+                Ok(height) => Ok(Some(height)),
+                Err(_) => Err(Error::new_compiler_error("Invalid cascade height")),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+    pub fn select(&self, prog: &ir::ast::Prog) -> Result<asm::ast::Prog, Error> {
+        Ok(ir_try_into_asm(prog)?)
+    }
+    pub fn run_pass(
+        &self,
+        opt: &Optimization,
+        prog: &asm::ast::Prog,
+    ) -> Result<asm::ast::Prog, Error> {
+        match opt {
+            Optimization::Cascade => {
+                Ok(cascader_with_height(prog, &self.target()?, self.height()?)?)
+            }
+        }
+    }
+    /// run the optimizations in order, before placement
+    pub fn optimize(&self, prog: &asm::ast::Prog) -> Result<asm::ast::Prog, Error> {
+        let mut prog = prog.clone();
+        for opt in self.opts.passes.iter() {
+            prog = self.run_pass(opt, &prog)?;
+        }
+        Ok(prog)
+    }
+    /// place unplaced locations, only if there is a device
+    pub fn place(&self, prog: &asm::ast::Prog) -> Result<asm::ast::Prog, Error> {
+        if let Some(device) = self.device() {
+            Ok(place(prog, device)?)
+        } else {
+            Ok(prog.clone())
+        }
+    }
+    /// assemble and pack into slices, only if there is a device
+    pub fn assemble(&self, prog: &asm::ast::Prog) -> Result<xir::ast::Prog, Error> {
+        let prog = asm_try_into_xir(prog)?;
+        if let Some(device) = self.device() {
+            Ok(pack(&prog, device)?)
+        } else {
+            Ok(prog)
+        }
+    }
+    /// xir ready for the structural backends, sites are checked against
+    /// the device and locations are dropped if no_loc_attr is set
+    pub fn legalize(&self, prog: &xir::ast::Prog) -> Result<xir::ast::Prog, Error> {
+        if let Some(device) = self.device() {
+            check_prog(prog, device)?;
+        }
+        if self.opts.no_loc_attr {
+            Ok(prog_without_loc(prog))
+        } else {
+            Ok(prog.clone())
+        }
+    }
+    /// structural verilog, with the site names of the device if any
+    pub fn expand(&self, prog: &xir::ast::Prog) -> Result<vl::Module, Error> {
+        let prog = self.legalize(prog)?;
+        Ok(xir_try_into_struct(&prog, self.clock(), self.device())?)
+    }
+    /// compile an ir program from its text
+    pub fn compile_str(&self, input: &str) -> Result<Session, Error> {
+        let start = Instant::now();
+        let prog = IrParser::parse_from_str(input)?;
+        let mut stats = Stats::default();
+        stats.add(Stage::Parse, start.elapsed());
+        self.compile_with_stats(prog, stats)
+    }
+    /// compile an ir program, keeping the program of every stage
+    pub fn compile(&self, prog: &ir::ast::Prog) -> Result<Session, Error> {
+        self.compile_with_stats(prog.clone(), Stats::default())
+    }
+    fn compile_with_stats(&self, prog: ir::ast::Prog, mut stats: Stats) -> Result<Session, Error> {
+        let mut diagnostics = Vec::new();
+        stats.ir = match prog.get("main") {
+            Some(def) => def.body().len(),
+            None => return Err(Error::new_compiler_error("main not found")),
+        };
+
+        let start = Instant::now();
+        let mut asm = self.select(&prog)?;
+        stats.add(Stage::Select, start.elapsed());
+
+        let start = Instant::now();
+        for opt in self.opts.passes.iter() {
+            let prog = self.run_pass(opt, &asm)?;
+            let msg = format!(
+                "{} changed {} instructions",
+                opt,
+                count_changed(&asm, &prog)
+            );
+            diagnostics.push(Diagnostic::note(Stage::Optimize, &msg));
+            asm = prog;
+        }
+        stats.add(Stage::Optimize, start.elapsed());
+
+        if self.device().is_some() {
+            let start = Instant::now();
+            asm = self.place(&asm)?;
+            stats.add(Stage::Place, start.elapsed());
+        } else {
+            let unplaced = count_asm(&asm, |instr| instr.loc().is_unplaced());
+            if unplaced > 0 {
+                let msg = format!("{} instructions are not placed without a device", unplaced);
+                diagnostics.push(Diagnostic::warning(Stage::Place, &msg));
+            }
+        }
+        stats.asm = asm.body().len();
+        stats.lut = count_asm(&asm, |instr| instr.is_lut());
+        stats.dsp = count_asm(&asm, |instr| instr.is_dsp());
+
+        let start = Instant::now();
+        let xir = self.assemble(&asm)?;
+        stats.add(Stage::Assemble, start.elapsed());
+        stats.xir = xir.body().len();
+
+        let start = Instant::now();
+        let verilog = self.expand(&xir)?;
+        stats.add(Stage::Expand, start.elapsed());
+
+        Ok(Session {
+            ir: prog,
+            asm,
+            xir,
+            verilog,
+            diagnostics,
+            stats,
+        })
+    }
+}
@@ -0,0 +1,101 @@
+use asm::errors::Error as AsmError;
+use bler::errors::Error as BlerError;
+use device::errors::Error as DeviceError;
+use ir::errors::Error as IrError;
+use isel::errors::Error as ISelError;
+use optimizer::errors::Error as OptimizerError;
+use placer::errors::Error as PlacerError;
+use std::fmt;
+use xir::errors::Error as XirError;
+use xpand::errors::Error as XpandError;
+
+#[derive(Debug)]
+pub enum Error {
+    Compiler(String),
+    Ir(IrError),
+    Asm(AsmError),
+    Xir(XirError),
+    ISel(ISelError),
+    Optimizer(OptimizerError),
+    Placer(PlacerError),
+    Bler(BlerError),
+    Xpand(XpandError),
+    Device(DeviceError),
+}
+
+impl Error {
+    pub fn new_compiler_error(msg: &str) -> Self {
+        Error::Compiler(msg.to_string())
+    }
+}
+
+impl From<IrError> for Error {
+    fn from(e: IrError) -> Self {
+        Error::Ir(e)
+    }
+}
+
+impl From<AsmError> for Error {
+    fn from(e: AsmError) -> Self {
+        Error::Asm(e)
+    }
+}
+
+impl From<XirError> for Error {
+    fn from(e: XirError) -> Self {
+        Error::Xir(e)
+    }
+}
+
+impl From<ISelError> for Error {
+    fn from(e: ISelError) -> Self {
+        Error::ISel(e)
+    }
+}
+
+impl From<OptimizerError> for Error {
+    fn from(e: OptimizerError) -> Self {
+        Error::Optimizer(e)
+    }
+}
+
+impl From<PlacerError> for Error {
+    fn from(e: PlacerError) -> Self {
+        Error::Placer(e)
+    }
+}
+
+impl From<BlerError> for Error {
+    fn from(e: BlerError) -> Self {
+        Error::Bler(e)
+    }
+}
+
+impl From<XpandError> for Error {
+    fn from(e: XpandError) -> Self {
+        Error::Xpand(e)
+    }
+}
+
+impl From<DeviceError> for Error {
+    fn from(e: DeviceError) -> Self {
+        Error::Device(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Compiler(msg) => write!(f, "{}", msg),
+            Error::Ir(msg) => write!(f, "{}", msg),
+            Error::Asm(msg) => write!(f, "{}", msg),
+            Error::Xir(msg) => write!(f, "{}", msg),
+            Error::ISel(msg) => write!(f, "{}", msg),
+            Error::Optimizer(msg) => write!(f, "{}", msg),
+            Error::Placer(msg) => write!(f, "{}", msg),
+            Error::Bler(msg) => write!(f, "{}", msg),
+            Error::Xpand(msg) => write!(f, "{}", msg),
+            Error::Device(msg) => write!(f, "{}", msg),
+        }
+    }
+}
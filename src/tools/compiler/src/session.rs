@@ -0,0 +1,139 @@
+use std::fmt;
+use std::time::Duration;
+use verilog::ast as vl;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Select,
+    Optimize,
+    Place,
+    Assemble,
+    Expand,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Level {
+    Note,
+    Warning,
+}
+
+/// message about a stage that did not stop the compilation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub level: Level,
+    pub stage: Stage,
+    pub msg: String,
+}
+
+/// instruction counts of every program and time spent in every stage
+#[derive(Clone, Debug, Default)]
+pub struct Stats {
+    pub ir: usize,
+    pub asm: usize,
+    pub xir: usize,
+    pub lut: usize,
+    pub dsp: usize,
+    pub time: Vec<(Stage, Duration)>,
+}
+
+/// programs of every stage of a compilation
+#[derive(Clone, Debug)]
+pub struct Session {
+    pub(crate) ir: ir::ast::Prog,
+    pub(crate) asm: asm::ast::Prog,
+    pub(crate) xir: xir::ast::Prog,
+    pub(crate) verilog: vl::Module,
+    pub(crate) diagnostics: Vec<Diagnostic>,
+    pub(crate) stats: Stats,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stage = match self {
+            Stage::Parse => "parse",
+            Stage::Select => "select",
+            Stage::Optimize => "optimize",
+            Stage::Place => "place",
+            Stage::Assemble => "assemble",
+            Stage::Expand => "expand",
+        };
+        write!(f, "{}", stage)
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match self {
+            Level::Note => "note",
+            Level::Warning => "warning",
+        };
+        write!(f, "{}", level)
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]: {}", self.level, self.stage, self.msg)
+    }
+}
+
+impl Diagnostic {
+    pub fn note(stage: Stage, msg: &str) -> Diagnostic {
+        Diagnostic {
+            level: Level::Note,
+            stage,
+            msg: msg.to_string(),
+        }
+    }
+    pub fn warning(stage: Stage, msg: &str) -> Diagnostic {
+        Diagnostic {
+            level: Level::Warning,
+            stage,
+            msg: msg.to_string(),
+        }
+    }
+    pub fn is_warning(&self) -> bool {
+        self.level == Level::Warning
+    }
+}
+
+impl Stats {
+    pub fn add(&mut self, stage: Stage, time: Duration) {
+        self.time.push((stage, time));
+    }
+    /// time spent in stage, zero if it did not run
+    pub fn time(&self, stage: Stage) -> Duration {
+        self.time
+            .iter()
+            .filter(|(s, _)| *s == stage)
+            .map(|(_, t)| *t)
+            .sum()
+    }
+    pub fn total(&self) -> Duration {
+        self.time.iter().map(|(_, t)| *t).sum()
+    }
+}
+
+impl Session {
+    pub fn ir(&self) -> &ir::ast::Prog {
+        &self.ir
+    }
+    /// selected, optimized and placed asm
+    pub fn asm(&self) -> &asm::ast::Prog {
+        &self.asm
+    }
+    pub fn xir(&self) -> &xir::ast::Prog {
+        &self.xir
+    }
+    /// structural verilog
+    pub fn verilog(&self) -> &vl::Module {
+        &self.verilog
+    }
+    pub fn diagnostics(&self) -> &Vec<Diagnostic> {
+        &self.diagnostics
+    }
+    pub fn stats(&self) -> &Stats {
+        &self.stats
+    }
+}
@@ -0,0 +1,56 @@
+use asm::parser::Parser as AsmParser;
+use compiler::compiler::{Compiler, Options};
+use compiler::errors::Error;
+use compiler::session::Stage;
+use device::ast::Device;
+use ir::parser::Parser as IrParser;
+use optimizer::opt::Optimization;
+use std::fs::read_to_string;
+
+fn read(path: &str) -> String {
+    read_to_string(path).unwrap()
+}
+
+#[test]
+fn compile_add() -> Result<(), Error> {
+    let session = Compiler::default().compile_str(&read("../../../examples/ir/add.ir"))?;
+    let ir = IrParser::parse_from_file("../../../examples/ir/add.ir")?;
+    assert_eq!(session.ir(), &ir);
+    assert_eq!(session.stats().ir, 2);
+    assert!(!session.xir().body().is_empty());
+    assert!(!session.verilog().to_string().is_empty());
+    // nothing is placed without a device
+    assert!(session.diagnostics().iter().any(|d| d.is_warning()));
+    Ok(())
+}
+
+#[test]
+fn compile_cascade() -> Result<(), Error> {
+    let opts = Options {
+        passes: vec![Optimization::Cascade],
+        ..Options::default()
+    };
+    let session = Compiler::new(opts).compile_str(&read("../../../examples/ir/tdot_5_3.ir"))?;
+    let asm = AsmParser::parse_from_file("../../../examples/asm/tdot_5_3_opt.asm")?;
+    assert_eq!(session.asm(), &asm);
+    assert_eq!(session.stats().dsp, 15);
+    let note = &session.diagnostics()[0];
+    assert_eq!(
+        note.to_string(),
+        "note[optimize]: cascade changed 15 instructions"
+    );
+    Ok(())
+}
+
+#[test]
+fn compile_placed() -> Result<(), Error> {
+    let opts = Options {
+        device: Some(Device::from_file("../../../dev/xczu3eg-sbva484.csv")?),
+        ..Options::default()
+    };
+    let ir = IrParser::parse_from_file("../../../examples/ir/tdot_5_3.ir")?;
+    let session = Compiler::new(opts).compile(&ir)?;
+    assert!(session.diagnostics().iter().all(|d| !d.is_warning()));
+    assert!(session.stats().time(Stage::Place) > std::time::Duration::default());
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use ir::ast as ir;
+use std::collections::HashMap;
+
+pub type Prim = ir::Prim;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Site {
+    pub prim: Prim,
+    pub x: u64,
+    pub y: u64,
+    pub raw: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Device {
+    pub name: String,
+    pub site: Vec<Site>,
+    pub index: HashMap<(Prim, u64, u64), usize>,
+}
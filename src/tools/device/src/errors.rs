@@ -0,0 +1,38 @@
+use ir::errors::Error as IrError;
+use std::fmt;
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+pub enum Error {
+    Ir(IrError),
+    ParseInt(ParseIntError),
+    Device(String),
+}
+
+impl Error {
+    pub fn new_device_error(msg: &str) -> Self {
+        Error::Device(msg.to_string())
+    }
+}
+
+impl From<IrError> for Error {
+    fn from(e: IrError) -> Self {
+        Error::Ir(e)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Error::ParseInt(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Ir(msg) => write!(f, "{}", msg),
+            Error::ParseInt(msg) => write!(f, "{}", msg),
+            Error::Device(msg) => write!(f, "{}", msg),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::ast::*;
+use crate::errors::Error;
+use std::str::FromStr;
+
+const HEADER: [&str; 4] = ["x", "y", "prim", "raw"];
+
+pub fn prim_from_site_str(input: &str) -> Result<Prim, Error> {
+    let err = format!("Error: {} is not valid site primitive", input);
+    match input {
+        "LUT" | "SLICE" => Ok(Prim::Lut),
+        "LRAM" => Ok(Prim::Lram),
+        "DSP" => Ok(Prim::Dsp),
+        "BRAM" => Ok(Prim::Bram),
+        "URAM" => Ok(Prim::Uram),
+        _ => Err(Error::new_device_error(&err)),
+    }
+}
+
+impl FromStr for Site {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let field: Vec<&str> = input.split(',').map(|f| f.trim()).collect();
+        if field.len() == HEADER.len() {
+            Ok(Site {
+                x: field[0].parse()?,
+                y: field[1].parse()?,
+                prim: prim_from_site_str(field[2])?,
+                raw: field[3].to_string(),
+            })
+        } else {
+            let err = format!("Error: {} is not valid site", input);
+            Err(Error::new_device_error(&err))
+        }
+    }
+}
+
+impl FromStr for Device {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut lines = input.lines().filter(|l| !l.trim().is_empty());
+        if let Some(header) = lines.next() {
+            let header: Vec<&str> = header.split(',').map(|f| f.trim()).collect();
+            if header != HEADER {
+                let err = format!("Error: device header must be {}", HEADER.join(","));
+                return Err(Error::new_device_error(&err));
+            }
+        }
+        let mut device = Device::default();
+        for line in lines {
+            device.add_site(Site::from_str(line)?)?;
+        }
+        Ok(device)
+    }
+}
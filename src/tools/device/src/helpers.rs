@@ -0,0 +1,166 @@
+use crate::ast::*;
+use crate::errors::Error;
+use io::file::read_to_string;
+use std::path::Path;
+use std::str::FromStr;
+use xir::ast::{Bel, ExprCoord, Loc};
+
+// site prims a device can have at any coordinate
+const PRIMS: [Prim; 5] = [Prim::Lut, Prim::Dsp, Prim::Lram, Prim::Bram, Prim::Uram];
+
+/// prim of the site a bel is in
+pub fn prim_from_bel(bel: &Bel) -> Prim {
+    match bel {
+        Bel::Dsp(_) => Prim::Dsp,
+        Bel::Lram(_) => Prim::Lram,
+        Bel::Bram(_) => Prim::Bram,
+        Bel::Uram(_) => Prim::Uram,
+        _ => Prim::Lut,
+    }
+}
+
+impl Site {
+    pub fn prim(&self) -> &Prim {
+        &self.prim
+    }
+    pub fn x(&self) -> u64 {
+        self.x
+    }
+    pub fn y(&self) -> u64 {
+        self.y
+    }
+    pub fn raw(&self) -> String {
+        self.raw.to_string()
+    }
+}
+
+impl Device {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Device, Error> {
+        let content = read_to_string(path.as_ref());
+        let mut device = Device::from_str(&content)?;
+        if let Some(name) = path.as_ref().file_stem() {
+            device.set_name(&name.to_string_lossy());
+        }
+        Ok(device)
+    }
+    pub fn name(&self) -> String {
+        self.name.to_string()
+    }
+    pub fn sites(&self) -> &Vec<Site> {
+        &self.site
+    }
+    pub fn set_name(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+    pub fn add_site(&mut self, site: Site) -> Result<(), Error> {
+        let key = (site.prim().clone(), site.x(), site.y());
+        if self.index.contains_key(&key) {
+            let err = format!("Error: site {} is defined more than once", site.raw());
+            Err(Error::new_device_error(&err))
+        } else {
+            self.index.insert(key, self.site.len());
+            self.site.push(site);
+            Ok(())
+        }
+    }
+    pub fn site(&self, prim: &Prim, x: u64, y: u64) -> Option<&Site> {
+        let key = (prim.clone(), x, y);
+        self.index.get(&key).and_then(|i| self.site.get(*i))
+    }
+    pub fn contains(&self, prim: &Prim, x: u64, y: u64) -> bool {
+        self.site(prim, x, y).is_some()
+    }
+    pub fn raw_name(&self, prim: &Prim, x: u64, y: u64) -> Option<String> {
+        self.site(prim, x, y).map(|s| s.raw())
+    }
+    /// a placed bel is legal if there is a site of the bel prim at its
+    /// coordinates, otherwise the site there belongs to another prim column
+    pub fn check_loc(&self, id: &str, loc: &Loc) -> Result<(), Error> {
+        let bel = loc.bel();
+        if let (ExprCoord::Val(x), ExprCoord::Val(y)) = (loc.x(), loc.y()) {
+            if self.contains(&prim_from_bel(bel), *x, *y) {
+                Ok(())
+            } else if let Some(site) = PRIMS.iter().find_map(|p| self.site(p, *x, *y)) {
+                let err = format!(
+                    "{} uses bel {}, but site {} is a {} site",
+                    id,
+                    bel,
+                    site.raw(),
+                    site.prim()
+                );
+                Err(Error::new_device_error(&err))
+            } else {
+                let err = format!(
+                    "{} is placed at {}({}, {}), which is outside {}",
+                    id,
+                    bel,
+                    x,
+                    y,
+                    self.name()
+                );
+                Err(Error::new_device_error(&err))
+            }
+        } else {
+            Ok(())
+        }
+    }
+    /// sorted x coordinates of every prim column
+    pub fn columns(&self, prim: &Prim) -> Vec<u64> {
+        let mut res: Vec<u64> = self
+            .site
+            .iter()
+            .filter(|s| s.prim() == prim)
+            .map(|s| s.x())
+            .collect();
+        res.sort_unstable();
+        res.dedup();
+        res
+    }
+    /// sorted y coordinates of every prim row
+    pub fn rows(&self, prim: &Prim) -> Vec<u64> {
+        let mut res: Vec<u64> = self
+            .site
+            .iter()
+            .filter(|s| s.prim() == prim)
+            .map(|s| s.y())
+            .collect();
+        res.sort_unstable();
+        res.dedup();
+        res
+    }
+    /// sorted y coordinates of prim sites in column x
+    pub fn column(&self, prim: &Prim, x: u64) -> Vec<u64> {
+        let mut res: Vec<u64> = self
+            .site
+            .iter()
+            .filter(|s| s.prim() == prim && s.x() == x)
+            .map(|s| s.y())
+            .collect();
+        res.sort_unstable();
+        res
+    }
+    /// longest run of contiguous prim sites in column x, which is the
+    /// maximum number of primitives that can be cascaded in that column
+    pub fn cascade_height(&self, prim: &Prim, x: u64) -> u64 {
+        let mut height: u64 = 0;
+        let mut run: u64 = 0;
+        let mut prev: Option<u64> = None;
+        for y in self.column(prim, x) {
+            run = match prev {
+                Some(p) if p + 1 == y => run + 1,
+                _ => 1,
+            };
+            height = height.max(run);
+            prev = Some(y);
+        }
+        height
+    }
+    /// cascade height of the tallest prim column
+    pub fn max_cascade_height(&self, prim: &Prim) -> u64 {
+        self.columns(prim)
+            .iter()
+            .map(|x| self.cascade_height(prim, *x))
+            .max()
+            .unwrap_or(0)
+    }
+}
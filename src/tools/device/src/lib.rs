@@ -0,0 +1,4 @@
+pub mod ast;
+pub mod errors;
+pub mod from_str;
+pub mod helpers;
@@ -0,0 +1,54 @@
+use device::ast::{Device, Prim};
+use device::errors::Error;
+use std::str::FromStr;
+use xir::ast::{Bel, BelLut, ExprCoord, Loc};
+
+const XCZU3EG: &str = "../../../dev/xczu3eg-sbva484.csv";
+
+#[test]
+fn xczu3eg() -> Result<(), Error> {
+    let dev = Device::from_file(XCZU3EG)?;
+    assert_eq!(dev.name(), "xczu3eg-sbva484");
+    assert_eq!(dev.columns(&Prim::Dsp), vec![0, 1, 2, 3, 4]);
+    assert_eq!(dev.max_cascade_height(&Prim::Dsp), 72);
+    assert_eq!(dev.cascade_height(&Prim::Dsp, 3), 72);
+    assert_eq!(
+        dev.raw_name(&Prim::Dsp, 3, 1),
+        Some("DSP48E2_X3Y1".to_string())
+    );
+    assert_eq!(
+        dev.raw_name(&Prim::Lut, 0, 179),
+        Some("SLICE_X0Y179".to_string())
+    );
+    assert!(!dev.contains(&Prim::Lut, 1, 179));
+    assert!(!dev.contains(&Prim::Dsp, 5, 0));
+    Ok(())
+}
+
+#[test]
+fn cascade_height() -> Result<(), Error> {
+    let dev = Device::from_str("x,y,prim,raw\n0,0,DSP,A\n0,1,DSP,B\n0,3,DSP,C\n")?;
+    assert_eq!(dev.cascade_height(&Prim::Dsp, 0), 2);
+    assert!(Device::from_str("x,y,prim,raw\n0,0,DSP,A\n0,0,DSP,B\n").is_err());
+    Ok(())
+}
+
+fn lut_loc(x: u64, y: u64) -> Loc {
+    Loc {
+        bel: Bel::Lut(BelLut::A6),
+        x: ExprCoord::Val(x),
+        y: ExprCoord::Val(y),
+    }
+}
+
+#[test]
+fn check_loc() -> Result<(), Error> {
+    let dev = Device::from_str("x,y,prim,raw\n0,0,LUT,SLICE_X0Y0\n1,0,DSP,DSP48E2_X0Y0\n")?;
+    assert!(dev.check_loc("y", &lut_loc(0, 0)).is_ok());
+    assert_eq!(
+        dev.check_loc("y", &lut_loc(1, 0)).unwrap_err().to_string(),
+        "y uses bel a6lut, but site DSP48E2_X0Y0 is a dsp site"
+    );
+    assert!(dev.check_loc("y", &lut_loc(2, 0)).is_err());
+    Ok(())
+}
@@ -7,6 +7,7 @@ impl Default for Tree {
             index: 0,
             node: HashMap::new(),
             edge: HashMap::new(),
+            binding: HashMap::new(),
         }
     }
 }
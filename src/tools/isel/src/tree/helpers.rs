@@ -98,6 +98,9 @@ impl Tree {
     pub fn edge(&self, index: u64) -> Option<&Vec<u64>> {
         self.edge.get(&index)
     }
+    pub fn binding(&self, index: u64) -> Option<&HashMap<u64, u64>> {
+        self.binding.get(&index)
+    }
     pub fn dfg(&self, start: u64) -> Vec<u64> {
         let mut res: Vec<u64> = Vec::new();
         let mut stack: Vec<u64> = Vec::new();
@@ -362,50 +365,137 @@ pub fn treelist_try_from_prog(prog: &Prog) -> Result<Vec<Tree>, Error> {
     }
 }
 
+fn is_commutative(op: &NodeOp) -> bool {
+    matches!(
+        op,
+        NodeOp::Prim(OpPrim::Add)
+            | NodeOp::Prim(OpPrim::Mul)
+            | NodeOp::Prim(OpPrim::And)
+            | NodeOp::Prim(OpPrim::Or)
+            | NodeOp::Prim(OpPrim::Xor)
+            | NodeOp::Prim(OpPrim::Eql)
+            | NodeOp::Prim(OpPrim::Neql)
+    )
+}
+
+// a scalar constant in a pattern with two values, i.e., const[lo, hi],
+// matches any constant within the inclusive range lo..=hi
+fn is_valid_attr(pnode: &Node, bnode: &Node) -> bool {
+    if pnode.op() == &NodeOp::Wire(OpWire::Con) && !pnode.ty().is_vector() {
+        if let Some(range) = pnode.attr().tup() {
+            if range.term().len() == 2 {
+                if let (Ok(lo), Ok(hi)) = (range.get_val(0), range.get_val(1)) {
+                    if let Some(val) = bnode.attr().tup() {
+                        if val.term().len() == 1 {
+                            if let Ok(v) = val.get_val(0) {
+                                return lo <= v && v <= hi;
+                            }
+                        }
+                    }
+                    return false;
+                }
+            }
+        }
+    }
+    pnode.attr() == bnode.attr()
+}
+
+fn is_valid_node(pnode: &Node, bnode: &Node) -> bool {
+    pnode.ty() == bnode.ty()
+        && (pnode.is_inp_op()
+            || (pnode.op() == bnode.op()
+                && (bnode.prim().is_any() || pnode.prim() == bnode.prim())
+                && is_valid_attr(pnode, bnode)))
+}
+
+fn match_node(
+    block: &Tree,
+    pat: &Tree,
+    bindex: u64,
+    pindex: u64,
+    free: bool,
+    map: &mut HashMap<u64, u64>,
+) -> bool {
+    if let (Some(bnode), Some(pnode)) = (block.node(bindex), pat.node(pindex)) {
+        if !is_valid_node(pnode, bnode) {
+            false
+        } else if pnode.is_inp_op() {
+            map.insert(pindex, bindex);
+            true
+        } else if free && bnode.is_committed() {
+            false
+        } else {
+            let empty: Vec<u64> = Vec::new();
+            let pedge = pat.edge(pindex).unwrap_or(&empty);
+            let bedge = block.edge(bindex).unwrap_or(&empty);
+            if pedge.len() != bedge.len() {
+                return false;
+            }
+            let mut order: Vec<Vec<u64>> = vec![bedge.clone()];
+            if is_commutative(pnode.op()) && bedge.len() == 2 {
+                order.push(vec![bedge[1], bedge[0]]);
+            }
+            for barg in order {
+                let mut cand = map.clone();
+                if pedge
+                    .iter()
+                    .zip(barg.iter())
+                    .all(|(p, b)| match_node(block, pat, *b, *p, free, &mut cand))
+                {
+                    cand.insert(pindex, bindex);
+                    *map = cand;
+                    return true;
+                }
+            }
+            false
+        }
+    } else {
+        false
+    }
+}
+
+// returns a map from pattern nodes to block nodes, operands of
+// commutative nodes are tried in both orders
+pub fn tree_match(block: &Tree, pat: &Tree, start: u64) -> Option<HashMap<u64, u64>> {
+    let mut map: HashMap<u64, u64> = HashMap::new();
+    if match_node(block, pat, start, 0, false, &mut map) {
+        Some(map)
+    } else {
+        None
+    }
+}
+
+// same as tree_match, but pattern nodes can only be matched against
+// block nodes that are not committed yet
+pub fn tree_match_free(block: &Tree, pat: &Tree, start: u64) -> Option<HashMap<u64, u64>> {
+    let mut map: HashMap<u64, u64> = HashMap::new();
+    if match_node(block, pat, start, 0, true, &mut map) {
+        Some(map)
+    } else {
+        None
+    }
+}
+
 pub fn is_valid_change(block: &Tree, pat: &Tree, start: u64) -> (bool, u64) {
-    let mut pstack = pat.bfs(0);
-    pstack.reverse();
-    let mut bstack: VecDeque<u64> = VecDeque::new();
-    bstack.push_back(start);
-    let mut next = bstack.pop_front();
-    let mut bcost: u64 = 0;
     if let Some(proot) = pat.node(0) {
         let pcost = proot.cost();
-        while let Some(bindex) = next {
-            if let Some(pindex) = pstack.pop() {
-                if let Some(bnode) = block.node(bindex) {
-                    if let Some(pnode) = pat.node(pindex) {
-                        if pnode.ty() != bnode.ty()
-                            || (!pnode.is_inp_op() && pnode.op() != bnode.op())
-                            || (!pnode.is_inp_op()
-                                && !bnode.prim().is_any()
-                                && pnode.prim() != bnode.prim())
-                            || (!pnode.is_inp_op() && pnode.attr() != bnode.attr())
-                            || (!pnode.is_inp_op() && bnode.is_committed())
-                        {
-                            next = None;
-                        } else if !pnode.is_inp_op() {
-                            if bnode.cost() == u64::MAX {
-                                bcost = bnode.cost();
-                            } else if bcost != u64::MAX {
-                                bcost += bnode.cost();
-                            }
-                            if let Some(edge) = block.edge(bindex) {
-                                for e in edge {
-                                    bstack.push_back(*e);
-                                }
-                            }
-                            next = bstack.pop_front();
-                        } else {
-                            next = bstack.pop_front();
+        if let Some(map) = tree_match_free(block, pat, start) {
+            let mut bcost: u64 = 0;
+            for (pindex, bindex) in map.iter() {
+                if let (Some(pnode), Some(bnode)) = (pat.node(*pindex), block.node(*bindex)) {
+                    if !pnode.is_inp_op() {
+                        if bnode.cost() == u64::MAX {
+                            bcost = bnode.cost();
+                        } else if bcost != u64::MAX {
+                            bcost += bnode.cost();
                         }
                     }
                 }
-            } else {
-                next = None;
             }
+            (pcost < bcost, pcost)
+        } else {
+            (false, pcost)
         }
-        (pstack.is_empty() & (pcost < bcost), pcost)
     } else {
         (false, u64::MAX)
     }
@@ -413,15 +503,12 @@ pub fn is_valid_change(block: &Tree, pat: &Tree, start: u64) -> (bool, u64) {
 
 pub fn tree_update(block: &Tree, pat: &Tree, target: u64, pat_name: &str, pat_cost: u64) -> Tree {
     let mut btree = block.clone();
-    let mut pstack = pat.bfs(0);
-    pstack.reverse();
-    let mut bstack: VecDeque<u64> = VecDeque::new();
-    bstack.push_back(target);
-    while let Some(bindex) = bstack.pop_front() {
-        if let Some(pindex) = pstack.pop() {
-            if let Some(pnode) = pat.node(pindex) {
+    if let Some(map) = tree_match_free(block, pat, target) {
+        for (pindex, bindex) in map.iter() {
+            if let Some(pnode) = pat.node(*pindex) {
                 if !pnode.is_inp_op() {
-                    if let Some(bnode) = btree.node_mut(bindex) {
+                    btree.binding.remove(bindex);
+                    if let Some(bnode) = btree.node_mut(*bindex) {
                         bnode.clear_pat();
                         bnode.set_cost(0);
                         bnode.stage();
@@ -429,14 +516,10 @@ pub fn tree_update(block: &Tree, pat: &Tree, target: u64, pat_name: &str, pat_co
                             bnode.set_pat_prim(pnode.prim().clone());
                         }
                     }
-                    if let Some(edge) = block.edge(bindex) {
-                        for e in edge {
-                            bstack.push_back(*e);
-                        }
-                    }
                 }
             }
         }
+        btree.binding.insert(target, map);
     }
     if let Some(bnode) = btree.node_mut(target) {
         bnode.set_pat(pat_name);
@@ -445,23 +528,14 @@ pub fn tree_update(block: &Tree, pat: &Tree, target: u64, pat_name: &str, pat_co
     btree
 }
 
+// inputs of the match selected at target by tree_update
 pub fn input_map(block: &Tree, pat: &Tree, target: u64) -> HashMap<String, String> {
     let mut map: HashMap<String, String> = HashMap::new();
-    let mut pstack = pat.bfs(0);
-    pstack.reverse();
-    let mut bstack: VecDeque<u64> = VecDeque::new();
-    bstack.push_back(target);
-    while let Some(bindex) = bstack.pop_front() {
-        if let Some(pindex) = pstack.pop() {
-            if let Some(pnode) = pat.node(pindex) {
+    if let Some(index) = block.binding(target) {
+        for (pindex, bindex) in index.iter() {
+            if let (Some(pnode), Some(bnode)) = (pat.node(*pindex), block.node(*bindex)) {
                 if pnode.is_inp_op() {
-                    if let Some(bnode) = block.node(bindex) {
-                        map.insert(pnode.id(), bnode.id());
-                    }
-                } else if let Some(edge) = block.edge(bindex) {
-                    for e in edge {
-                        bstack.push_back(*e);
-                    }
+                    map.insert(pnode.id(), bnode.id());
                 }
             }
         }
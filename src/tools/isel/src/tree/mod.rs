@@ -50,4 +50,6 @@ pub struct Tree {
     pub index: u64,
     pub node: HashMap<u64, Node>,
     pub edge: HashMap<u64, Vec<u64>>,
+    // pattern to block nodes of the match selected at every root
+    pub binding: HashMap<u64, HashMap<u64, u64>>,
 }
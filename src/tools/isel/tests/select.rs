@@ -90,3 +90,8 @@ fn tdot_5_18() -> Result<(), Error> {
 fn tdot_5_36() -> Result<(), Error> {
     test("tdot_5_36")
 }
+
+#[test]
+fn tdot_swap() -> Result<(), Error> {
+    test("tdot_swap")
+}
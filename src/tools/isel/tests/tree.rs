@@ -0,0 +1,112 @@
+use ir::parser::Parser as IrParser;
+use isel::tree::helpers::*;
+use isel::tree::*;
+use pat::parser::Parser as PatParser;
+use std::collections::HashSet;
+use xim::parser::Parser as XimParser;
+
+const PAT: &str = r#"
+pat daddk_i8(a: i8) -> (y: i8) {
+    t0:i8 = const[2, 5];
+    y:i8 = add(a, t0) @dsp;
+}
+pat dmuladd_i8i8(a: i8, b: i8, c: i8) -> (y: i8) {
+    t0:i8 = mul(a, b) @dsp;
+    y:i8 = add(t0, c) @dsp;
+}
+"#;
+
+const IMP: &str = r#"
+imp daddk_i8[1, 1](a: i8) -> (y: i8) {
+    y:i8 = muladd(a, a, a) @alu(??, ??);
+}
+imp dmuladd_i8i8[1, 1](a: i8, b: i8, c: i8) -> (y: i8) {
+    y:i8 = muladd(a, b, c) @alu(??, ??);
+}
+"#;
+
+fn tree_map() -> TreeMap {
+    let pat = PatParser::parse_from_str(PAT).unwrap();
+    let imp = XimParser::parse_from_str(IMP).unwrap();
+    treemap_try_from_target_pair(&pat, &imp).unwrap()
+}
+
+fn block(prog: &str) -> Tree {
+    let prog = IrParser::parse_from_str(prog).unwrap();
+    treelist_try_from_prog(&prog).unwrap()[0].clone()
+}
+
+fn addk(val: i64) -> Tree {
+    block(&format!(
+        "def main(a:i8) -> (y:i8) {{ t0:i8 = const[{}]; y:i8 = add(t0, a) @??; }}",
+        val
+    ))
+}
+
+#[test]
+fn const_range() {
+    let map = tree_map();
+    let pat = map.get("daddk_i8").unwrap();
+    assert!(tree_match(&addk(2), pat, 0).is_some());
+    assert!(tree_match(&addk(5), pat, 0).is_some());
+    assert!(tree_match(&addk(1), pat, 0).is_none());
+    assert!(tree_match(&addk(6), pat, 0).is_none());
+}
+
+#[test]
+fn committed_swap() {
+    let map = tree_map();
+    let pat = map.get("dmuladd_i8i8").unwrap();
+    let mut blk = block(
+        r#"def main(a:i8, b:i8, c:i8, d:i8) -> (y:i8) {
+            t0:i8 = mul(a, b) @??;
+            t1:i8 = mul(c, d) @??;
+            y:i8 = add(t0, t1) @??;
+        }"#,
+    );
+    let index = *blk
+        .node_map()
+        .iter()
+        .find(|(_, n)| n.id() == "t0")
+        .unwrap()
+        .0;
+    blk.node_mut(index).unwrap().commit();
+    let res = tree_match_free(&blk, pat, 0).unwrap();
+    for (p, b) in res.iter() {
+        if !pat.node(*p).unwrap().is_inp_op() {
+            assert_ne!(*b, index);
+        }
+    }
+    assert!(is_valid_change(&blk, pat, 0).0);
+}
+
+#[test]
+fn committed_swap_codegen() {
+    let src = r#"def main(a:i8, b:i8, c:i8, d:i8) -> (y:i8) {
+        t0:i8 = mul(a, b) @??;
+        t1:i8 = mul(c, d) @??;
+        y:i8 = add(t0, t1) @??;
+    }"#;
+    let prog = IrParser::parse_from_str(src).unwrap();
+    let imap = InstrMap::from(prog.get("main").unwrap().clone());
+    let tmap = tree_map();
+    let pmap = PatParser::parse_from_str(PAT).unwrap().pat().clone();
+    let pat = tmap.get("dmuladd_i8i8").unwrap();
+    let mut blk = block(src);
+    let index = *blk
+        .node_map()
+        .iter()
+        .find(|(_, n)| n.id() == "t0")
+        .unwrap()
+        .0;
+    blk.node_mut(index).unwrap().commit();
+    let (_, cost) = is_valid_change(&blk, pat, 0);
+    let blk = tree_update(&blk, pat, 0, "dmuladd_i8i8", cost);
+    let blk = tree_commit(&[blk]).unwrap();
+    let body = tree_codegen(&mut HashSet::new(), &imap, &blk[0], &tmap, &pmap).unwrap();
+    assert_eq!(body.len(), 1);
+    assert_eq!(
+        body[0].to_string(),
+        "y:i8 = dmuladd_i8i8(c, d, t0) @dsp(??, ??)"
+    );
+}
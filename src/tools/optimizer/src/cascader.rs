@@ -1,20 +1,22 @@
 use crate::errors::Error;
+use crate::target::{Cascade, Target, CASCADE};
 use asm::ast::*;
 use itertools::Itertools;
 use std::collections::HashMap;
 use std::rc::Rc;
+use std::str::FromStr;
 
 type Map = HashMap<String, InstrAsm>;
 type Pair = HashMap<String, String>;
 
-fn create_map_and_pair(prog: &Prog) -> Result<(Map, Pair), Error> {
+fn create_map_and_pair(prog: &Prog, rule: &Cascade) -> Result<(Map, Pair), Error> {
     let mut map = Map::new();
     let mut pair = Pair::new();
     for instr in prog.body() {
         match instr {
-            Instr::Asm(instr) if instr.op().name().as_str() == "dmuladdrega_i8i8" => {
+            Instr::Asm(instr) if instr.op().name() == rule.op() => {
                 let dst = instr.dst().get_term(0)?;
-                let arg = instr.arg().get_term(2)?;
+                let arg = instr.arg().get_term(rule.input())?;
                 let dst_id = dst.get_id()?;
                 let arg_id = arg.get_id()?;
                 map.insert(dst_id.clone(), instr.clone());
@@ -32,7 +34,7 @@ fn create_map_and_pair(prog: &Prog) -> Result<(Map, Pair), Error> {
     Ok((map, pair))
 }
 
-fn find_tail(pair: &Pair) -> String {
+fn find_head(pair: &Pair) -> String {
     // make it ordered
     let keys: Vec<String> = pair
         .iter()
@@ -40,22 +42,22 @@ fn find_tail(pair: &Pair) -> String {
         .map(|(x, _)| x)
         .cloned()
         .collect();
-    let mut tail = keys[0].clone();
+    let mut head = keys[0].clone();
     let mut prev = String::new();
-    while tail != prev {
-        prev = tail.clone();
+    while head != prev {
+        prev = head.clone();
         for (k, v) in pair {
-            if v == &tail {
-                tail = k.clone();
+            if v == &head {
+                head = k.clone();
             }
         }
     }
-    tail
+    head
 }
 
-fn find_cascade(pair: &Pair, tail: &str) -> (Vec<String>, Pair) {
+fn find_cascade(pair: &Pair, head: &str) -> (Vec<String>, Pair) {
     let mut stack: Vec<String> = Vec::new();
-    stack.push(tail.to_string());
+    stack.push(head.to_string());
     let mut cascade: Vec<String> = Vec::new();
     let mut pair = pair.clone();
     while let Some(cur) = stack.pop() {
@@ -72,14 +74,36 @@ fn find_cascade_all(pair: &Pair) -> Vec<Vec<String>> {
     let mut pair = pair.clone();
     let mut res: Vec<Vec<String>> = Vec::new();
     while !pair.is_empty() {
-        let tail = find_tail(&pair);
-        let (c, p) = find_cascade(&pair, &tail);
+        let head = find_head(&pair);
+        let (c, p) = find_cascade(&pair, &head);
         pair = p;
         res.push(c);
     }
     res
 }
 
+// break cascades longer than height into segments of similar length,
+// a segment starts with a head reading the previous segment through
+// the fabric, which has the same latency as the cascade input
+fn split_cascade_all(cascade: Vec<Vec<String>>, height: Option<usize>) -> Vec<Vec<String>> {
+    if let Some(height) = height {
+        let mut res: Vec<Vec<String>> = Vec::new();
+        for c in cascade {
+            let num = (c.len() + height.max(1) - 1) / height.max(1);
+            let len = (c.len() + num - 1) / num;
+            for seg in c.chunks(len) {
+                // a single instruction is not a cascade
+                if seg.len() > 1 {
+                    res.push(seg.to_vec());
+                }
+            }
+        }
+        res
+    } else {
+        cascade
+    }
+}
+
 fn x_coord(x: u64) -> ExprCoord {
     let var = format!("x{}", x);
     ExprCoord::Var(var)
@@ -97,64 +121,63 @@ fn y_add_val_coord(y: u64, val: u64) -> ExprCoord {
     ExprCoord::Bin(OpCoord::Add, y, one)
 }
 
-#[allow(unused_assignments)]
-fn replace_map(map: &Map, cascade: &[Vec<String>]) -> Map {
+fn replace_instr(instr: &InstrAsm, op: &str, x: ExprCoord, y: ExprCoord) -> InstrAsm {
+    let mut instr = instr.clone();
+    instr.set_op(OpAsm::Op(op.to_string()));
+    let mut loc = instr.loc().clone();
+    loc.set_x(x);
+    loc.set_y(y);
+    instr.set_loc(loc);
+    instr
+}
+
+// every cascade gets its own column, starting at column offset
+fn replace_map(map: &Map, cascade: &[Vec<String>], rule: &Cascade, offset: u64) -> Map {
     let mut res = Map::new();
-    for (x, c) in cascade.iter().enumerate() {
-        let mut y: u64 = 1;
-        let mut cur = c.clone();
-        cur.reverse();
-        if let Some(tail) = cur.pop() {
-            if let Some(instr) = map.get(&tail) {
-                let mut instr = instr.clone();
-                let op = String::from("dmuladdregaco_i8i8");
-                let op = OpAsm::Op(op);
-                instr.set_op(op);
-                let mut loc = instr.loc().clone();
-                loc.set_x(x_coord(x as u64));
-                loc.set_y(y_coord(x as u64));
-                instr.set_loc(loc);
-                res.insert(tail.clone(), instr);
-            }
-        }
-        while cur.len() > 1 {
-            if let Some(mid) = cur.pop() {
-                if let Some(instr) = map.get(&mid) {
-                    let mut instr = instr.clone();
-                    let op = String::from("dmuladdregacio_i8i8");
-                    let op = OpAsm::Op(op);
-                    instr.set_op(op);
-                    let mut loc = instr.loc().clone();
-                    loc.set_x(x_coord(x as u64));
-                    loc.set_y(y_add_val_coord(x as u64, y));
-                    instr.set_loc(loc);
-                    res.insert(mid.clone(), instr);
-                    y += 1;
-                }
-            }
-        }
-        if let Some(head) = cur.pop() {
-            if let Some(instr) = map.get(&head) {
-                let mut instr = instr.clone();
-                let op = String::from("dmuladdregaci_i8i8");
-                let op = OpAsm::Op(op);
-                instr.set_op(op);
-                let mut loc = instr.loc().clone();
-                loc.set_x(x_coord(x as u64));
-                loc.set_y(y_add_val_coord(x as u64, y));
-                instr.set_loc(loc);
-                res.insert(head.clone(), instr);
-                y += 1;
+    for (i, c) in cascade.iter().enumerate() {
+        let x = offset + i as u64;
+        let last = c.len() - 1;
+        for (y, id) in c.iter().enumerate() {
+            if let Some(instr) = map.get(id) {
+                let instr = if y == 0 {
+                    replace_instr(instr, &rule.head(), x_coord(x), y_coord(x))
+                } else if y == last {
+                    replace_instr(
+                        instr,
+                        &rule.tail(),
+                        x_coord(x),
+                        y_add_val_coord(x, y as u64),
+                    )
+                } else {
+                    replace_instr(
+                        instr,
+                        &rule.middle(),
+                        x_coord(x),
+                        y_add_val_coord(x, y as u64),
+                    )
+                };
+                res.insert(id.clone(), instr);
             }
         }
     }
     res
 }
 
-pub fn cascader(prog: &Prog) -> Result<Prog, Error> {
-    let (map, pair) = create_map_and_pair(&prog)?;
-    let cas = find_cascade_all(&pair);
-    let map = replace_map(&map, &cas);
+/// chain instructions of prog following the cascades in target, with
+/// at most height instructions per chain
+pub fn cascader_with_height(
+    prog: &Prog,
+    target: &Target,
+    height: Option<usize>,
+) -> Result<Prog, Error> {
+    let mut map = Map::new();
+    let mut offset: u64 = 0;
+    for rule in target.cascade() {
+        let (instr, pair) = create_map_and_pair(prog, rule)?;
+        let cas = split_cascade_all(find_cascade_all(&pair), height);
+        map.extend(replace_map(&instr, &cas, rule, offset));
+        offset += cas.len() as u64;
+    }
     let mut body: Vec<Instr> = Vec::new();
     for instr in prog.body() {
         match instr {
@@ -174,3 +197,14 @@ pub fn cascader(prog: &Prog) -> Result<Prog, Error> {
     prog.set_body(body);
     Ok(prog)
 }
+
+/// chain instructions of prog following the cascades in target
+pub fn cascader_with_target(prog: &Prog, target: &Target) -> Result<Prog, Error> {
+    cascader_with_height(prog, target, None)
+}
+
+/// chain instructions of prog following the cascades of the default target
+pub fn cascader(prog: &Prog) -> Result<Prog, Error> {
+    let target = Target::from_str(CASCADE)?;
+    cascader_with_target(prog, &target)
+}
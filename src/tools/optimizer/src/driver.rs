@@ -1,9 +1,14 @@
-use crate::cascader::cascader;
+use crate::cascader::cascader_with_height;
 use crate::errors::Error;
 use crate::opt::{Opt, Optimization};
+use crate::target::{Target, CASCADE};
+use asm::ast::Prim;
 use asm::parser::Parser as AsmParser;
+use device::ast::Device;
 use io::file::write_to_file;
+use std::convert::TryFrom;
 use std::path::PathBuf;
+use std::str::FromStr;
 use structopt::StructOpt;
 
 #[derive(Clone, Debug)]
@@ -34,13 +39,35 @@ impl Driver {
     pub fn opts(&self) -> &Opt {
         &self.opts
     }
+    pub fn target(&self) -> Result<Target, Error> {
+        if let Some(path) = self.opts().target() {
+            Target::from_file(path)
+        } else {
+            Target::from_str(CASCADE)
+        }
+    }
+    // cascade height from the flag, otherwise from the device if any
+    pub fn height(&self) -> Result<Option<usize>, Error> {
+        if let Some(max) = self.opts().max_chain() {
+            Ok(Some(max))
+        } else if let Some(path) = self.opts().device() {
+            let device = Device::from_file(path)?;
+            let height = device.max_cascade_height(&Prim::Dsp);
+            match usize::try_from(height) {
+                Ok(height) => Ok(Some(height)),
+                Err(_) => Err(Error::new_driver_error("Invalid cascade height")),
+            }
+        } else {
+            Ok(None)
+        }
+    }
     pub fn run(&self) -> Result<(), Error> {
         let input = self.opts().input();
         let output = self.opts().output();
         match self.opts().optimization() {
             Optimization::Cascade => {
                 let prog = AsmParser::parse_from_file(input)?;
-                let opt = cascader(&prog)?;
+                let opt = cascader_with_height(&prog, &self.target()?, self.height()?)?;
                 write_output(output, &opt.to_string());
                 Ok(())
             }
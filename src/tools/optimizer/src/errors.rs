@@ -1,13 +1,18 @@
 use asm::errors::Error as AsmError;
+use device::errors::Error as DeviceError;
 use ir::errors::Error as IrError;
 use std::fmt;
+use std::num::ParseIntError;
 
 #[derive(Debug)]
 pub enum Error {
     Ir(IrError),
     Asm(AsmError),
+    Device(DeviceError),
     Driver(String),
     Opt(String),
+    Target(String),
+    ParseInt(ParseIntError),
 }
 
 impl Error {
@@ -17,6 +22,9 @@ impl Error {
     pub fn new_driver_error(msg: &str) -> Self {
         Error::Driver(msg.to_string())
     }
+    pub fn new_target_error(msg: &str) -> Self {
+        Error::Target(msg.to_string())
+    }
 }
 
 impl From<AsmError> for Error {
@@ -25,19 +33,34 @@ impl From<AsmError> for Error {
     }
 }
 
+impl From<DeviceError> for Error {
+    fn from(e: DeviceError) -> Self {
+        Error::Device(e)
+    }
+}
+
 impl From<IrError> for Error {
     fn from(e: IrError) -> Self {
         Error::Ir(e)
     }
 }
 
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Error::ParseInt(e)
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::Ir(msg) => write!(f, "{}", msg),
             Error::Asm(msg) => write!(f, "{}", msg),
+            Error::Device(msg) => write!(f, "{}", msg),
             Error::Driver(msg) => write!(f, "{}", msg),
             Error::Opt(msg) => write!(f, "{}", msg),
+            Error::Target(msg) => write!(f, "{}", msg),
+            Error::ParseInt(msg) => write!(f, "{}", msg),
         }
     }
 }
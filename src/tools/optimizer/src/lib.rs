@@ -2,3 +2,4 @@ pub mod cascader;
 pub mod driver;
 pub mod errors;
 pub mod opt;
+pub mod target;
@@ -26,6 +26,18 @@ pub struct Opt {
     // Optimization
     #[structopt(long = "opt", default_value = "cascade")]
     pub optimization: Optimization,
+
+    // Target metadata file
+    #[structopt(long = "target", parse(from_os_str))]
+    pub target: Option<PathBuf>,
+
+    // Device file, bounds cascades to the tallest dsp column
+    #[structopt(long = "device", parse(from_os_str))]
+    pub device: Option<PathBuf>,
+
+    // Maximum number of instructions in a cascade, overrides device
+    #[structopt(long = "max-chain")]
+    pub max_chain: Option<usize>,
 }
 
 impl Opt {
@@ -41,6 +53,15 @@ impl Opt {
     pub fn optimization(&self) -> &Optimization {
         &self.optimization
     }
+    pub fn target(&self) -> Option<&PathBuf> {
+        self.target.as_ref()
+    }
+    pub fn device(&self) -> Option<&PathBuf> {
+        self.device.as_ref()
+    }
+    pub fn max_chain(&self) -> Option<usize> {
+        self.max_chain
+    }
 }
 
 #[derive(Clone, Debug)]
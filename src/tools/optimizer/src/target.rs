@@ -0,0 +1,104 @@
+use crate::errors::Error;
+use io::file::read_to_string;
+use std::path::Path;
+use std::str::FromStr;
+
+const HEADER: [&str; 5] = ["op", "input", "head", "middle", "tail"];
+
+/// cascades available in the default target
+pub const CASCADE: &str = include_str!("../../../../examples/xim/cascade.csv");
+
+/// A family of instructions that can be chained through one operand.
+/// The head starts a chain and only drives the cascade output, the
+/// tail ends it and only uses the cascade input, the middle does both
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cascade {
+    pub op: String,
+    pub input: usize,
+    pub head: String,
+    pub middle: String,
+    pub tail: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Target {
+    pub cascade: Vec<Cascade>,
+}
+
+impl Cascade {
+    pub fn op(&self) -> String {
+        self.op.to_string()
+    }
+    pub fn input(&self) -> usize {
+        self.input
+    }
+    pub fn head(&self) -> String {
+        self.head.to_string()
+    }
+    pub fn middle(&self) -> String {
+        self.middle.to_string()
+    }
+    pub fn tail(&self) -> String {
+        self.tail.to_string()
+    }
+}
+
+impl Target {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Target, Error> {
+        let content = read_to_string(path.as_ref());
+        Target::from_str(&content)
+    }
+    pub fn cascade(&self) -> &Vec<Cascade> {
+        &self.cascade
+    }
+    pub fn add_cascade(&mut self, cascade: Cascade) -> Result<(), Error> {
+        if self.cascade.iter().any(|c| c.op == cascade.op) {
+            let err = format!(
+                "Error: cascade for {} is defined more than once",
+                cascade.op
+            );
+            Err(Error::new_target_error(&err))
+        } else {
+            self.cascade.push(cascade);
+            Ok(())
+        }
+    }
+}
+
+impl FromStr for Cascade {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let field: Vec<&str> = input.split(',').map(|f| f.trim()).collect();
+        if field.len() == HEADER.len() {
+            Ok(Cascade {
+                op: field[0].to_string(),
+                input: field[1].parse()?,
+                head: field[2].to_string(),
+                middle: field[3].to_string(),
+                tail: field[4].to_string(),
+            })
+        } else {
+            let err = format!("Error: {} is not valid cascade", input);
+            Err(Error::new_target_error(&err))
+        }
+    }
+}
+
+impl FromStr for Target {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut lines = input.lines().filter(|l| !l.trim().is_empty());
+        if let Some(header) = lines.next() {
+            let header: Vec<&str> = header.split(',').map(|f| f.trim()).collect();
+            if header != HEADER {
+                let err = format!("Error: target header must be {}", HEADER.join(","));
+                return Err(Error::new_target_error(&err));
+            }
+        }
+        let mut target = Target::default();
+        for line in lines {
+            target.add_cascade(Cascade::from_str(line)?)?;
+        }
+        Ok(target)
+    }
+}
@@ -1,5 +1,6 @@
 use asm::parser::Parser as AsmParser;
-use optimizer::cascader::cascader;
+use optimizer::cascader::{cascader, cascader_with_height, cascader_with_target};
+use optimizer::target::Target;
 
 #[test]
 fn tdot() {
@@ -8,3 +9,38 @@ fn tdot() {
     let res = cascader(&prog).unwrap();
     assert_eq!(res, exp);
 }
+
+#[test]
+fn tdot_target() {
+    let prog = AsmParser::parse_from_file("../../../examples/asm/tdot_5_3.asm").unwrap();
+    let exp = AsmParser::parse_from_file("../../../examples/asm/tdot_5_3_opt.asm").unwrap();
+    let target = Target::from_file("../../../examples/xim/cascade.csv").unwrap();
+    let res = cascader_with_target(&prog, &target).unwrap();
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn empty_target() {
+    let prog = AsmParser::parse_from_file("../../../examples/asm/tdot_5_3.asm").unwrap();
+    let res = cascader_with_target(&prog, &Target::default()).unwrap();
+    assert_eq!(res, prog);
+}
+
+#[test]
+fn tdot_split() {
+    let prog = AsmParser::parse_from_file("../../../examples/asm/tdot_5_3.asm").unwrap();
+    let exp = AsmParser::parse_from_file("../../../examples/asm/tdot_5_3_split.asm").unwrap();
+    let target = Target::from_file("../../../examples/xim/cascade.csv").unwrap();
+    let res = cascader_with_height(&prog, &target, Some(2)).unwrap();
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn tdot_i16_target() {
+    let prog = AsmParser::parse_from_file("../../../examples/asm/tdot_2_3_i16.asm").unwrap();
+    let exp = AsmParser::parse_from_file("../../../examples/asm/tdot_2_3_i16_opt.asm").unwrap();
+    let target = Target::from_file("../../../examples/xim/cascade.csv").unwrap();
+    assert_eq!(target.cascade().len(), 2);
+    let res = cascader_with_target(&prog, &target).unwrap();
+    assert_eq!(res, exp);
+}
@@ -0,0 +1,32 @@
+use crate::errors::Error;
+use device::ast::Device;
+use std::collections::HashMap;
+use xir::ast::*;
+
+/// check that every location in prog is a legal and free bel of
+/// device, reporting every violation at once
+pub fn check_xir_prog(prog: &Prog, device: &Device) -> Result<(), Error> {
+    let mut errors: Vec<String> = Vec::new();
+    let mut occupied: HashMap<Loc, Id> = HashMap::new();
+    for instr in prog.body() {
+        if let Some(mach) = instr.mach() {
+            if let Some(loc) = mach.loc() {
+                let id = mach.dst().get_id(0)?;
+                if !loc.is_placed() {
+                    errors.push(format!("{} is not placed, location is {}", id, loc));
+                } else if let Err(err) = device.check_loc(&id, loc) {
+                    errors.push(err.to_string());
+                } else if let Some(other) = occupied.get(loc) {
+                    errors.push(format!("{} and {} are both placed at {}", other, id, loc));
+                } else {
+                    occupied.insert(loc.clone(), id);
+                }
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::new_check_error(&errors.join("\n")))
+    }
+}
@@ -0,0 +1,53 @@
+use asm::errors::Error as AsmError;
+use ir::errors::Error as IrError;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    Ir(IrError),
+    Asm(AsmError),
+    Resolve(String),
+    Place(String),
+    Check(String),
+    Pack(String),
+}
+
+impl Error {
+    pub fn new_resolve_error(msg: &str) -> Self {
+        Error::Resolve(msg.to_string())
+    }
+    pub fn new_place_error(msg: &str) -> Self {
+        Error::Place(msg.to_string())
+    }
+    pub fn new_check_error(msg: &str) -> Self {
+        Error::Check(msg.to_string())
+    }
+    pub fn new_pack_error(msg: &str) -> Self {
+        Error::Pack(msg.to_string())
+    }
+}
+
+impl From<AsmError> for Error {
+    fn from(e: AsmError) -> Self {
+        Error::Asm(e)
+    }
+}
+
+impl From<IrError> for Error {
+    fn from(e: IrError) -> Self {
+        Error::Ir(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Ir(msg) => write!(f, "{}", msg),
+            Error::Asm(msg) => write!(f, "{}", msg),
+            Error::Resolve(msg) => write!(f, "{}", msg),
+            Error::Place(msg) => write!(f, "{}", msg),
+            Error::Check(msg) => write!(f, "{}", msg),
+            Error::Pack(msg) => write!(f, "{}", msg),
+        }
+    }
+}
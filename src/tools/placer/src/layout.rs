@@ -0,0 +1,68 @@
+use asm::ast::Prim;
+use device::ast::Device;
+
+/// Sites available in a device, coordinates follow asm locations
+pub trait Layout {
+    /// true if there is a site for prim at (x, y)
+    fn contains(&self, prim: &Prim, x: u64, y: u64) -> bool;
+    /// sorted x coordinates with at least one prim site
+    fn columns(&self, prim: &Prim) -> Vec<u64>;
+    /// sorted y coordinates with at least one prim site
+    fn rows(&self, prim: &Prim) -> Vec<u64>;
+    /// every prim site, column by column
+    fn sites(&self, prim: &Prim) -> Vec<(u64, u64)> {
+        let mut res: Vec<(u64, u64)> = Vec::new();
+        let rows = self.rows(prim);
+        for x in self.columns(prim) {
+            for y in rows.iter() {
+                if self.contains(prim, x, *y) {
+                    res.push((x, *y));
+                }
+            }
+        }
+        res
+    }
+}
+
+/// Rectangular layout with the same sites for every primitive
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid {
+    pub width: u64,
+    pub height: u64,
+}
+
+impl Grid {
+    pub fn new(width: u64, height: u64) -> Grid {
+        Grid { width, height }
+    }
+    pub fn width(&self) -> u64 {
+        self.width
+    }
+    pub fn height(&self) -> u64 {
+        self.height
+    }
+}
+
+impl Layout for Grid {
+    fn contains(&self, prim: &Prim, x: u64, y: u64) -> bool {
+        !prim.is_any() && x < self.width && y < self.height
+    }
+    fn columns(&self, _: &Prim) -> Vec<u64> {
+        (0..self.width).collect()
+    }
+    fn rows(&self, _: &Prim) -> Vec<u64> {
+        (0..self.height).collect()
+    }
+}
+
+impl Layout for Device {
+    fn contains(&self, prim: &Prim, x: u64, y: u64) -> bool {
+        Device::contains(self, prim, x, y)
+    }
+    fn columns(&self, prim: &Prim) -> Vec<u64> {
+        Device::columns(self, prim)
+    }
+    fn rows(&self, prim: &Prim) -> Vec<u64> {
+        Device::rows(self, prim)
+    }
+}
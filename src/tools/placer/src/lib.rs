@@ -0,0 +1,6 @@
+pub mod check;
+pub mod errors;
+pub mod layout;
+pub mod pack;
+pub mod place;
+pub mod resolver;
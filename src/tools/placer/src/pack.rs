@@ -0,0 +1,372 @@
+use crate::errors::Error;
+use crate::layout::Layout;
+use crate::place::{distance, Coord};
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use xir::ast::*;
+
+const LUT: [BelLut; 8] = [
+    BelLut::A6,
+    BelLut::B6,
+    BelLut::C6,
+    BelLut::D6,
+    BelLut::E6,
+    BelLut::F6,
+    BelLut::G6,
+    BelLut::H6,
+];
+
+const REG: [BelReg; 8] = [
+    BelReg::A,
+    BelReg::B,
+    BelReg::C,
+    BelReg::D,
+    BelReg::E,
+    BelReg::F,
+    BelReg::G,
+    BelReg::H,
+];
+
+const REG2: [BelReg; 8] = [
+    BelReg::A2,
+    BelReg::B2,
+    BelReg::C2,
+    BelReg::D2,
+    BelReg::E2,
+    BelReg::F2,
+    BelReg::G2,
+    BelReg::H2,
+];
+
+// registers in the same half slice share clock enable and reset
+type Ctrl = (OpMach, ExprTerm);
+
+#[derive(Clone, Debug, Default)]
+struct Slice {
+    lut: [bool; 8],
+    reg: [[bool; 2]; 8],
+    ctrl: [Option<Ctrl>; 2],
+    hint: Option<Coord>,
+}
+
+impl Slice {
+    fn new(hint: Option<Coord>) -> Slice {
+        Slice {
+            hint,
+            ..Slice::default()
+        }
+    }
+    fn free_lut(&self) -> Option<usize> {
+        self.lut.iter().position(|used| !used)
+    }
+    fn is_reg_free(&self, letter: usize, second: usize, ctrl: &Ctrl) -> bool {
+        let half = &self.ctrl[letter / 4];
+        !self.reg[letter][second] && half.as_ref().map_or(true, |c| c == ctrl)
+    }
+    fn use_reg(&mut self, letter: usize, second: usize, ctrl: &Ctrl) -> Bel {
+        self.reg[letter][second] = true;
+        self.ctrl[letter / 4] = Some(ctrl.clone());
+        if second == 0 {
+            Bel::Reg(REG[letter].clone())
+        } else {
+            Bel::Reg(REG2[letter].clone())
+        }
+    }
+}
+
+fn is_lut(op: &OpMach) -> bool {
+    matches!(
+        op,
+        OpMach::Lut1 | OpMach::Lut2 | OpMach::Lut3 | OpMach::Lut4 | OpMach::Lut5 | OpMach::Lut6
+    )
+}
+
+fn is_reg(op: &OpMach) -> bool {
+    matches!(op, OpMach::Fdre | OpMach::Fdse)
+}
+
+fn is_carry(op: &OpMach) -> bool {
+    matches!(op, OpMach::CarryAdd | OpMach::CarrySub)
+}
+
+fn letter_from_bel(bel: &Bel) -> Option<usize> {
+    match bel {
+        Bel::Lut(lut) => LUT.iter().position(|l| l == lut),
+        _ => None,
+    }
+}
+
+fn hint_from_instr(instr: &InstrMach) -> Option<Coord> {
+    match instr.loc().map(|l| (l.x(), l.y())) {
+        Some((ExprCoord::Val(x), ExprCoord::Val(y))) => Some((*x, *y)),
+        _ => None,
+    }
+}
+
+// instr defining term, looking through id instructions
+fn def_of<'a>(def: &HashMap<Id, &'a Instr>, term: &ExprTerm) -> Option<&'a Instr> {
+    let mut visited: HashSet<Id> = HashSet::new();
+    let mut cur = term.id()?;
+    while visited.insert(cur.clone()) {
+        let instr = def.get(&cur)?;
+        match instr {
+            Instr::Basc(basc) if basc.op() == &OpBasc::Id => {
+                cur = basc.arg().get_term(0).ok()?.id()?;
+            }
+            _ => return Some(instr),
+        }
+    }
+    None
+}
+
+fn lut_of(def: &HashMap<Id, &Instr>, term: &ExprTerm) -> Option<Id> {
+    match def_of(def, term) {
+        Some(Instr::Mach(mach)) if is_lut(mach.op()) => mach.dst().get_id(0).ok(),
+        _ => None,
+    }
+}
+
+// bits of term, lsb first, looking through cat instructions
+fn bits_of(def: &HashMap<Id, &Instr>, term: &ExprTerm) -> Vec<ExprTerm> {
+    match def_of(def, term) {
+        Some(Instr::Basc(basc)) if basc.op() == &OpBasc::Cat => basc.arg().clone().into(),
+        _ => vec![term.clone()],
+    }
+}
+
+// slice and letter of the lut or carry output driving a register
+fn driver_of(
+    def: &HashMap<Id, &Instr>,
+    assign: &HashMap<Id, (usize, Bel)>,
+    term: &ExprTerm,
+) -> Option<(usize, usize)> {
+    match def_of(def, term)? {
+        Instr::Mach(mach) if is_lut(mach.op()) => {
+            let (slice, bel) = assign.get(&mach.dst().get_id(0).ok()?)?;
+            Some((*slice, letter_from_bel(bel)?))
+        }
+        Instr::Basc(basc) if basc.op() == &OpBasc::Ext => {
+            let index = usize::try_from(basc.attr().get_val(0).ok()?).ok()?;
+            let src = basc.arg().get_term(0).ok()?;
+            match def_of(def, src)? {
+                Instr::Mach(mach) if is_carry(mach.op()) && index < 8 => {
+                    let (slice, _) = assign.get(&mach.dst().get_id(0).ok()?)?;
+                    Some((*slice, index))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn pack_carry(
+    def: &HashMap<Id, &Instr>,
+    slices: &mut Vec<Slice>,
+    assign: &mut HashMap<Id, (usize, Bel)>,
+    instr: &InstrMach,
+) -> Result<(), Error> {
+    let index = slices.len();
+    // every lut in the slice is either driving the carry or used as a route-through
+    let mut slice = Slice::new(hint_from_instr(instr));
+    slice.lut = [true; 8];
+    slices.push(slice);
+    assign.insert(
+        instr.dst().get_id(0)?,
+        (index, Bel::Carry(BelCarry::Carry8)),
+    );
+    let sum = instr.arg().get_term(1)?;
+    for (letter, bit) in bits_of(def, sum).iter().enumerate().take(8) {
+        if let Some(lut) = lut_of(def, bit) {
+            if assign.contains_key(&lut) {
+                let err = format!("{} drives more than one carry", lut);
+                return Err(Error::new_pack_error(&err));
+            }
+            assign.insert(lut, (index, Bel::Lut(LUT[letter].clone())));
+        }
+    }
+    Ok(())
+}
+
+// luts only share a slice with luts placed on the same site, unplaced
+// ones with other unplaced ones, keeping the bel letter if it is free
+fn pack_lut(
+    slices: &mut Vec<Slice>,
+    assign: &mut HashMap<Id, (usize, Bel)>,
+    instr: &InstrMach,
+) -> Result<(), Error> {
+    let id = instr.dst().get_id(0)?;
+    if assign.contains_key(&id) {
+        return Ok(());
+    }
+    let hint = hint_from_instr(instr);
+    let wanted = instr.loc().and_then(|l| letter_from_bel(l.bel()));
+    let free = slices
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| s.hint == hint)
+        .find_map(|(i, s)| match wanted {
+            Some(l) if hint.is_some() && !s.lut[l] => Some((i, l)),
+            _ => s.free_lut().map(|l| (i, l)),
+        });
+    let (index, letter) = if let Some(free) = free {
+        free
+    } else {
+        slices.push(Slice::new(hint));
+        let letter = if hint.is_some() {
+            wanted.unwrap_or(0)
+        } else {
+            0
+        };
+        (slices.len() - 1, letter)
+    };
+    slices[index].lut[letter] = true;
+    assign.insert(id, (index, Bel::Lut(LUT[letter].clone())));
+    Ok(())
+}
+
+fn pack_reg(
+    def: &HashMap<Id, &Instr>,
+    slices: &mut Vec<Slice>,
+    assign: &mut HashMap<Id, (usize, Bel)>,
+    instr: &InstrMach,
+) -> Result<(), Error> {
+    let id = instr.dst().get_id(0)?;
+    let ctrl: Ctrl = (instr.op().clone(), instr.arg().get_term(1)?.clone());
+    // next to the lut or carry driving it, otherwise the first free register
+    // of a slice on the same site
+    let hint = hint_from_instr(instr);
+    let mut candidates: Vec<(usize, usize)> = Vec::new();
+    if let Some(driver) = driver_of(def, assign, instr.arg().get_term(0)?) {
+        candidates.push(driver);
+    }
+    for (slice, _) in slices.iter().enumerate().filter(|(_, s)| s.hint == hint) {
+        for letter in 0..8 {
+            candidates.push((slice, letter));
+        }
+    }
+    for (slice, letter) in candidates {
+        for second in 0..2 {
+            if slices[slice].is_reg_free(letter, second, &ctrl) {
+                let bel = slices[slice].use_reg(letter, second, &ctrl);
+                assign.insert(id, (slice, bel));
+                return Ok(());
+            }
+        }
+    }
+    let mut slice = Slice::new(hint);
+    let bel = slice.use_reg(0, 0, &ctrl);
+    assign.insert(id, (slices.len(), bel));
+    slices.push(slice);
+    Ok(())
+}
+
+// pick a site for every slice, the site of its hint if it is free,
+// otherwise the closest free one. Sites in reserved are not used
+fn sites_from_slices(
+    slices: &[Slice],
+    reserved: &HashSet<Coord>,
+    layout: &impl Layout,
+) -> Result<Vec<Coord>, Error> {
+    let prim = Prim::Lut;
+    let mut free: Vec<Coord> = layout
+        .sites(&prim)
+        .into_iter()
+        .filter(|c| !reserved.contains(c))
+        .collect();
+    let mut res: Vec<Option<Coord>> = vec![None; slices.len()];
+    for (i, slice) in slices.iter().enumerate() {
+        if let Some(hint) = slice.hint {
+            if let Some(j) = free.iter().position(|c| *c == hint) {
+                res[i] = Some(free.remove(j));
+            }
+        }
+    }
+    for (i, slice) in slices.iter().enumerate() {
+        if res[i].is_some() {
+            continue;
+        }
+        let target = slice.hint.unwrap_or((0, 0));
+        let closest = free
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, c)| distance(**c, target))
+            .map(|(i, _)| i);
+        if let Some(j) = closest {
+            res[i] = Some(free.remove(j));
+        } else {
+            return Err(Error::new_pack_error("there are no free slice sites left"));
+        }
+    }
+    Ok(res.into_iter().flatten().collect())
+}
+
+// sites taken by placed lut rams, they use the luts of a whole slice
+fn reserved_from_prog(prog: &Prog) -> HashSet<Coord> {
+    let mut res: HashSet<Coord> = HashSet::new();
+    for instr in prog.body() {
+        if let Instr::Mach(mach) = instr {
+            if mach.op() == &OpMach::Lram {
+                if let Some(coord) = hint_from_instr(mach) {
+                    res.insert(coord);
+                }
+            }
+        }
+    }
+    res
+}
+
+/// pack lut, register and carry instructions of prog into slices of
+/// layout, and rewrite their locations with the bel and site assigned.
+/// Luts driving a carry share its slice, registers are placed next to
+/// their driver when the clock enable and reset of the half slice allow it.
+/// Placed instructions keep their site whenever it is free, and sites of
+/// placed lut rams are never used.
+pub fn pack(prog: &Prog, layout: &impl Layout) -> Result<Prog, Error> {
+    let mut def: HashMap<Id, &Instr> = HashMap::new();
+    let mut mach: Vec<&InstrMach> = Vec::new();
+    for instr in prog.body() {
+        let dst: Vec<ExprTerm> = instr.dst().clone().into();
+        for d in dst {
+            if let Some(id) = d.id() {
+                def.insert(id, instr);
+            }
+        }
+        if let Instr::Mach(instr) = instr {
+            mach.push(instr);
+        }
+    }
+    let mut slices: Vec<Slice> = Vec::new();
+    let mut assign: HashMap<Id, (usize, Bel)> = HashMap::new();
+    for instr in mach.iter().filter(|m| is_carry(m.op())) {
+        pack_carry(&def, &mut slices, &mut assign, instr)?;
+    }
+    for instr in mach.iter().filter(|m| is_lut(m.op())) {
+        pack_lut(&mut slices, &mut assign, instr)?;
+    }
+    for instr in mach.iter().filter(|m| is_reg(m.op())) {
+        pack_reg(&def, &mut slices, &mut assign, instr)?;
+    }
+    let site = sites_from_slices(&slices, &reserved_from_prog(prog), layout)?;
+    let mut body: Vec<Instr> = Vec::new();
+    for instr in prog.body() {
+        match instr {
+            Instr::Mach(mach) => {
+                let mut mach = mach.clone();
+                if let Some((slice, bel)) = assign.get(&mach.dst().get_id(0)?) {
+                    let (x, y) = site[*slice];
+                    mach.set_loc(Loc {
+                        bel: bel.clone(),
+                        x: ExprCoord::Val(x),
+                        y: ExprCoord::Val(y),
+                    });
+                }
+                body.push(Instr::from(mach));
+            }
+            _ => body.push(instr.clone()),
+        }
+    }
+    let mut res = prog.clone();
+    res.set_body(body);
+    Ok(res)
+}
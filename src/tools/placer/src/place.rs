@@ -0,0 +1,312 @@
+use crate::errors::Error;
+use crate::layout::Layout;
+use crate::resolver::resolve;
+use asm::ast::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::{HashMap, HashSet};
+
+const SEED: u64 = 0;
+const MOVES_PER_CELL: usize = 16;
+const COOLING_RATE: f64 = 0.9;
+const MIN_TEMPERATURE: f64 = 0.05;
+
+pub(crate) type Coord = (u64, u64);
+
+#[derive(Clone, Debug)]
+struct Cell {
+    id: Id,
+    prim: Prim,
+    coord: Coord,
+    fixed: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Netlist {
+    cell: Vec<Cell>,
+    net: Vec<Vec<usize>>,
+    // nets connected to every cell
+    cell_net: Vec<Vec<usize>>,
+    // cell index on every used site
+    occupied: HashMap<(Prim, Coord), usize>,
+}
+
+pub(crate) fn distance(a: Coord, b: Coord) -> u64 {
+    let dx = if a.0 > b.0 { a.0 - b.0 } else { b.0 - a.0 };
+    let dy = if a.1 > b.1 { a.1 - b.1 } else { b.1 - a.1 };
+    dx + dy
+}
+
+// follow wire instructions back to the asm instructions driving them
+fn drivers(wire: &HashMap<Id, Vec<Id>>, id: &str) -> Vec<Id> {
+    let mut res: Vec<Id> = Vec::new();
+    let mut visited: HashSet<Id> = HashSet::new();
+    let mut stack: Vec<Id> = vec![id.to_string()];
+    while let Some(cur) = stack.pop() {
+        if visited.insert(cur.clone()) {
+            if let Some(arg) = wire.get(&cur) {
+                stack.extend(arg.iter().cloned());
+            } else {
+                res.push(cur);
+            }
+        }
+    }
+    res
+}
+
+fn coord_from_loc(loc: &Loc) -> Option<Coord> {
+    match (loc.x(), loc.y()) {
+        (ExprCoord::Val(x), ExprCoord::Val(y)) => Some((*x, *y)),
+        (_, _) => None,
+    }
+}
+
+impl Netlist {
+    fn hpwl(&self, net: usize) -> u64 {
+        let mut coord = self.net[net].iter().map(|c| self.cell[*c].coord);
+        if let Some(first) = coord.next() {
+            let (mut xmin, mut ymin) = first;
+            let (mut xmax, mut ymax) = first;
+            for (x, y) in coord {
+                xmin = xmin.min(x);
+                xmax = xmax.max(x);
+                ymin = ymin.min(y);
+                ymax = ymax.max(y);
+            }
+            (xmax - xmin) + (ymax - ymin)
+        } else {
+            0
+        }
+    }
+    fn cost(&self) -> u64 {
+        (0..self.net.len()).map(|n| self.hpwl(n)).sum()
+    }
+    fn nets_of(&self, cells: &[usize]) -> Vec<usize> {
+        let mut res: Vec<usize> = cells
+            .iter()
+            .flat_map(|c| self.cell_net[*c].iter().cloned())
+            .collect();
+        res.sort_unstable();
+        res.dedup();
+        res
+    }
+    fn neighbors(&self, cell: usize) -> Vec<usize> {
+        let mut res: Vec<usize> = Vec::new();
+        for n in self.cell_net[cell].iter() {
+            for c in self.net[*n].iter() {
+                if *c != cell && !res.contains(c) {
+                    res.push(*c);
+                }
+            }
+        }
+        res
+    }
+    fn set_coord(&mut self, cell: usize, coord: Coord) {
+        let prim = self.cell[cell].prim.clone();
+        let old = self.cell[cell].coord;
+        if self.occupied.get(&(prim.clone(), old)) == Some(&cell) {
+            self.occupied.remove(&(prim.clone(), old));
+        }
+        self.cell[cell].coord = coord;
+        self.occupied.insert((prim, coord), cell);
+    }
+}
+
+fn netlist_try_from_prog(prog: &Prog) -> Result<Netlist, Error> {
+    let mut netlist = Netlist::default();
+    let mut index: HashMap<Id, usize> = HashMap::new();
+    let mut wire: HashMap<Id, Vec<Id>> = HashMap::new();
+    for instr in prog.body() {
+        match instr {
+            Instr::Asm(instr) => {
+                let id = instr.dst().get_id(0)?;
+                let loc = instr.loc();
+                if loc.prim().is_any() {
+                    let err = format!("{} must have a primitive to be placed", id);
+                    return Err(Error::new_place_error(&err));
+                }
+                let coord = coord_from_loc(loc);
+                index.insert(id.clone(), netlist.cell.len());
+                netlist.cell.push(Cell {
+                    id,
+                    prim: loc.prim().clone(),
+                    coord: coord.unwrap_or((0, 0)),
+                    fixed: coord.is_some(),
+                });
+            }
+            Instr::Wire(instr) => {
+                let dst = instr.dst().get_id(0)?;
+                let arg: Vec<ExprTerm> = instr.arg().clone().into();
+                let arg: Vec<Id> = arg.iter().filter_map(|t| t.id()).collect();
+                wire.insert(dst, arg);
+            }
+        }
+    }
+    let mut sink: HashMap<usize, Vec<usize>> = HashMap::new();
+    for instr in prog.body() {
+        if let Instr::Asm(instr) = instr {
+            let id = instr.dst().get_id(0)?;
+            let cell = index[&id];
+            let arg: Vec<ExprTerm> = instr.arg().clone().into();
+            for term in arg {
+                if let Some(id) = term.id() {
+                    for d in drivers(&wire, &id) {
+                        if let Some(driver) = index.get(&d) {
+                            let net = sink.entry(*driver).or_default();
+                            if *driver != cell && !net.contains(&cell) {
+                                net.push(cell);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    netlist.cell_net = vec![Vec::new(); netlist.cell.len()];
+    let mut driver: Vec<usize> = sink.keys().cloned().collect();
+    driver.sort_unstable();
+    for d in driver {
+        let mut net = vec![d];
+        net.extend(sink[&d].iter());
+        if net.len() > 1 {
+            let n = netlist.net.len();
+            for c in net.iter() {
+                netlist.cell_net[*c].push(n);
+            }
+            netlist.net.push(net);
+        }
+    }
+    for (i, c) in netlist.cell.iter().enumerate() {
+        if c.fixed {
+            netlist.occupied.insert((c.prim.clone(), c.coord), i);
+        }
+    }
+    Ok(netlist)
+}
+
+// place every movable cell on the free site closest to its placed neighbors
+fn initial_placement(netlist: &mut Netlist, site: &HashMap<Prim, Vec<Coord>>) -> Result<(), Error> {
+    let mut placed: Vec<bool> = netlist.cell.iter().map(|c| c.fixed).collect();
+    for cell in 0..netlist.cell.len() {
+        if placed[cell] {
+            continue;
+        }
+        let prim = netlist.cell[cell].prim.clone();
+        let anchor: Vec<Coord> = netlist
+            .neighbors(cell)
+            .iter()
+            .filter(|n| placed[**n])
+            .map(|n| netlist.cell[*n].coord)
+            .collect();
+        let target = if anchor.is_empty() {
+            (0, 0)
+        } else {
+            let len = anchor.len() as u64;
+            let x: u64 = anchor.iter().map(|c| c.0).sum();
+            let y: u64 = anchor.iter().map(|c| c.1).sum();
+            (x / len, y / len)
+        };
+        let free = site.get(&prim).and_then(|s| {
+            s.iter()
+                .filter(|c| !netlist.occupied.contains_key(&(prim.clone(), **c)))
+                .min_by_key(|c| distance(**c, target))
+                .cloned()
+        });
+        if let Some(coord) = free {
+            netlist.set_coord(cell, coord);
+            placed[cell] = true;
+        } else {
+            let err = format!(
+                "there are no free {} sites left to place {}",
+                prim, netlist.cell[cell].id
+            );
+            return Err(Error::new_place_error(&err));
+        }
+    }
+    Ok(())
+}
+
+// simulated annealing, moving a cell to a random site of the same
+// primitive and swapping it with the cell already there, if any
+fn anneal(netlist: &mut Netlist, site: &HashMap<Prim, Vec<Coord>>) {
+    let movable: Vec<usize> = (0..netlist.cell.len())
+        .filter(|c| !netlist.cell[*c].fixed)
+        .collect();
+    if movable.is_empty() || netlist.net.is_empty() {
+        return;
+    }
+    let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+    let mut temp = netlist.cost() as f64 / netlist.net.len() as f64;
+    let moves = MOVES_PER_CELL * movable.len();
+    while temp > MIN_TEMPERATURE {
+        for _ in 0..moves {
+            let cell = movable[rng.gen_range(0..movable.len())];
+            let prim = netlist.cell[cell].prim.clone();
+            let candidates = &site[&prim];
+            let to = candidates[rng.gen_range(0..candidates.len())];
+            let from = netlist.cell[cell].coord;
+            let other = netlist.occupied.get(&(prim.clone(), to)).cloned();
+            if to == from || other.map_or(false, |o| netlist.cell[o].fixed) {
+                continue;
+            }
+            let mut affected = vec![cell];
+            affected.extend(other);
+            let nets = netlist.nets_of(&affected);
+            let before: u64 = nets.iter().map(|n| netlist.hpwl(*n)).sum();
+            netlist.set_coord(cell, to);
+            if let Some(o) = other {
+                netlist.set_coord(o, from);
+            }
+            let after: u64 = nets.iter().map(|n| netlist.hpwl(*n)).sum();
+            let delta = after as f64 - before as f64;
+            if delta > 0.0 && rng.gen::<f64>() >= (-delta / temp).exp() {
+                netlist.set_coord(cell, from);
+                if let Some(o) = other {
+                    netlist.set_coord(o, to);
+                }
+            }
+        }
+        temp *= COOLING_RATE;
+    }
+}
+
+/// place every instruction with ?? coordinates on a free site of
+/// layout, minimizing the estimated wirelength (half-perimeter).
+/// Fixed and relative coordinates are resolved first and never moved.
+pub fn place(prog: &Prog, layout: &impl Layout) -> Result<Prog, Error> {
+    let prog = resolve(prog, layout)?;
+    let mut netlist = netlist_try_from_prog(&prog)?;
+    let mut site: HashMap<Prim, Vec<Coord>> = HashMap::new();
+    for c in netlist.cell.iter() {
+        if !site.contains_key(&c.prim) {
+            site.insert(c.prim.clone(), layout.sites(&c.prim));
+        }
+    }
+    initial_placement(&mut netlist, &site)?;
+    anneal(&mut netlist, &site);
+    let coord: HashMap<Id, Coord> = netlist
+        .cell
+        .iter()
+        .map(|c| (c.id.clone(), c.coord))
+        .collect();
+    let mut body: Vec<Instr> = Vec::new();
+    for instr in prog.body() {
+        match instr {
+            Instr::Asm(instr) => {
+                let mut instr = instr.clone();
+                let id = instr.dst().get_id(0)?;
+                if let Some((x, y)) = coord.get(&id) {
+                    let mut loc = instr.loc().clone();
+                    loc.set_x(ExprCoord::Val(*x));
+                    loc.set_y(ExprCoord::Val(*y));
+                    instr.set_loc(loc);
+                }
+                body.push(Instr::from(instr));
+            }
+            _ => body.push(instr.clone()),
+        }
+    }
+    let mut res = prog.clone();
+    res.set_body(body);
+    Ok(res)
+}
@@ -0,0 +1,459 @@
+use crate::errors::Error;
+use crate::layout::Layout;
+use asm::ast::*;
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+
+// number of values the search can try before giving up
+const SEARCH_BUDGET: usize = 100_000;
+
+// a coordinate in the form of var + offset, var is optional
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Linear {
+    var: Option<Id>,
+    offset: u64,
+}
+
+#[derive(Clone, Debug)]
+struct Site {
+    id: Id,
+    prim: Prim,
+    x: Linear,
+    y: Linear,
+}
+
+type Occupied = HashMap<(Prim, u64, u64), Id>;
+type Assignment = HashMap<Id, u64>;
+
+impl Linear {
+    fn eval(&self, assign: &Assignment) -> Option<u64> {
+        match &self.var {
+            Some(var) => assign.get(var).map(|v| v + self.offset),
+            None => Some(self.offset),
+        }
+    }
+}
+
+impl Site {
+    fn vars(&self) -> Vec<Id> {
+        let mut vars: Vec<Id> = Vec::new();
+        for v in [&self.x.var, &self.y.var].iter().copied().flatten() {
+            if !vars.contains(v) {
+                vars.push(v.clone());
+            }
+        }
+        vars
+    }
+    fn eval(&self, assign: &Assignment) -> Option<(u64, u64)> {
+        match (self.x.eval(assign), self.y.eval(assign)) {
+            (Some(x), Some(y)) => Some((x, y)),
+            (_, _) => None,
+        }
+    }
+}
+
+fn linear_try_from_coord(coord: &ExprCoord) -> Result<Linear, Error> {
+    match coord {
+        ExprCoord::Val(n) => Ok(Linear {
+            var: None,
+            offset: *n,
+        }),
+        ExprCoord::Var(v) => Ok(Linear {
+            var: Some(v.clone()),
+            offset: 0,
+        }),
+        ExprCoord::Bin(OpCoord::Add, lhs, rhs) => {
+            let lhs = linear_try_from_coord(lhs)?;
+            let rhs = linear_try_from_coord(rhs)?;
+            let var = match (lhs.var, rhs.var) {
+                (Some(_), Some(_)) => {
+                    let err = format!("coordinate {} must have at most one variable", coord);
+                    return Err(Error::new_resolve_error(&err));
+                }
+                (Some(v), None) | (None, Some(v)) => Some(v),
+                (None, None) => None,
+            };
+            Ok(Linear {
+                var,
+                offset: lhs.offset + rhs.offset,
+            })
+        }
+        ExprCoord::Any => Err(Error::new_resolve_error("?? is not a valid coordinate")),
+    }
+}
+
+fn site_try_from_instr(instr: &InstrAsm) -> Result<Option<Site>, Error> {
+    let id = instr.dst().get_id(0)?;
+    let loc = instr.loc();
+    match (loc.x(), loc.y()) {
+        (ExprCoord::Any, ExprCoord::Any) => Ok(None),
+        (ExprCoord::Any, _) | (_, ExprCoord::Any) => {
+            let err = format!(
+                "{} is partially placed, both coordinates must be either ?? or resolvable",
+                id
+            );
+            Err(Error::new_resolve_error(&err))
+        }
+        (x, y) => Ok(Some(Site {
+            id,
+            prim: loc.prim().clone(),
+            x: linear_try_from_coord(x)?,
+            y: linear_try_from_coord(y)?,
+        })),
+    }
+}
+
+fn site_name(prim: &Prim, x: u64, y: u64) -> String {
+    format!("{}({}, {})", prim, x, y)
+}
+
+fn occupy(occupied: &mut Occupied, site: &Site, x: u64, y: u64) -> Result<(), Error> {
+    let key = (site.prim.clone(), x, y);
+    if let Some(other) = occupied.get(&key) {
+        let err = format!(
+            "{} and {} overlap at {}",
+            other,
+            site.id,
+            site_name(&site.prim, x, y)
+        );
+        Err(Error::new_resolve_error(&err))
+    } else {
+        occupied.insert(key, site.id.clone());
+        Ok(())
+    }
+}
+
+// group sites that share variables, in program order
+fn components(site: &[Site]) -> Vec<Vec<Site>> {
+    let mut group: Vec<Vec<Site>> = Vec::new();
+    let mut vars: Vec<HashSet<Id>> = Vec::new();
+    for s in site {
+        let svars: HashSet<Id> = s.vars().into_iter().collect();
+        let mut merged: Vec<Site> = vec![s.clone()];
+        let mut mvars = svars.clone();
+        let mut i = 0;
+        while i < group.len() {
+            if vars[i].is_disjoint(&mvars) {
+                i += 1;
+            } else {
+                let mut g = group.remove(i);
+                let v = vars.remove(i);
+                g.append(&mut merged);
+                merged = g;
+                mvars.extend(v);
+            }
+        }
+        group.push(merged);
+        vars.push(mvars);
+    }
+    group
+}
+
+fn domain(layout: &impl Layout, site: &[Site], var: &str) -> Vec<u64> {
+    for s in site {
+        if s.x.var.as_deref() == Some(var) {
+            return layout
+                .columns(&s.prim)
+                .into_iter()
+                .filter(|c| *c >= s.x.offset)
+                .map(|c| c - s.x.offset)
+                .collect();
+        }
+        if s.y.var.as_deref() == Some(var) {
+            return layout
+                .rows(&s.prim)
+                .into_iter()
+                .filter(|r| *r >= s.y.offset)
+                .map(|r| r - s.y.offset)
+                .collect();
+        }
+    }
+    Vec::new()
+}
+
+// true if every site with all its variables assigned is on a free site
+fn is_consistent(
+    layout: &impl Layout,
+    occupied: &Occupied,
+    site: &[Site],
+    assign: &Assignment,
+) -> bool {
+    let mut used: HashSet<(Prim, u64, u64)> = HashSet::new();
+    for s in site {
+        if let Some((x, y)) = s.eval(assign) {
+            let key = (s.prim.clone(), x, y);
+            if !layout.contains(&s.prim, x, y) || occupied.contains_key(&key) || used.contains(&key)
+            {
+                return false;
+            }
+            used.insert(key);
+        }
+    }
+    true
+}
+
+// sites that share variables, with the values every variable can take
+#[derive(Clone, Debug)]
+struct Component {
+    site: Vec<Site>,
+    vars: Vec<Id>,
+    domains: HashMap<Id, Vec<u64>>,
+}
+
+impl Component {
+    // number of assignments, fewer means tighter constraints
+    fn size(&self) -> usize {
+        self.domains
+            .values()
+            .fold(1_usize, |n, d| n.saturating_mul(d.len()))
+    }
+    fn ids(&self) -> Vec<Id> {
+        self.site.iter().map(|s| s.id.clone()).collect()
+    }
+}
+
+// every assignment of vars that is consistent is passed to leaf, until
+// leaf accepts one of them or the budget runs out
+fn search(
+    layout: &impl Layout,
+    occupied: &mut Occupied,
+    comp: &Component,
+    vars: &[Id],
+    assign: &mut Assignment,
+    budget: &Cell<usize>,
+    leaf: &mut dyn FnMut(&mut Occupied, &Assignment) -> bool,
+) -> bool {
+    if let Some((var, rest)) = vars.split_first() {
+        if let Some(values) = comp.domains.get(var) {
+            for v in values {
+                if budget.get() == 0 {
+                    break;
+                }
+                budget.set(budget.get() - 1);
+                assign.insert(var.clone(), *v);
+                if is_consistent(layout, occupied, &comp.site, assign)
+                    && search(layout, occupied, comp, rest, assign, budget, leaf)
+                {
+                    return true;
+                }
+            }
+        }
+        assign.remove(var);
+        false
+    } else {
+        leaf(occupied, assign)
+    }
+}
+
+// there must be at least as many free sites as sites to place, for
+// every prim
+fn check_capacity(layout: &impl Layout, occupied: &Occupied, site: &[Site]) -> Result<(), Error> {
+    let mut demand: Vec<(Prim, usize)> = Vec::new();
+    for s in site {
+        if let Some(d) = demand.iter_mut().find(|(p, _)| *p == s.prim) {
+            d.1 += 1;
+        } else {
+            demand.push((s.prim.clone(), 1));
+        }
+    }
+    for (prim, n) in demand {
+        let used = occupied.keys().filter(|(p, _, _)| *p == prim).count();
+        let free = layout.sites(&prim).len().saturating_sub(used);
+        if n > free {
+            let err = format!(
+                "unsatisfiable constraints, there are {} {} sites to place but only {} are free",
+                n, prim, free
+            );
+            return Err(Error::new_resolve_error(&err));
+        }
+    }
+    Ok(())
+}
+
+fn check_always_overlap(site: &[Site]) -> Result<(), Error> {
+    let mut seen: HashMap<(Prim, Linear, Linear), Id> = HashMap::new();
+    for s in site {
+        let key = (s.prim.clone(), s.x.clone(), s.y.clone());
+        if let Some(other) = seen.get(&key) {
+            let err = format!(
+                "unsatisfiable constraints, {} and {} always overlap",
+                other, s.id
+            );
+            return Err(Error::new_resolve_error(&err));
+        }
+        seen.insert(key, s.id.clone());
+    }
+    Ok(())
+}
+
+fn component_try_from_sites(layout: &impl Layout, site: Vec<Site>) -> Result<Component, Error> {
+    check_always_overlap(&site)?;
+    let mut vars: Vec<Id> = Vec::new();
+    for s in site.iter() {
+        for v in s.vars() {
+            if !vars.contains(&v) {
+                vars.push(v);
+            }
+        }
+    }
+    let mut domains: HashMap<Id, Vec<u64>> = HashMap::new();
+    for v in vars.iter() {
+        domains.insert(v.clone(), domain(layout, &site, v));
+    }
+    Ok(Component {
+        site,
+        vars,
+        domains,
+    })
+}
+
+// a component that can not be placed even on its own
+fn check_component(
+    layout: &impl Layout,
+    occupied: &Occupied,
+    comp: &Component,
+) -> Result<(), Error> {
+    let mut occupied = occupied.clone();
+    let mut assign = Assignment::new();
+    let budget = Cell::new(SEARCH_BUDGET);
+    if search(
+        layout,
+        &mut occupied,
+        comp,
+        &comp.vars,
+        &mut assign,
+        &budget,
+        &mut |_, _| true,
+    ) {
+        Ok(())
+    } else {
+        let err = format!(
+            "unsatisfiable constraints, there are no free sites for [{}] that satisfy variables [{}]",
+            comp.ids().join(", "),
+            comp.vars.join(", ")
+        );
+        Err(Error::new_resolve_error(&err))
+    }
+}
+
+// place components in order, going back to the previous one whenever
+// the rest can not be placed with its current sites
+fn solve(
+    layout: &impl Layout,
+    occupied: &mut Occupied,
+    comp: &[Component],
+    assign: &mut Assignment,
+    budget: &Cell<usize>,
+) -> bool {
+    if let Some((c, rest)) = comp.split_first() {
+        let mut local = Assignment::new();
+        search(
+            layout,
+            occupied,
+            c,
+            &c.vars,
+            &mut local,
+            budget,
+            &mut |occupied, local| {
+                let mut used: Vec<(Prim, u64, u64)> = Vec::new();
+                for s in c.site.iter() {
+                    if let Some((x, y)) = s.eval(local) {
+                        let key = (s.prim.clone(), x, y);
+                        occupied.insert(key.clone(), s.id.clone());
+                        used.push(key);
+                    }
+                }
+                if solve(layout, occupied, rest, assign, budget) {
+                    assign.extend(local.clone());
+                    true
+                } else {
+                    for key in used {
+                        occupied.remove(&key);
+                    }
+                    false
+                }
+            },
+        )
+    } else {
+        true
+    }
+}
+
+fn coord_from_linear(linear: &Linear, assign: &Assignment) -> ExprCoord {
+    if let Some(val) = linear.eval(assign) {
+        ExprCoord::Val(val)
+    } else {
+        ExprCoord::Any
+    }
+}
+
+/// assign a value to every coordinate variable in prog, so that
+/// every location lands on a free site of layout. Locations with
+/// ?? coordinates are left untouched.
+pub fn resolve(prog: &Prog, layout: &impl Layout) -> Result<Prog, Error> {
+    let mut fixed: Vec<Site> = Vec::new();
+    let mut symbolic: Vec<Site> = Vec::new();
+    for instr in prog.body() {
+        if let Instr::Asm(instr) = instr {
+            if let Some(site) = site_try_from_instr(instr)? {
+                if site.vars().is_empty() {
+                    fixed.push(site);
+                } else {
+                    symbolic.push(site);
+                }
+            }
+        }
+    }
+    let mut occupied = Occupied::new();
+    for s in fixed.iter() {
+        let x = s.x.offset;
+        let y = s.y.offset;
+        if !layout.contains(&s.prim, x, y) {
+            let err = format!(
+                "{} is placed at {}, which is outside the device",
+                s.id,
+                site_name(&s.prim, x, y)
+            );
+            return Err(Error::new_resolve_error(&err));
+        }
+        occupy(&mut occupied, s, x, y)?;
+    }
+    check_capacity(layout, &occupied, &symbolic)?;
+    let mut comp: Vec<Component> = Vec::new();
+    for site in components(&symbolic) {
+        let c = component_try_from_sites(layout, site)?;
+        check_component(layout, &occupied, &c)?;
+        comp.push(c);
+    }
+    // tightest components first, so they do not lose their few sites
+    comp.sort_by_key(|c| c.size());
+    let mut assign = Assignment::new();
+    let budget = Cell::new(SEARCH_BUDGET);
+    if !solve(layout, &mut occupied, &comp, &mut assign, &budget) {
+        let ids: Vec<Id> = comp.iter().flat_map(|c| c.ids()).collect();
+        let err = format!(
+            "unsatisfiable constraints, there are not enough free sites for [{}]",
+            ids.join(", ")
+        );
+        return Err(Error::new_resolve_error(&err));
+    }
+    let mut body: Vec<Instr> = Vec::new();
+    for instr in prog.body() {
+        match instr {
+            Instr::Asm(instr) => {
+                let mut instr = instr.clone();
+                if let Some(site) = site_try_from_instr(&instr)? {
+                    let mut loc = instr.loc().clone();
+                    loc.set_x(coord_from_linear(&site.x, &assign));
+                    loc.set_y(coord_from_linear(&site.y, &assign));
+                    instr.set_loc(loc);
+                }
+                body.push(Instr::from(instr));
+            }
+            _ => body.push(instr.clone()),
+        }
+    }
+    let mut res = prog.clone();
+    res.set_body(body);
+    Ok(res)
+}
@@ -0,0 +1,53 @@
+use device::ast::Device;
+use placer::check::check_xir_prog;
+use placer::errors::Error;
+use xir::parser::Parser as XirParser;
+
+fn device() -> Device {
+    Device::from_file("../../../dev/xczu3eg-sbva484.csv").unwrap()
+}
+
+#[test]
+fn addreduce_placed() -> Result<(), Error> {
+    let p = XirParser::parse_from_file("../../../examples/xir/addreduce_placed.xir").unwrap();
+    check_xir_prog(&p, &device())
+}
+
+#[test]
+fn illegal() {
+    let p = XirParser::parse_from_str(
+        r#"def main(a:bool, b:bool, c:i8<4>, d:i8<4>) -> (y:bool, z:bool, w:bool, v:i8<4>) {
+            y:bool = lut2[6](a, b) @a6lut(0, 0);
+            z:bool = lut2[6](a, b) @a6lut(0, 0);
+            w:bool = lut2[6](a, b) @a6lut(1000, 0);
+            v:i8<4> = vaddrega(c, d, a, a, a) @alu(10, 0);
+        }"#,
+    )
+    .unwrap();
+    let err = check_xir_prog(&p, &device()).unwrap_err().to_string();
+    let lines: Vec<&str> = err.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("y and z"));
+    assert!(lines[1].starts_with("w is placed at"));
+    assert!(lines[2].starts_with("v uses bel alu"));
+}
+
+#[test]
+fn wrong_site_type() {
+    let p = XirParser::parse_from_str(
+        r#"def main(a:bool, b:bool, c:i8<4>, d:i8<4>) -> (y:bool, v:i8<4>) {
+            y:bool = lut2[6](a, b) @a6lut(1, 70);
+            v:i8<4> = vaddrega(c, d, a, a, a) @alu(10, 0);
+        }"#,
+    )
+    .unwrap();
+    let err = check_xir_prog(&p, &device()).unwrap_err().to_string();
+    let lines: Vec<&str> = err.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "y uses bel a6lut, but site DSP48E2_X1Y70 is a dsp site",
+            "v uses bel alu, but site SLICE_X10Y0 is a lut site",
+        ]
+    );
+}
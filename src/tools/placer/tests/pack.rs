@@ -0,0 +1,54 @@
+use device::ast::Device;
+use placer::check::check_xir_prog;
+use placer::errors::Error;
+use placer::layout::Grid;
+use placer::pack::pack;
+use std::path::Path;
+use xir::parser::Parser as XirParser;
+
+fn test(input: &str, output: &str) -> Result<(), Error> {
+    let mut i = Path::new("../../../examples/xir").join(input);
+    let mut o = Path::new("../../../examples/xir").join(output);
+    i.set_extension("xir");
+    o.set_extension("xir");
+    let dev = Device::from_file("../../../dev/xczu3eg-sbva484.csv").unwrap();
+    let p = XirParser::parse_from_file(i).unwrap();
+    let e = XirParser::parse_from_file(o).unwrap();
+    let r = pack(&p, &dev)?;
+    check_xir_prog(&r, &dev)?;
+    assert_eq!(r, e);
+    Ok(())
+}
+
+#[test]
+fn fsm_3() -> Result<(), Error> {
+    test("fsm_3", "fsm_3_packed")
+}
+
+#[test]
+fn addreduce_placed() -> Result<(), Error> {
+    test("addreduce_placed", "addreduce_placed")
+}
+
+#[test]
+fn hand_placed() -> Result<(), Error> {
+    let input = r#"
+def main(a:bool, b:bool, en:bool) -> (y:bool, z:bool, w:i8) {
+    y:bool = lut2[8](a, b) @c6lut(3, 5);
+    z:bool = lut2[6](a, b) @a6lut(??, ??);
+    w:i8 = lram[1, 2, 3, 4](a, b, y, en) @ram64m8(0, 0);
+}"#;
+    let p = XirParser::parse_from_str(input).unwrap();
+    let r = pack(&p, &Grid::new(4, 8))?;
+    let loc: Vec<String> = r
+        .body()
+        .iter()
+        .map(|i| i.to_string())
+        .filter(|i| i.contains("lut"))
+        .collect();
+    // the placed lut keeps its slice and bel, the lut ram site is reserved
+    assert!(loc[0].ends_with("@c6lut(3, 5)"));
+    assert!(!loc[1].ends_with("(0, 0)"));
+    assert!(!loc[1].ends_with("(3, 5)"));
+    Ok(())
+}
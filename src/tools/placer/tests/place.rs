@@ -0,0 +1,31 @@
+use asm::parser::Parser as AsmParser;
+use device::ast::Device;
+use placer::errors::Error;
+use placer::place::place;
+use placer::resolver::resolve;
+use std::path::Path;
+
+fn test(name: &str) -> Result<(), Error> {
+    let mut i = Path::new("../../../examples/asm").join(name);
+    let mut o = Path::new("../../../examples/asm").join(format!("{}_placed", name));
+    i.set_extension("asm");
+    o.set_extension("asm");
+    let dev = Device::from_file("../../../dev/xczu3eg-sbva484.csv").unwrap();
+    let p = AsmParser::parse_from_file(i)?;
+    let e = AsmParser::parse_from_file(o)?;
+    let r = place(&p, &dev)?;
+    // placed programs must be legal as they are
+    assert_eq!(resolve(&r, &dev)?, r);
+    assert_eq!(r, e);
+    Ok(())
+}
+
+#[test]
+fn fsm_3() -> Result<(), Error> {
+    test("fsm_3")
+}
+
+#[test]
+fn tdot_5_3() -> Result<(), Error> {
+    test("tdot_5_3")
+}
@@ -0,0 +1,92 @@
+use asm::parser::Parser as AsmParser;
+use device::ast::Device;
+use placer::errors::Error;
+use placer::layout::Grid;
+use placer::resolver::resolve;
+
+#[test]
+fn tdot_5_3() -> Result<(), Error> {
+    let prog = AsmParser::parse_from_file("../../../examples/asm/tdot_5_3_opt.asm")?;
+    let exp = AsmParser::parse_from_file("../../../examples/asm/tdot_5_3_resolved.asm")?;
+    let res = resolve(&prog, &Grid::new(2, 9))?;
+    assert_eq!(res, exp);
+    Ok(())
+}
+
+#[test]
+fn tdot_5_3_unsat() -> Result<(), Error> {
+    let prog = AsmParser::parse_from_file("../../../examples/asm/tdot_5_3_opt.asm")?;
+    assert!(resolve(&prog, &Grid::new(2, 7)).is_err());
+    Ok(())
+}
+
+#[test]
+fn tdot_5_3_xczu3eg() -> Result<(), Error> {
+    let prog = AsmParser::parse_from_file("../../../examples/asm/tdot_5_3_opt.asm")?;
+    let dev = Device::from_file("../../../dev/xczu3eg-sbva484.csv").unwrap();
+    assert!(resolve(&prog, &dev).is_ok());
+    Ok(())
+}
+
+// placing y first at (0, 0) leaves no room for z and w, so the
+// resolver has to go back and move it
+#[test]
+fn backtrack() -> Result<(), Error> {
+    let prog = AsmParser::parse_from_str(
+        "def main(a:i8, b:i8, c:i8, en:bool) -> (y:i8, z:i8, w:i8) {
+            y:i8 = dmuladdrega_i8i8(a, b, c, en, en, en, en) @dsp(x0, y0);
+            z:i8 = dmuladdrega_i8i8(a, b, c, en, en, en, en) @dsp(x1, y1);
+            w:i8 = dmuladdrega_i8i8(a, b, c, en, en, en, en) @dsp(x1, y1+2);
+        }",
+    )?;
+    let exp = AsmParser::parse_from_str(
+        "def main(a:i8, b:i8, c:i8, en:bool) -> (y:i8, z:i8, w:i8) {
+            y:i8 = dmuladdrega_i8i8(a, b, c, en, en, en, en) @dsp(0, 1);
+            z:i8 = dmuladdrega_i8i8(a, b, c, en, en, en, en) @dsp(0, 0);
+            w:i8 = dmuladdrega_i8i8(a, b, c, en, en, en, en) @dsp(0, 2);
+        }",
+    )?;
+    assert_eq!(resolve(&prog, &Grid::new(1, 3))?, exp);
+    Ok(())
+}
+
+// one more free dsp than the device has fails before searching
+#[test]
+fn capacity() -> Result<(), Error> {
+    let dev = Device::from_file("../../../dev/xczu3eg-sbva484.csv").unwrap();
+    let mut body = String::new();
+    for i in 0..361 {
+        body.push_str(&format!(
+            "y{0}:i8 = dmuladdrega_i8i8(a, b, c, en, en, en, en) @dsp(x{0}, y{0});\n",
+            i
+        ));
+    }
+    let prog = AsmParser::parse_from_str(&format!(
+        "def main(a:i8, b:i8, c:i8, en:bool) -> (y0:i8) {{\n{}}}",
+        body
+    ))?;
+    let err = resolve(&prog, &dev).unwrap_err().to_string();
+    assert!(err.contains("361 dsp sites to place but only 360 are free"));
+    Ok(())
+}
+
+// pairs two rows apart can only tile an even number of pairs per row
+// parity, the search gives up instead of trying every combination
+#[test]
+fn budget() -> Result<(), Error> {
+    let mut body = String::new();
+    for i in 0..15 {
+        body.push_str(&format!(
+            "p{0}:i8 = dmuladdrega_i8i8(a, b, c, en, en, en, en) @dsp(x{0}, y{0});\n\
+             q{0}:i8 = dmuladdrega_i8i8(a, b, c, en, en, en, en) @dsp(x{0}, y{0}+2);\n",
+            i
+        ));
+    }
+    let prog = AsmParser::parse_from_str(&format!(
+        "def main(a:i8, b:i8, c:i8, en:bool) -> (p0:i8) {{\n{}}}",
+        body
+    ))?;
+    let err = resolve(&prog, &Grid::new(1, 30)).unwrap_err().to_string();
+    assert!(err.contains("unsatisfiable constraints"));
+    Ok(())
+}
@@ -1,4 +1,23 @@
+// FromStr of an enum through the Display strings of its variants
+macro_rules! from_str {
+    ($ty:ident, [$($var:ident),*]) => {
+        impl std::str::FromStr for $ty {
+            type Err = crate::PrimError;
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                [$($ty::$var),*]
+                    .iter()
+                    .find(|v| v.to_string() == s)
+                    .cloned()
+                    .ok_or_else(|| crate::PrimError::InvalidParamValue(s.into()))
+            }
+        }
+    };
+}
+
 mod prim;
 pub mod ultrascale;
 
-pub use crate::prim::{Param, ParamSet, Port, PortSet, Prim, PrimError, ToPrim};
+pub use crate::prim::{
+    FromValue, Library, Param, ParamDef, ParamSet, Port, PortDef, PortSet, Prim, PrimDef,
+    PrimError, ToPrim, Validate, Value,
+};
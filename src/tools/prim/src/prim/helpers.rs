@@ -77,6 +77,13 @@ impl<T: Eq + fmt::Debug + fmt::Display> Prim<T> {
     pub fn output(&self) -> &PortSet {
         &self.output
     }
+    pub fn get_param(&self, name: &str) -> Result<&T> {
+        if let Some(param) = self.param.get(name) {
+            Ok(param.value())
+        } else {
+            Err(PrimError::MissingParam(name.into()).into())
+        }
+    }
     pub fn set_param<U>(&mut self, name: &str, value: U) -> Result<()>
     where
         U: Into<T>,
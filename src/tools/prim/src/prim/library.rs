@@ -0,0 +1,332 @@
+use crate::prim::{Param, ParamSet, Port, PortSet, Prim, PrimError};
+use anyhow::Result;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+// A library describes a family of primitives, so new primitives only need
+// a description file. Parameter types are bool, int, bytes, string or the
+// name of an enum of the library, and names with {} are expanded once for
+// every string in each, e.g. INIT_{} with each A and B.
+//
+// Only Prim<Value> is built from the description alone. Typed parameters,
+// e.g. DspParam, convert values with FromValue, so a primitive with a new
+// enum parameter also needs its Rust enum (from_str!) and a FromValue arm
+// before it can be built with that type.
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum Value {
+    Bool(bool),
+    Int(i64),
+    Str(String),
+    Bytes(Vec<u8>),
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct PortDef {
+    pub name: String,
+    pub width: u32,
+    #[serde(default)]
+    pub each: Vec<String>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ParamDef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(default)]
+    pub width: Option<u32>,
+    #[serde(default)]
+    pub default: Option<Value>,
+    #[serde(default)]
+    pub values: Vec<Value>,
+    #[serde(default)]
+    pub range: Option<(i64, i64)>,
+    #[serde(default)]
+    pub each: Vec<String>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct PrimDef {
+    pub name: String,
+    #[serde(default)]
+    pub param: Vec<ParamDef>,
+    #[serde(default)]
+    pub input: Vec<PortDef>,
+    #[serde(default)]
+    pub output: Vec<PortDef>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct Library {
+    pub family: String,
+    #[serde(default)]
+    pub enums: HashMap<String, Vec<String>>,
+    pub prim: Vec<PrimDef>,
+}
+
+// T ~> Param value type of a primitive, built from a library value of type ty
+pub trait FromValue: Sized {
+    fn from_value(ty: &str, value: &Value) -> Result<Self>;
+}
+
+const BOOL: &str = "bool";
+const INT: &str = "int";
+const BYTES: &str = "bytes";
+const STRING: &str = "string";
+
+// names with {} once for every string in each
+fn expand(name: &str, each: &[String]) -> Vec<String> {
+    if each.is_empty() {
+        vec![name.to_string()]
+    } else {
+        each.iter().map(|e| name.replace("{}", e)).collect()
+    }
+}
+
+fn invalid(msg: &str) -> anyhow::Error {
+    PrimError::InvalidParamValue(msg.into()).into()
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Bool(_), Value::Bool(_)) => true,
+            (Value::Int(_), Value::Int(_)) => true,
+            (Value::Str(_), Value::Str(_)) => true,
+            (Value::Bytes(_), Value::Bytes(_)) => true,
+            (_, _) => false,
+        }
+    }
+}
+
+impl Eq for Value {}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Bool(v) => write!(f, "{}", v),
+            Value::Int(v) => write!(f, "{}", v),
+            Value::Str(v) => write!(f, "{}", v),
+            Value::Bytes(v) => write!(f, "{:?}", v),
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Int(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Str(value.to_string())
+    }
+}
+
+impl From<Vec<u8>> for Value {
+    fn from(value: Vec<u8>) -> Self {
+        Value::Bytes(value)
+    }
+}
+
+// primitives without a typed representation keep library values
+impl FromValue for Value {
+    fn from_value(_: &str, value: &Value) -> Result<Self> {
+        Ok(value.clone())
+    }
+}
+
+impl Value {
+    fn is_same(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Bool(a), Value::Bool(b)) => a == b,
+            (Value::Int(a), Value::Int(b)) => a == b,
+            (Value::Str(a), Value::Str(b)) => a == b,
+            (Value::Bytes(a), Value::Bytes(b)) => a == b,
+            (_, _) => false,
+        }
+    }
+}
+
+impl PortDef {
+    pub fn to_ports(&self) -> Vec<Port> {
+        expand(&self.name, &self.each)
+            .iter()
+            .map(|n| Port::new(n, self.width))
+            .collect()
+    }
+}
+
+impl ParamDef {
+    pub fn names(&self) -> Vec<String> {
+        expand(&self.name, &self.each)
+    }
+    // enum values of the parameter, if it is an enum
+    fn variants<'a>(&self, lib: &'a Library) -> Option<&'a Vec<String>> {
+        match self.ty.as_str() {
+            BOOL | INT | BYTES | STRING => None,
+            ty => lib.enums.get(ty),
+        }
+    }
+    /// default value, or the zero value of the type if there is none
+    pub fn default_value(&self, lib: &Library) -> Result<Value> {
+        if let Some(value) = &self.default {
+            return Ok(value.clone());
+        }
+        match self.ty.as_str() {
+            BOOL => Ok(Value::Bool(false)),
+            INT => Ok(Value::Int(0)),
+            BYTES => Ok(Value::Bytes(vec![0; self.width.unwrap_or(0) as usize / 8])),
+            STRING => Ok(Value::Str(String::new())),
+            _ => match self.variants(lib).and_then(|v| v.first()) {
+                Some(value) => Ok(Value::Str(value.to_string())),
+                None => Err(invalid(&format!("{} does not have a default", self.name))),
+            },
+        }
+    }
+    /// checks value against the type, width, values and range of the parameter
+    pub fn check(&self, lib: &Library, value: &Value) -> Result<()> {
+        let legal = match (self.ty.as_str(), value) {
+            (BOOL, Value::Bool(_)) => true,
+            (INT, Value::Int(_)) => true,
+            (BYTES, Value::Bytes(_)) => true,
+            (STRING, Value::Str(_)) => true,
+            (ty, Value::Str(v)) => match self.variants(lib) {
+                Some(variants) => variants.contains(v),
+                None => {
+                    let err = format!("{} is not a type of {}", ty, lib.family);
+                    return Err(invalid(&err));
+                }
+            },
+            (_, _) => false,
+        };
+        if !legal {
+            let err = format!("{} can not be {}", self.name, value);
+            return Err(invalid(&err));
+        }
+        if !self.values.is_empty() && !self.values.iter().any(|v| v.is_same(value)) {
+            let err = format!("{} can not be {}", self.name, value);
+            return Err(invalid(&err));
+        }
+        if let (Some((min, max)), Value::Int(v)) = (self.range, value) {
+            if v < &min || v > &max {
+                let err = format!("{} must be between {} and {}", self.name, min, max);
+                return Err(invalid(&err));
+            }
+        }
+        let fits = match (value, self.width) {
+            (Value::Int(v), Some(w)) => *v >= 0 && (w >= 64 || *v >> w == 0),
+            (Value::Bytes(v), Some(w)) => v.len() * 8 == w as usize,
+            _ => true,
+        };
+        if !fits {
+            let err = format!("{} must be {} bits wide", self.name, self.width.unwrap());
+            return Err(invalid(&err));
+        }
+        Ok(())
+    }
+}
+
+impl PrimDef {
+    pub fn name(&self) -> String {
+        self.name.to_string()
+    }
+    /// parameter definition of the parameter called name
+    pub fn get_param(&self, name: &str) -> Result<&ParamDef> {
+        match self
+            .param
+            .iter()
+            .find(|p| p.names().iter().any(|n| n == name))
+        {
+            Some(param) => Ok(param),
+            None => Err(PrimError::MissingParam(name.into()).into()),
+        }
+    }
+    pub fn to_prim<T>(&self, lib: &Library) -> Result<Prim<T>>
+    where
+        T: FromValue + Eq + fmt::Debug + fmt::Display,
+    {
+        let mut param = ParamSet::new();
+        for def in self.param.iter() {
+            let value = def.default_value(lib)?;
+            def.check(lib, &value)?;
+            for name in def.names() {
+                param.insert(Param {
+                    name,
+                    width: def.width,
+                    value: T::from_value(&def.ty, &value)?,
+                });
+            }
+        }
+        let mut input = PortSet::new();
+        for def in self.input.iter() {
+            input.extend(def.to_ports());
+        }
+        let mut output = PortSet::new();
+        for def in self.output.iter() {
+            output.extend(def.to_ports());
+        }
+        Ok(Prim {
+            name: self.name(),
+            param,
+            input,
+            output,
+        })
+    }
+}
+
+impl Library {
+    pub fn family(&self) -> String {
+        self.family.to_string()
+    }
+    pub fn get(&self, name: &str) -> Result<&PrimDef> {
+        match self.prim.iter().find(|p| p.name == name) {
+            Some(prim) => Ok(prim),
+            None => Err(PrimError::MissingPrim(name.into()).into()),
+        }
+    }
+    /// checks every parameter of prim against its definition
+    pub fn check<T>(&self, prim: &Prim<T>) -> Result<()>
+    where
+        T: Clone + Into<Value> + Eq + fmt::Debug + fmt::Display,
+    {
+        let def = self.get(&prim.name)?;
+        for p in prim.param().iter() {
+            def.get_param(&p.name)?
+                .check(self, &p.value().clone().into())?;
+        }
+        Ok(())
+    }
+    /// primitive called name with default parameters
+    pub fn to_prim<T>(&self, name: &str) -> Result<Prim<T>>
+    where
+        T: FromValue + Eq + fmt::Debug + fmt::Display,
+    {
+        self.get(name)?.to_prim(self)
+    }
+    pub fn parse_from_str(input: &str) -> Result<Library> {
+        let lib: Library = serde_json::from_str(input)?;
+        // defaults must be legal, so every primitive can be built
+        for prim in lib.prim.iter() {
+            for def in prim.param.iter() {
+                def.check(&lib, &def.default_value(&lib)?)?;
+            }
+        }
+        Ok(lib)
+    }
+    pub fn parse_from_file<P: AsRef<Path>>(path: P) -> Result<Library> {
+        Library::parse_from_str(&fs::read_to_string(path)?)
+    }
+}
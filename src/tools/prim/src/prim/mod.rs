@@ -3,10 +3,14 @@ pub mod eq;
 pub mod from;
 pub mod hash;
 pub mod helpers;
+mod library;
 pub mod partial_eq;
 mod to_prim;
+mod validate;
 
+pub use crate::prim::library::{FromValue, Library, ParamDef, PortDef, PrimDef, Value};
 pub use crate::prim::to_prim::ToPrim;
+pub use crate::prim::validate::Validate;
 
 use derive_more::{Deref, DerefMut};
 use std::collections::HashSet;
@@ -46,4 +50,8 @@ pub enum PrimError {
     InvalidParamValue(String),
     #[error("Missing parameter: {0}")]
     MissingParam(String),
+    #[error("Missing primitive: {0}")]
+    MissingPrim(String),
+    #[error("Incompatible parameters: {0}")]
+    IncompatibleParams(String),
 }
@@ -0,0 +1,9 @@
+use anyhow::Result;
+
+// Checks run on a primitive once its parameters are set, so illegal
+// configurations are caught before they reach the vendor tools.
+pub trait Validate {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
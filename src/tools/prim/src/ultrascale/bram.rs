@@ -1,4 +1,6 @@
-use crate::{Param, ParamSet, Port, PortSet, Prim, ToPrim};
+use crate::ultrascale::{library, to_prim};
+use crate::{FromValue, Prim, PrimError, Validate, Value};
+use anyhow::Result;
 use derive_more::{Deref, DerefMut, Display, From};
 use std::fmt;
 
@@ -14,6 +16,8 @@ pub enum CascadeOrder {
     None,
 }
 
+from_str!(CascadeOrder, [First, Middle, Last, None]);
+
 #[derive(Clone, Debug, PartialEq, Eq, Display)]
 pub enum ClockDomains {
     #[display(fmt = "COMMON")]
@@ -22,6 +26,8 @@ pub enum ClockDomains {
     Independent,
 }
 
+from_str!(ClockDomains, [Common, Independent]);
+
 #[derive(Clone, Debug, PartialEq, Eq, Display)]
 pub enum CollisionCheck {
     #[display(fmt = "ALL")]
@@ -34,6 +40,8 @@ pub enum CollisionCheck {
     Warning,
 }
 
+from_str!(CollisionCheck, [All, GenX, None, Warning]);
+
 #[derive(Clone, Debug, PartialEq, Eq, Display)]
 pub enum FilePath {
     #[display(fmt = "{}", _0)]
@@ -50,6 +58,8 @@ pub enum RstRegPriority {
     RegCe,
 }
 
+from_str!(RstRegPriority, [RstReg, RegCe]);
+
 #[derive(Clone, Debug, PartialEq, Eq, Display)]
 pub enum WriteMode {
     #[display(fmt = "WRITE_FIRST")]
@@ -60,6 +70,8 @@ pub enum WriteMode {
     ReadFirst,
 }
 
+from_str!(WriteMode, [WriteFirst, NoChange, ReadFirst]);
+
 #[derive(Clone, Debug, From, Eq)]
 pub enum BramParam {
     CascadeOrder(CascadeOrder),
@@ -76,9 +88,6 @@ pub enum BramParam {
 #[derive(Clone, Debug, Deref, DerefMut)]
 pub struct Bram(Prim<BramParam>);
 
-#[derive(Clone, Debug, Default)]
-struct BramPrim;
-
 impl PartialEq for BramParam {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -99,252 +108,88 @@ impl PartialEq for BramParam {
 impl fmt::Display for BramParam {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            BramParam::CascadeOrder(v) => write!(f, "{}", v),
+            BramParam::ClockDomains(v) => write!(f, "{}", v),
+            BramParam::CollisionCheck(v) => write!(f, "{}", v),
+            BramParam::Bool(v) => write!(f, "{}", v),
             BramParam::Bytes(v) => write!(f, "{:?}", v),
-            _ => write!(f, "{}", self),
+            BramParam::I64(v) => write!(f, "{}", v),
+            BramParam::FilePath(v) => write!(f, "{}", v),
+            BramParam::RstRegPriority(v) => write!(f, "{}", v),
+            BramParam::WriteMode(v) => write!(f, "{}", v),
         }
     }
 }
 
-impl ToPrim<BramParam> for BramPrim {
-    fn to_name(&self) -> String {
-        String::from("RAMB18E2")
-    }
-    fn to_param(&self) -> ParamSet<BramParam> {
-        let mut param = ParamSet::new();
-        param.insert(Param {
-            name: "CASCADE_ORDER_A".into(),
-            width: None,
-            value: CascadeOrder::None.into(),
-        });
-        param.insert(Param {
-            name: "CASCADE_ORDER_B".into(),
-            width: None,
-            value: CascadeOrder::None.into(),
-        });
-        param.insert(Param {
-            name: "CLOCK_DOMAINS".into(),
-            width: None,
-            value: ClockDomains::Independent.into(),
-        });
-        param.insert(Param {
-            name: "SIM_COLLISION_CHECK".into(),
-            width: None,
-            value: CollisionCheck::All.into(),
-        });
-        param.insert(Param {
-            name: "DOA_REG".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "DOB_REG".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "ENADDRENA".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "ENADDRENB".into(),
-            width: None,
-            value: false.into(),
-        });
-        for i in 0..8 {
-            let name = format!("INITP_{:02X}", i);
-            param.insert(Param {
-                name,
-                width: Some(256),
-                value: vec![0; 32].into(),
-            });
-        }
-        for i in 0..64 {
-            let name = format!("INIT_{:02X}", i);
-            param.insert(Param {
-                name,
-                width: Some(256),
-                value: vec![0; 32].into(),
-            });
+impl FromValue for BramParam {
+    fn from_value(ty: &str, value: &Value) -> Result<Self> {
+        match (ty, value) {
+            ("CascadeOrder", Value::Str(v)) => Ok(v.parse::<CascadeOrder>()?.into()),
+            ("ClockDomains", Value::Str(v)) => Ok(v.parse::<ClockDomains>()?.into()),
+            ("CollisionCheck", Value::Str(v)) => Ok(v.parse::<CollisionCheck>()?.into()),
+            ("RstRegPriority", Value::Str(v)) => Ok(v.parse::<RstRegPriority>()?.into()),
+            ("WriteMode", Value::Str(v)) => Ok(v.parse::<WriteMode>()?.into()),
+            ("string", Value::Str(v)) if v == "NONE" => Ok(FilePath::None.into()),
+            ("string", Value::Str(v)) => Ok(FilePath::Some(v.to_string()).into()),
+            ("bool", Value::Bool(v)) => Ok((*v).into()),
+            ("bytes", Value::Bytes(v)) => Ok(v.clone().into()),
+            ("int", Value::Int(v)) => Ok((*v).into()),
+            (_, _) => Err(PrimError::InvalidParamValue(value.to_string()).into()),
         }
-        param.insert(Param {
-            name: "INIT_A".into(),
-            width: Some(18),
-            value: 0i64.into(),
-        });
-        param.insert(Param {
-            name: "INIT_B".into(),
-            width: Some(18),
-            value: 0i64.into(),
-        });
-        param.insert(Param {
-            name: "INIT_FILE".into(),
-            width: None,
-            value: FilePath::None.into(),
-        });
-        param.insert(Param {
-            name: "IS_CLKARDCLK_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_CLKBWRCLK_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_ENARDEN_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_ENBWREN_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_RSTRAMARSTRAM_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_RSTRAMB_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_RSTREGARSTREG_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_RSTREGB_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "RDADDRCHANGEA".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "RDADDRCHANGEB".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "READ_WIDTH_A".into(),
-            width: None,
-            value: 0i64.into(),
-        });
-        param.insert(Param {
-            name: "READ_WIDTH_B".into(),
-            width: None,
-            value: 0i64.into(),
-        });
-        param.insert(Param {
-            name: "WRITE_WIDTH_A".into(),
-            width: None,
-            value: 0i64.into(),
-        });
-        param.insert(Param {
-            name: "WRITE_WIDTH_B".into(),
-            width: None,
-            value: 0i64.into(),
-        });
-        param.insert(Param {
-            name: "RSTREG_PRIORITY_A".into(),
-            width: None,
-            value: RstRegPriority::RstReg.into(),
-        });
-        param.insert(Param {
-            name: "RSTREG_PRIORITY_B".into(),
-            width: None,
-            value: RstRegPriority::RstReg.into(),
-        });
-        param.insert(Param {
-            name: "SRVAL_A".into(),
-            width: Some(18),
-            value: 0i64.into(),
-        });
-        param.insert(Param {
-            name: "SRVAL_B".into(),
-            width: Some(18),
-            value: 0i64.into(),
-        });
-        param.insert(Param {
-            name: "SLEEP_ASYNC".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "WRITE_MODE_A".into(),
-            width: None,
-            value: WriteMode::NoChange.into(),
-        });
-        param.insert(Param {
-            name: "WRITE_MODE_B".into(),
-            width: None,
-            value: WriteMode::NoChange.into(),
-        });
-        param
-    }
-    fn to_input(&self) -> PortSet {
-        let mut port = PortSet::new();
-        port.insert(Port::new("CASDIMUXA", 1));
-        port.insert(Port::new("CASDIMUXB", 1));
-        port.insert(Port::new("CASDINA", 16));
-        port.insert(Port::new("CASDINB", 16));
-        port.insert(Port::new("CASDINPA", 2));
-        port.insert(Port::new("CASDINPB", 2));
-        port.insert(Port::new("CASDOMUXA", 1));
-        port.insert(Port::new("CASDOMUXB", 1));
-        port.insert(Port::new("CASDOMUXEN_A", 1));
-        port.insert(Port::new("CASDOMUXEN_B", 1));
-        port.insert(Port::new("CASOREGIMUXA", 1));
-        port.insert(Port::new("CASOREGIMUXB", 1));
-        port.insert(Port::new("CASOREGIMUXEN_A", 1));
-        port.insert(Port::new("CASOREGIMUXEN_B", 1));
-        port.insert(Port::new("ADDRARDADDR", 14));
-        port.insert(Port::new("ADDRENA", 1));
-        port.insert(Port::new("CLKARDCLK", 1));
-        port.insert(Port::new("ENARDEN", 1));
-        port.insert(Port::new("REGCEAREGCE", 1));
-        port.insert(Port::new("RSTRAMARSTRAM", 1));
-        port.insert(Port::new("RSTREGARSTREG", 1));
-        port.insert(Port::new("WEA", 2));
-        port.insert(Port::new("DINADIN", 16));
-        port.insert(Port::new("DINPADINP", 2));
-        port.insert(Port::new("ADDRBWRADDR", 14));
-        port.insert(Port::new("ADDRENB", 1));
-        port.insert(Port::new("CLKBWRCLK", 1));
-        port.insert(Port::new("ENBWREN", 1));
-        port.insert(Port::new("REGCEB", 1));
-        port.insert(Port::new("RSTRAMB", 1));
-        port.insert(Port::new("RSTREGB", 1));
-        port.insert(Port::new("SLEEP", 1));
-        port.insert(Port::new("WEBWE", 4));
-        port.insert(Port::new("DINBDIN", 16));
-        port.insert(Port::new("DINPBDINP", 2));
-        port
     }
-    fn to_output(&self) -> PortSet {
-        let mut port = PortSet::new();
-        port.insert(Port::new("CASDOUTA", 16));
-        port.insert(Port::new("CASDOUTB", 16));
-        port.insert(Port::new("CASDOUTPA", 2));
-        port.insert(Port::new("CASDOUTPB", 2));
-        port.insert(Port::new("DOUTADOUT", 16));
-        port.insert(Port::new("DOUTPADOUTP", 2));
-        port.insert(Port::new("DOUTBDOUT", 16));
-        port.insert(Port::new("DOUTPBDOUTP", 2));
-        port
+}
+
+impl From<BramParam> for Value {
+    fn from(param: BramParam) -> Self {
+        match param {
+            BramParam::Bool(v) => Value::Bool(v),
+            BramParam::Bytes(v) => Value::Bytes(v),
+            BramParam::I64(v) => Value::Int(v),
+            v => Value::Str(v.to_string()),
+        }
     }
 }
 
 impl Default for Bram {
     fn default() -> Self {
-        let ram = BramPrim;
-        Bram(ram.to_prim())
+        Bram(to_prim("RAMB18E2"))
+    }
+}
+
+// port width of simple dual port mode
+const SDP_WIDTH: i64 = 36;
+
+impl Bram {
+    fn port_width(&self, name: &str) -> Result<i64> {
+        match self.get_param(name)? {
+            BramParam::I64(width) => Ok(*width),
+            value => Err(PrimError::InvalidParamValue(value.to_string()).into()),
+        }
+    }
+}
+
+impl Validate for Bram {
+    // constraints of the RAMB18E2 attributes in UG573
+    fn validate(&self) -> Result<()> {
+        // widths, including 36 only for port A reads and port B writes
+        library().check(self)?;
+        let read_a = self.port_width("READ_WIDTH_A")?;
+        let write_b = self.port_width("WRITE_WIDTH_B")?;
+        // simple dual port mode uses port A to read and port B to write
+        if read_a == SDP_WIDTH || write_b == SDP_WIDTH {
+            for name in ["READ_WIDTH_B", "WRITE_WIDTH_A"].iter() {
+                if self.port_width(name)? != 0 {
+                    let err = format!("{} must be 0 in simple dual port mode", name);
+                    return Err(PrimError::IncompatibleParams(err).into());
+                }
+            }
+            for name in ["WRITE_MODE_A", "WRITE_MODE_B"].iter() {
+                if let BramParam::WriteMode(WriteMode::NoChange) = self.get_param(name)? {
+                    let err = format!("{} can not be NO_CHANGE in simple dual port mode", name);
+                    return Err(PrimError::IncompatibleParams(err).into());
+                }
+            }
+        }
+        Ok(())
     }
 }
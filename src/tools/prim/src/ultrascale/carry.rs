@@ -1,4 +1,6 @@
-use crate::{Param, ParamSet, Port, PortSet, Prim, ToPrim};
+use crate::ultrascale::to_prim;
+use crate::{FromValue, Prim, PrimError, Validate, Value};
+use anyhow::Result;
 use derive_more::{Deref, DerefMut, Display, From};
 
 #[derive(Clone, Debug, PartialEq, Eq, Display)]
@@ -9,6 +11,8 @@ pub enum CarryType {
     Single,
 }
 
+from_str!(CarryType, [Dual, Single]);
+
 #[derive(Clone, Debug, From, Eq, Display)]
 pub enum CarryParam {
     CarryType(CarryType),
@@ -17,48 +21,32 @@ pub enum CarryParam {
 #[derive(Clone, Debug, Deref, DerefMut)]
 pub struct Carry(Prim<CarryParam>);
 
-#[derive(Clone, Debug, Default)]
-struct CarryPrim;
-
 impl PartialEq for CarryParam {
     fn eq(&self, _: &Self) -> bool {
         true
     }
 }
 
-impl ToPrim<CarryParam> for CarryPrim {
-    fn to_name(&self) -> String {
-        String::from("CARRY8")
-    }
-    fn to_param(&self) -> ParamSet<CarryParam> {
-        let mut param = ParamSet::new();
-        let ty = Param {
-            name: "CARRY_TYPE".to_string(),
-            width: None,
-            value: CarryType::Single.into(),
-        };
-        param.insert(ty);
-        param
-    }
-    fn to_input(&self) -> PortSet {
-        let mut port = PortSet::new();
-        port.insert(Port::new("DI", 8));
-        port.insert(Port::new("S", 8));
-        port.insert(Port::new("CI", 1));
-        port.insert(Port::new("CI_TOP", 1));
-        port
+impl FromValue for CarryParam {
+    fn from_value(ty: &str, value: &Value) -> Result<Self> {
+        match (ty, value) {
+            ("CarryType", Value::Str(v)) => Ok(v.parse::<CarryType>()?.into()),
+            (_, _) => Err(PrimError::InvalidParamValue(value.to_string()).into()),
+        }
     }
-    fn to_output(&self) -> PortSet {
-        let mut port = PortSet::new();
-        port.insert(Port::new("O", 8));
-        port.insert(Port::new("CO", 8));
-        port
+}
+
+impl From<CarryParam> for Value {
+    fn from(param: CarryParam) -> Self {
+        Value::Str(param.to_string())
     }
 }
 
 impl Default for Carry {
     fn default() -> Carry {
-        let carry = CarryPrim;
-        Carry(carry.to_prim())
+        Carry(to_prim("CARRY8"))
     }
 }
+
+// both carry types are legal on their own
+impl Validate for Carry {}
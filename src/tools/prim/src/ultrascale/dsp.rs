@@ -1,22 +1,402 @@
-use crate::{ParamSet, PortSet, ToPrim};
+use crate::ultrascale::{library, to_prim};
+use crate::{FromValue, Prim, PrimError, Validate, Value};
+use anyhow::Result;
+use derive_more::{Deref, DerefMut, Display, From};
 
-#[derive(Clone, Debug, Default, PartialEq)]
-pub struct DspParam;
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum InputTy {
+    #[display(fmt = "DIRECT")]
+    Direct,
+    #[display(fmt = "CASCADE")]
+    Cascade,
+}
+
+from_str!(InputTy, [Direct, Cascade]);
+
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum AMultSel {
+    #[display(fmt = "A")]
+    A,
+    #[display(fmt = "AD")]
+    AD,
+}
+
+from_str!(AMultSel, [A, AD]);
+
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum BMultSel {
+    #[display(fmt = "B")]
+    B,
+    #[display(fmt = "AD")]
+    AD,
+}
+
+from_str!(BMultSel, [B, AD]);
+
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum PreAddInSel {
+    #[display(fmt = "A")]
+    A,
+    #[display(fmt = "B")]
+    B,
+}
+
+from_str!(PreAddInSel, [A, B]);
+
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum UseMult {
+    #[display(fmt = "MULTIPLY")]
+    Multiply,
+    #[display(fmt = "DYNAMIC")]
+    Dynamic,
+    #[display(fmt = "NONE")]
+    None,
+}
+
+from_str!(UseMult, [Multiply, Dynamic, None]);
+
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum UseSimd {
+    #[display(fmt = "ONE48")]
+    One,
+    #[display(fmt = "TWO24")]
+    Two,
+    #[display(fmt = "FOUR12")]
+    Four,
+}
+
+from_str!(UseSimd, [One, Two, Four]);
+
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum XorSimd {
+    #[display(fmt = "XOR12")]
+    One,
+    #[display(fmt = "XOR24_48_96")]
+    Two,
+}
+
+from_str!(XorSimd, [One, Two]);
+
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum AutoResetPatDet {
+    #[display(fmt = "NO_RESET")]
+    NoReset,
+    #[display(fmt = "RESET_MATCH")]
+    ResetMatch,
+    #[display(fmt = "RESET_NOT_MATCH")]
+    ResetNotMatch,
+}
+
+from_str!(AutoResetPatDet, [NoReset, ResetMatch, ResetNotMatch]);
+
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum AutoResetPriority {
+    #[display(fmt = "RESET")]
+    Reset,
+    #[display(fmt = "CEP")]
+    Cep,
+}
+
+from_str!(AutoResetPriority, [Reset, Cep]);
+
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum SelMask {
+    #[display(fmt = "C")]
+    C,
+    #[display(fmt = "MASK")]
+    Mask,
+    #[display(fmt = "ROUNDING_MODE1")]
+    RoundModeOne,
+    #[display(fmt = "ROUNDING_MODE2")]
+    RoundModeTwo,
+}
+
+from_str!(SelMask, [C, Mask, RoundModeOne, RoundModeTwo]);
+
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum SelPattern {
+    #[display(fmt = "C")]
+    C,
+    #[display(fmt = "PATTERN")]
+    Pattern,
+}
+
+from_str!(SelPattern, [C, Pattern]);
+
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum UsePatternDetect {
+    #[display(fmt = "NO_PATDET")]
+    NoPatDet,
+    #[display(fmt = "PATDET")]
+    PatDet,
+}
+
+from_str!(UsePatternDetect, [NoPatDet, PatDet]);
+
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum NumReg {
+    #[display(fmt = "0")]
+    Zero,
+    #[display(fmt = "1")]
+    One,
+}
+
+from_str!(NumReg, [Zero, One]);
+
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum NumRegAB {
+    #[display(fmt = "0")]
+    Zero,
+    #[display(fmt = "1")]
+    One,
+    #[display(fmt = "2")]
+    Two,
+}
+
+from_str!(NumRegAB, [Zero, One, Two]);
+
+#[derive(Clone, Debug, From, Eq, Display)]
+pub enum DspParam {
+    InputTy(InputTy),
+    AMultSel(AMultSel),
+    BMultSel(BMultSel),
+    PreAddInSel(PreAddInSel),
+    UseMult(UseMult),
+    UseSimd(UseSimd),
+    XorSimd(XorSimd),
+    AutoResetPatDet(AutoResetPatDet),
+    AutoResetPriority(AutoResetPriority),
+    SelMask(SelMask),
+    SelPattern(SelPattern),
+    UsePatternDetect(UsePatternDetect),
+    NumReg(NumReg),
+    NumRegAB(NumRegAB),
+    Bool(bool),
+    I64(i64),
+}
+
+#[derive(Clone, Debug, Deref, DerefMut)]
+pub struct Dsp(Prim<DspParam>);
+
+impl Default for InputTy {
+    fn default() -> Self {
+        InputTy::Direct
+    }
+}
+
+impl Default for AMultSel {
+    fn default() -> Self {
+        AMultSel::A
+    }
+}
+
+impl Default for BMultSel {
+    fn default() -> Self {
+        BMultSel::B
+    }
+}
+
+impl Default for PreAddInSel {
+    fn default() -> Self {
+        PreAddInSel::A
+    }
+}
+
+impl Default for UseMult {
+    fn default() -> Self {
+        UseMult::None
+    }
+}
+
+impl Default for UseSimd {
+    fn default() -> Self {
+        UseSimd::One
+    }
+}
+
+impl Default for XorSimd {
+    fn default() -> Self {
+        XorSimd::Two
+    }
+}
+
+impl Default for AutoResetPatDet {
+    fn default() -> Self {
+        AutoResetPatDet::NoReset
+    }
+}
+
+impl Default for AutoResetPriority {
+    fn default() -> Self {
+        AutoResetPriority::Reset
+    }
+}
+
+impl Default for SelMask {
+    fn default() -> Self {
+        SelMask::Mask
+    }
+}
+
+impl Default for SelPattern {
+    fn default() -> Self {
+        SelPattern::Pattern
+    }
+}
+
+impl Default for UsePatternDetect {
+    fn default() -> Self {
+        UsePatternDetect::NoPatDet
+    }
+}
+
+impl Default for NumReg {
+    fn default() -> Self {
+        NumReg::Zero
+    }
+}
+
+impl Default for NumRegAB {
+    fn default() -> Self {
+        NumRegAB::Zero
+    }
+}
 
-#[derive(Clone, Debug, Default)]
-pub struct Dsp;
+impl PartialEq for DspParam {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (DspParam::InputTy(_), DspParam::InputTy(_)) => true,
+            (DspParam::AMultSel(_), DspParam::AMultSel(_)) => true,
+            (DspParam::BMultSel(_), DspParam::BMultSel(_)) => true,
+            (DspParam::PreAddInSel(_), DspParam::PreAddInSel(_)) => true,
+            (DspParam::UseMult(_), DspParam::UseMult(_)) => true,
+            (DspParam::UseSimd(_), DspParam::UseSimd(_)) => true,
+            (DspParam::XorSimd(_), DspParam::XorSimd(_)) => true,
+            (DspParam::AutoResetPatDet(_), DspParam::AutoResetPatDet(_)) => true,
+            (DspParam::AutoResetPriority(_), DspParam::AutoResetPriority(_)) => true,
+            (DspParam::SelMask(_), DspParam::SelMask(_)) => true,
+            (DspParam::SelPattern(_), DspParam::SelPattern(_)) => true,
+            (DspParam::UsePatternDetect(_), DspParam::UsePatternDetect(_)) => true,
+            (DspParam::NumReg(_), DspParam::NumReg(_)) => true,
+            (DspParam::NumRegAB(_), DspParam::NumRegAB(_)) => true,
+            (DspParam::Bool(_), DspParam::Bool(_)) => true,
+            (DspParam::I64(_), DspParam::I64(_)) => true,
+            (_, _) => false,
+        }
+    }
+}
 
-impl ToPrim<DspParam> for Dsp {
-    fn to_name(&self) -> String {
-        String::from("DSP48E2")
+impl FromValue for DspParam {
+    fn from_value(ty: &str, value: &Value) -> Result<Self> {
+        match (ty, value) {
+            ("InputTy", Value::Str(v)) => Ok(v.parse::<InputTy>()?.into()),
+            ("AMultSel", Value::Str(v)) => Ok(v.parse::<AMultSel>()?.into()),
+            ("BMultSel", Value::Str(v)) => Ok(v.parse::<BMultSel>()?.into()),
+            ("PreAddInSel", Value::Str(v)) => Ok(v.parse::<PreAddInSel>()?.into()),
+            ("UseMult", Value::Str(v)) => Ok(v.parse::<UseMult>()?.into()),
+            ("UseSimd", Value::Str(v)) => Ok(v.parse::<UseSimd>()?.into()),
+            ("XorSimd", Value::Str(v)) => Ok(v.parse::<XorSimd>()?.into()),
+            ("AutoResetPatDet", Value::Str(v)) => Ok(v.parse::<AutoResetPatDet>()?.into()),
+            ("AutoResetPriority", Value::Str(v)) => Ok(v.parse::<AutoResetPriority>()?.into()),
+            ("SelMask", Value::Str(v)) => Ok(v.parse::<SelMask>()?.into()),
+            ("SelPattern", Value::Str(v)) => Ok(v.parse::<SelPattern>()?.into()),
+            ("UsePatternDetect", Value::Str(v)) => Ok(v.parse::<UsePatternDetect>()?.into()),
+            ("NumReg", Value::Str(v)) => Ok(v.parse::<NumReg>()?.into()),
+            ("NumRegAB", Value::Str(v)) => Ok(v.parse::<NumRegAB>()?.into()),
+            ("bool", Value::Bool(v)) => Ok((*v).into()),
+            ("int", Value::Int(v)) => Ok((*v).into()),
+            (_, _) => Err(PrimError::InvalidParamValue(value.to_string()).into()),
+        }
     }
-    fn to_param(&self) -> ParamSet<DspParam> {
-        ParamSet::new()
+}
+
+impl From<DspParam> for Value {
+    fn from(param: DspParam) -> Self {
+        match param {
+            DspParam::Bool(v) => Value::Bool(v),
+            DspParam::I64(v) => Value::Int(v),
+            v => Value::Str(v.to_string()),
+        }
     }
-    fn to_input(&self) -> PortSet {
-        PortSet::new()
+}
+
+impl Default for Dsp {
+    fn default() -> Self {
+        Dsp(to_prim("DSP48E2"))
     }
-    fn to_output(&self) -> PortSet {
-        PortSet::new()
+}
+
+fn incompatible(msg: &str) -> anyhow::Error {
+    PrimError::IncompatibleParams(msg.into()).into()
+}
+
+impl Validate for Dsp {
+    // constraints of the DSP48E2 attributes in UG579
+    fn validate(&self) -> Result<()> {
+        library().check(self)?;
+        let mult = !matches!(
+            self.get_param("USE_MULT")?,
+            DspParam::UseMult(UseMult::None)
+        );
+        // the multiplier needs the whole alu
+        if mult && !matches!(self.get_param("USE_SIMD")?, DspParam::UseSimd(UseSimd::One)) {
+            return Err(incompatible("USE_SIMD must be ONE48 when USE_MULT is set"));
+        }
+        if !mult && !matches!(self.get_param("MREG")?, DspParam::NumReg(NumReg::Zero)) {
+            return Err(incompatible("MREG must be 0 when USE_MULT is NONE"));
+        }
+        let amultsel = matches!(
+            self.get_param("AMULTSEL")?,
+            DspParam::AMultSel(AMultSel::AD)
+        );
+        let bmultsel = matches!(
+            self.get_param("BMULTSEL")?,
+            DspParam::BMultSel(BMultSel::AD)
+        );
+        if !mult && (amultsel || bmultsel) {
+            return Err(incompatible(
+                "AMULTSEL and BMULTSEL can not be AD without USE_MULT",
+            ));
+        }
+        // the cascade register is one of the input registers, so it is
+        // there if they are and may be the first of two
+        for (reg, cascade) in [("AREG", "ACASCREG"), ("BREG", "BCASCREG")].iter() {
+            let r = self.get_param(reg)?;
+            let c = self.get_param(cascade)?;
+            let legal = matches!(
+                (r, c),
+                (
+                    DspParam::NumRegAB(NumRegAB::Zero),
+                    DspParam::NumRegAB(NumRegAB::Zero)
+                ) | (
+                    DspParam::NumRegAB(NumRegAB::One),
+                    DspParam::NumRegAB(NumRegAB::One)
+                ) | (
+                    DspParam::NumRegAB(NumRegAB::Two),
+                    DspParam::NumRegAB(NumRegAB::One)
+                ) | (
+                    DspParam::NumRegAB(NumRegAB::Two),
+                    DspParam::NumRegAB(NumRegAB::Two)
+                )
+            );
+            if !legal {
+                let err = format!("{} = {} does not allow {} = {}", reg, r, cascade, c);
+                return Err(incompatible(&err));
+            }
+        }
+        let patdet = matches!(
+            self.get_param("USE_PATTERN_DETECT")?,
+            DspParam::UsePatternDetect(UsePatternDetect::PatDet)
+        );
+        let autoreset = !matches!(
+            self.get_param("AUTORESET_PATDET")?,
+            DspParam::AutoResetPatDet(AutoResetPatDet::NoReset)
+        );
+        if autoreset && !patdet {
+            return Err(incompatible(
+                "AUTORESET_PATDET needs USE_PATTERN_DETECT = PATDET",
+            ));
+        }
+        Ok(())
     }
 }
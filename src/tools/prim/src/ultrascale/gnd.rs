@@ -1,4 +1,6 @@
-use crate::{Port, PortSet, Prim, ToPrim};
+use crate::ultrascale::to_prim;
+use crate::{FromValue, Prim, PrimError, Value};
+use anyhow::Result;
 use derive_more::{Deref, DerefMut, Display};
 
 pub const GND: &str = "gnd";
@@ -9,22 +11,15 @@ pub struct GndParam;
 #[derive(Clone, Debug, Deref, DerefMut)]
 pub struct Gnd(Prim<GndParam>);
 
-#[derive(Clone, Debug, Default)]
-struct GndPrim;
-
-impl ToPrim<GndParam> for GndPrim {
-    fn to_name(&self) -> String {
-        String::from("GND")
-    }
-    fn to_output(&self) -> PortSet {
-        let mut port = PortSet::new();
-        port.insert(Port::new("G", 1));
-        port
+// GND does not have parameters
+impl FromValue for GndParam {
+    fn from_value(_: &str, value: &Value) -> Result<Self> {
+        Err(PrimError::InvalidParamValue(value.to_string()).into())
     }
 }
 
 impl Default for Gnd {
     fn default() -> Gnd {
-        Gnd(GndPrim::default().to_prim())
+        Gnd(to_prim("GND"))
     }
 }
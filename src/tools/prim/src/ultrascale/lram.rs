@@ -1,4 +1,6 @@
-use crate::{Param, ParamSet, Port, PortSet, Prim, ToPrim};
+use crate::ultrascale::{library, to_prim};
+use crate::{FromValue, Prim, PrimError, Validate, Value};
+use anyhow::Result;
 use derive_more::{Deref, DerefMut, From};
 use std::fmt;
 
@@ -11,9 +13,6 @@ pub enum LramParam {
 #[derive(Clone, Debug, Deref, DerefMut)]
 pub struct Lram(Prim<LramParam>);
 
-#[derive(Clone, Debug, Default)]
-struct LramPrim;
-
 impl PartialEq for LramParam {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -27,60 +26,39 @@ impl PartialEq for LramParam {
 impl fmt::Display for LramParam {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            LramParam::Bool(v) => write!(f, "{}", v),
             LramParam::Bytes(v) => write!(f, "{:?}", v),
-            _ => write!(f, "{}", self),
         }
     }
 }
 
-const LUT: [&str; 8] = ["A", "B", "C", "D", "E", "F", "G", "H"];
-
-impl ToPrim<LramParam> for LramPrim {
-    fn to_name(&self) -> String {
-        String::from("RAM64M8")
-    }
-    fn to_param(&self) -> ParamSet<LramParam> {
-        let mut param = ParamSet::new();
-        for l in LUT.iter() {
-            let name = format!("INIT_{}", l);
-            param.insert(Param {
-                name,
-                width: Some(64),
-                value: vec![0; 8].into(),
-            });
-        }
-        param.insert(Param {
-            name: "IS_WCLK_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param
-    }
-    fn to_input(&self) -> PortSet {
-        let mut port = PortSet::new();
-        for l in LUT.iter() {
-            let din = format!("DI{}", l);
-            let addr = format!("ADDR{}", l);
-            port.insert(Port::new(&din, 1));
-            port.insert(Port::new(&addr, 6));
+impl FromValue for LramParam {
+    fn from_value(ty: &str, value: &Value) -> Result<Self> {
+        match (ty, value) {
+            ("bool", Value::Bool(v)) => Ok((*v).into()),
+            ("bytes", Value::Bytes(v)) => Ok(v.clone().into()),
+            (_, _) => Err(PrimError::InvalidParamValue(value.to_string()).into()),
         }
-        port.insert(Port::new("WE", 1));
-        port.insert(Port::new("WCLK", 1));
-        port
     }
-    fn to_output(&self) -> PortSet {
-        let mut port = PortSet::new();
-        for l in LUT.iter() {
-            let dout = format!("DO{}", l);
-            port.insert(Port::new(&dout, 1));
+}
+
+impl From<LramParam> for Value {
+    fn from(param: LramParam) -> Self {
+        match param {
+            LramParam::Bool(v) => Value::Bool(v),
+            LramParam::Bytes(v) => Value::Bytes(v),
         }
-        port
     }
 }
 
 impl Default for Lram {
     fn default() -> Self {
-        let ram = LramPrim;
-        Lram(ram.to_prim())
+        Lram(to_prim("RAM64M8"))
+    }
+}
+
+impl Validate for Lram {
+    fn validate(&self) -> Result<()> {
+        library().check(self)
     }
 }
@@ -4,3 +4,27 @@ pub mod dsp;
 pub mod gnd;
 pub mod lram;
 pub mod uram;
+
+use crate::{FromValue, Library, Prim};
+use std::fmt;
+
+const LIBRARY: &str = include_str!("ultrascale.json");
+
+/// descriptions of the UltraScale primitives, parsed once
+pub fn library() -> &'static Library {
+    lazy_static::lazy_static! {
+        static ref LIB: Library =
+            Library::parse_from_str(LIBRARY).expect("Error: invalid ultrascale library");
+    }
+    &LIB
+}
+
+// primitive of the library with default parameters
+fn to_prim<T>(name: &str) -> Prim<T>
+where
+    T: FromValue + Eq + fmt::Debug + fmt::Display,
+{
+    library()
+        .to_prim(name)
+        .expect("Error: invalid ultrascale primitive")
+}
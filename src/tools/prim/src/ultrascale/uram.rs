@@ -1,4 +1,6 @@
-use crate::{Param, ParamSet, Port, PortSet, Prim, ToPrim};
+use crate::ultrascale::{library, to_prim};
+use crate::{FromValue, Prim, PrimError, Validate, Value};
+use anyhow::Result;
 use derive_more::{Deref, DerefMut, Display, From};
 
 #[derive(Clone, Debug, PartialEq, Eq, Display)]
@@ -13,6 +15,8 @@ pub enum CascadeOrder {
     None,
 }
 
+from_str!(CascadeOrder, [First, Middle, Last, None]);
+
 #[derive(Clone, Debug, PartialEq, Eq, Display)]
 pub enum BwMode {
     #[display(fmt = "PARITY_INTERLEAVED")]
@@ -21,6 +25,8 @@ pub enum BwMode {
     Independent,
 }
 
+from_str!(BwMode, [Interleaved, Independent]);
+
 #[derive(Clone, Debug, PartialEq, Eq, Display)]
 pub enum RstMode {
     #[display(fmt = "SYNC")]
@@ -29,6 +35,8 @@ pub enum RstMode {
     Async,
 }
 
+from_str!(RstMode, [Sync, Async]);
+
 #[derive(Clone, Debug, From, Eq, Display)]
 pub enum UramParam {
     CascadeOrder(CascadeOrder),
@@ -41,9 +49,6 @@ pub enum UramParam {
 #[derive(Clone, Debug, Deref, DerefMut)]
 pub struct Uram(Prim<UramParam>);
 
-#[derive(Clone, Debug, Default)]
-struct UramPrim;
-
 impl PartialEq for UramParam {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -57,267 +62,50 @@ impl PartialEq for UramParam {
     }
 }
 
-impl ToPrim<UramParam> for UramPrim {
-    fn to_name(&self) -> String {
-        String::from("URAM288")
-    }
-    fn to_param(&self) -> ParamSet<UramParam> {
-        let mut param = ParamSet::new();
-        // TODO: range for this param is 3-15
-        // but there is no special types for this
-        param.insert(Param {
-            name: "AUTO_SLEEP_LATENCY".into(),
-            width: None,
-            value: 8i64.into(),
-        });
-        // TODO: range for this param is 10-10000
-        // but there is no special types for this
-        param.insert(Param {
-            name: "AVG_CONS_INACTIVE_CYCLES".into(),
-            width: None,
-            value: 10i64.into(),
-        });
-        param.insert(Param {
-            name: "BWE_MODE_A".into(),
-            width: None,
-            value: BwMode::Interleaved.into(),
-        });
-        param.insert(Param {
-            name: "BWE_MODE_B".into(),
-            width: None,
-            value: BwMode::Interleaved.into(),
-        });
-        param.insert(Param {
-            name: "CASCADE_ORDER_A".into(),
-            width: None,
-            value: CascadeOrder::None.into(),
-        });
-        param.insert(Param {
-            name: "CASCADE_ORDER_B".into(),
-            width: None,
-            value: CascadeOrder::None.into(),
-        });
-        param.insert(Param {
-            name: "EN_AUTO_SLEEP_MODE".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "EN_ECC_RD_A".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "EN_ECC_RD_B".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "EN_ECC_WR_A".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "EN_ECC_WR_B".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IREG_PRE_A".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IREG_PRE_B".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_CLK_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_EN_A_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_EN_B_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_RDB_WR_A_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_RDB_WR_B_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_RST_A_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "IS_RST_B_INVERTED".into(),
-            width: Some(1),
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "OREG_A".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "OREG_B".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "OREG_ECC_A".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "OREG_ECC_B".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "REG_CAS_A".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "REG_CAS_B".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "RST_MODE_A".into(),
-            width: None,
-            value: RstMode::Sync.into(),
-        });
-        param.insert(Param {
-            name: "RST_MODE_B".into(),
-            width: None,
-            value: RstMode::Sync.into(),
-        });
-        param.insert(Param {
-            name: "SELF_ADDR_A".into(),
-            width: Some(11),
-            value: 0i64.into(),
-        });
-        param.insert(Param {
-            name: "SELF_ADDR_B".into(),
-            width: Some(11),
-            value: 0i64.into(),
-        });
-        param.insert(Param {
-            name: "SELF_MASK_A".into(),
-            width: Some(11),
-            value: 2047i64.into(),
-        });
-        param.insert(Param {
-            name: "SELF_MASK_B".into(),
-            width: Some(11),
-            value: 2047i64.into(),
-        });
-        param.insert(Param {
-            name: "USE_EXT_CE_A".into(),
-            width: None,
-            value: false.into(),
-        });
-        param.insert(Param {
-            name: "USE_EXT_CE_B".into(),
-            width: None,
-            value: false.into(),
-        });
-        param
-    }
-    fn to_input(&self) -> PortSet {
-        let mut port = PortSet::new();
-        port.insert(Port::new("ADDR_A", 23));
-        port.insert(Port::new("ADDR_B", 23));
-        port.insert(Port::new("BWE_A", 9));
-        port.insert(Port::new("BWE_B", 9));
-        port.insert(Port::new("CAS_IN_ADDR_A", 23));
-        port.insert(Port::new("CAS_IN_ADDR_B", 23));
-        port.insert(Port::new("CAS_IN_BWE_A", 9));
-        port.insert(Port::new("CAS_IN_BWE_B", 9));
-        port.insert(Port::new("CAS_IN_DBITERR_A", 1));
-        port.insert(Port::new("CAS_IN_DBITERR_B", 1));
-        port.insert(Port::new("CAS_IN_DIN_A", 72));
-        port.insert(Port::new("CAS_IN_DIN_B", 72));
-        port.insert(Port::new("CAS_IN_DOUT_A", 72));
-        port.insert(Port::new("CAS_IN_DOUT_B", 72));
-        port.insert(Port::new("CAS_IN_EN_A", 1));
-        port.insert(Port::new("CAS_IN_EN_B", 1));
-        port.insert(Port::new("CAS_IN_RDACCESS_A", 1));
-        port.insert(Port::new("CAS_IN_RDACCESS_B", 1));
-        port.insert(Port::new("CAS_IN_RDB_WR_A", 1));
-        port.insert(Port::new("CAS_IN_RDB_WR_B", 1));
-        port.insert(Port::new("CAS_IN_SBITERR_A", 1));
-        port.insert(Port::new("CAS_IN_SBITERR_B", 1));
-        port.insert(Port::new("CLK", 1));
-        port.insert(Port::new("DIN_A", 72));
-        port.insert(Port::new("DIN_B", 72));
-        port.insert(Port::new("EN_A", 1));
-        port.insert(Port::new("EN_B", 1));
-        port.insert(Port::new("INJECT_DBITERR_A", 1));
-        port.insert(Port::new("INJECT_DBITERR_B", 1));
-        port.insert(Port::new("INJECT_SBITERR_A", 1));
-        port.insert(Port::new("INJECT_SBITERR_B", 1));
-        port.insert(Port::new("OREG_CE_A", 1));
-        port.insert(Port::new("OREG_CE_B", 1));
-        port.insert(Port::new("OREG_ECC_CE_A", 1));
-        port.insert(Port::new("OREG_ECC_CE_B", 1));
-        port.insert(Port::new("RDB_WR_A", 1));
-        port.insert(Port::new("RDB_WR_B", 1));
-        port.insert(Port::new("RST_A", 1));
-        port.insert(Port::new("RST_B", 1));
-        port.insert(Port::new("SLEEP", 1));
-        port
+impl FromValue for UramParam {
+    fn from_value(ty: &str, value: &Value) -> Result<Self> {
+        match (ty, value) {
+            ("CascadeOrder", Value::Str(v)) => Ok(v.parse::<CascadeOrder>()?.into()),
+            ("BwMode", Value::Str(v)) => Ok(v.parse::<BwMode>()?.into()),
+            ("RstMode", Value::Str(v)) => Ok(v.parse::<RstMode>()?.into()),
+            ("bool", Value::Bool(v)) => Ok((*v).into()),
+            ("int", Value::Int(v)) => Ok((*v).into()),
+            (_, _) => Err(PrimError::InvalidParamValue(value.to_string()).into()),
+        }
     }
-    fn to_output(&self) -> PortSet {
-        let mut port = PortSet::new();
-        port.insert(Port::new("CAS_OUT_ADDR_A", 23));
-        port.insert(Port::new("CAS_OUT_ADDR_B", 23));
-        port.insert(Port::new("CAS_OUT_BWE_A", 9));
-        port.insert(Port::new("CAS_OUT_BWE_B", 9));
-        port.insert(Port::new("CAS_OUT_DBITERR_A", 1));
-        port.insert(Port::new("CAS_OUT_DBITERR_B", 1));
-        port.insert(Port::new("CAS_OUT_DIN_A", 72));
-        port.insert(Port::new("CAS_OUT_DIN_B", 72));
-        port.insert(Port::new("CAS_OUT_DOUT_A", 72));
-        port.insert(Port::new("CAS_OUT_DOUT_B", 72));
-        port.insert(Port::new("CAS_OUT_EN_A", 1));
-        port.insert(Port::new("CAS_OUT_EN_B", 1));
-        port.insert(Port::new("CAS_OUT_RDACCESS_A", 1));
-        port.insert(Port::new("CAS_OUT_RDACCESS_B", 1));
-        port.insert(Port::new("CAS_OUT_RDB_WR_A", 1));
-        port.insert(Port::new("CAS_OUT_RDB_WR_B", 1));
-        port.insert(Port::new("CAS_OUT_SBITERR_A", 1));
-        port.insert(Port::new("CAS_OUT_SBITERR_B", 1));
-        port.insert(Port::new("DBITERR_A", 1));
-        port.insert(Port::new("DBITERR_B", 1));
-        port.insert(Port::new("DOUT_A", 72));
-        port.insert(Port::new("DOUT_B", 72));
-        port.insert(Port::new("RDACCESS_A", 1));
-        port.insert(Port::new("RDACCESS_B", 1));
-        port.insert(Port::new("SBITERR_A", 1));
-        port.insert(Port::new("SBITERR_B", 1));
-        port
+}
+
+impl From<UramParam> for Value {
+    fn from(param: UramParam) -> Self {
+        match param {
+            UramParam::Bool(v) => Value::Bool(v),
+            UramParam::I64(v) => Value::Int(v),
+            v => Value::Str(v.to_string()),
+        }
     }
 }
 
 impl Default for Uram {
     fn default() -> Self {
-        let ram = UramPrim;
-        Uram(ram.to_prim())
+        Uram(to_prim("URAM288"))
+    }
+}
+
+impl Validate for Uram {
+    // constraints of the URAM288 attributes in UG573
+    fn validate(&self) -> Result<()> {
+        library().check(self)?;
+        // the cascade register only exists in a cascade
+        for port in ["A", "B"].iter() {
+            let reg = self.get_param(&format!("REG_CAS_{}", port))?;
+            let order = self.get_param(&format!("CASCADE_ORDER_{}", port))?;
+            if let (UramParam::Bool(true), UramParam::CascadeOrder(CascadeOrder::None)) =
+                (reg, order)
+            {
+                let err = format!("REG_CAS_{} needs a CASCADE_ORDER_{}", port, port);
+                return Err(PrimError::IncompatibleParams(err).into());
+            }
+        }
+        Ok(())
     }
 }
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use prim::{Param, ParamSet, PortSet, Prim};
+use prim::{Param, ParamSet, PortSet, Prim, Validate};
 use std::fmt;
 
 fn test_name<T: Eq + fmt::Debug + fmt::Display>(prim: &Prim<T>, exp: &str) {
@@ -69,6 +69,41 @@ mod test_carry {
     }
 }
 
+mod test_dsp {
+    use super::*;
+    use prim::ultrascale::dsp::*;
+
+    #[test]
+    fn name() {
+        let prim = Dsp::default();
+        test_name(&prim, "DSP48E2");
+    }
+
+    #[test]
+    fn validate() -> Result<()> {
+        let prim = Dsp::default();
+        prim.validate()
+    }
+
+    #[test]
+    fn validate_simd() -> Result<()> {
+        let mut prim = Dsp::default();
+        prim.set_param("USE_MULT", UseMult::Multiply)?;
+        prim.set_param("USE_SIMD", UseSimd::Two)?;
+        assert!(prim.validate().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn validate_cascade_reg() -> Result<()> {
+        let mut prim = Dsp::default();
+        prim.set_param("AREG", NumRegAB::One)?;
+        prim.set_param("ACASCREG", NumRegAB::Two)?;
+        assert!(prim.validate().is_err());
+        Ok(())
+    }
+}
+
 mod test_gnd {
     use super::*;
     use prim::ultrascale::gnd::Gnd;
@@ -352,6 +387,15 @@ mod test_bram {
         ];
         test_output(&prim, &output);
     }
+
+    #[test]
+    fn validate() -> Result<()> {
+        let mut prim = Bram::default();
+        prim.validate()?;
+        prim.set_param("READ_WIDTH_B", 36)?;
+        assert!(prim.validate().is_err());
+        Ok(())
+    }
 }
 
 mod test_lram {
@@ -697,4 +741,64 @@ mod test_uram {
         ];
         test_output(&prim, &output);
     }
+
+    #[test]
+    fn validate() -> Result<()> {
+        let mut prim = Uram::default();
+        prim.validate()?;
+        prim.set_param("AUTO_SLEEP_LATENCY", 2)?;
+        assert!(prim.validate().is_err());
+        Ok(())
+    }
+}
+
+mod test_library {
+    use super::*;
+    use prim::ultrascale::library;
+    use prim::{Library, Value};
+
+    const RAM: &str = r#"{
+        "family": "test",
+        "enums": { "Mode": ["SYNC", "ASYNC"] },
+        "prim": [
+            {
+                "name": "RAM",
+                "param": [
+                    {"name": "MODE_{}", "type": "Mode", "each": ["A", "B"]},
+                    {"name": "DEPTH", "type": "int", "default": 64, "range": [1, 64]},
+                    {"name": "INIT", "type": "bytes", "width": 16}
+                ],
+                "input": [{"name": "ADDR", "width": 6}, {"name": "WE", "width": 1}],
+                "output": [{"name": "DO", "width": 8}]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn ultrascale() -> Result<()> {
+        let lib = library();
+        assert_eq!(lib.family(), "ultrascale");
+        for name in ["CARRY8", "DSP48E2", "GND", "RAM64M8", "RAMB18E2", "URAM288"].iter() {
+            let prim: Prim<Value> = lib.to_prim(name)?;
+            lib.check(&prim)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse() -> Result<()> {
+        let lib = Library::parse_from_str(RAM)?;
+        let mut prim: Prim<Value> = lib.to_prim("RAM")?;
+        test_name(&prim, "RAM");
+        test_input(&prim, &[("ADDR", 6), ("WE", 1)]);
+        test_output(&prim, &[("DO", 8)]);
+        assert_eq!(prim.param().len(), 4);
+        lib.check(&prim)?;
+        prim.set_param("DEPTH", 65)?;
+        assert!(lib.check(&prim).is_err());
+        prim.set_param("DEPTH", 32)?;
+        prim.set_param("MODE_B", "FIFO")?;
+        assert!(lib.check(&prim).is_err());
+        Ok(())
+    }
 }
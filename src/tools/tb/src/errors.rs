@@ -0,0 +1,46 @@
+use ir::errors::Error as IrError;
+use std::fmt;
+use std::num::{ParseIntError, TryFromIntError};
+
+#[derive(Debug)]
+pub enum Error {
+    Ir(IrError),
+    Tb(String),
+    TryFromInt(TryFromIntError),
+    ParseInt(ParseIntError),
+}
+
+impl Error {
+    pub fn new_tb_error(msg: &str) -> Self {
+        Error::Tb(msg.to_string())
+    }
+}
+
+impl From<IrError> for Error {
+    fn from(e: IrError) -> Self {
+        Error::Ir(e)
+    }
+}
+
+impl From<TryFromIntError> for Error {
+    fn from(e: TryFromIntError) -> Self {
+        Error::TryFromInt(e)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Error::ParseInt(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Ir(msg) => write!(f, "{}", msg),
+            Error::Tb(msg) => write!(f, "{}", msg),
+            Error::TryFromInt(msg) => write!(f, "{}", msg),
+            Error::ParseInt(msg) => write!(f, "{}", msg),
+        }
+    }
+}
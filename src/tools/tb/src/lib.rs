@@ -0,0 +1,248 @@
+pub mod errors;
+pub mod stimulus;
+pub mod trace;
+
+use crate::errors::Error;
+use crate::stimulus::Stimulus;
+use ir::ast::{Expr, ExprTerm, Id, Prog};
+use ir::clock::Clock;
+use ir::interp::{Env, Interp};
+use std::fmt;
+
+pub use ir::clock::{CLOCK, RESET};
+
+// cycles with reset asserted before the first stimulus
+pub const RESET_CYCLES: u64 = 2;
+
+// half of the clock period
+pub const HALF_PERIOD: u64 = 5;
+
+/// a lane of a port, vector lanes are separate Verilog ports
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Port {
+    pub name: String,
+    pub width: u64,
+}
+
+/// value of a port lane in one cycle
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Value {
+    pub port: Port,
+    pub value: i64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cycle {
+    pub input: Vec<Value>,
+    pub output: Vec<Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Testbench {
+    pub dut: String,
+    pub input: Vec<Port>,
+    pub output: Vec<Port>,
+    pub cycle: Vec<Cycle>,
+    pub clock: Clock,
+}
+
+impl Port {
+    pub fn new(name: &str, width: u64) -> Self {
+        Port {
+            name: name.to_string(),
+            width,
+        }
+    }
+}
+
+impl Value {
+    /// verilog literal with the bits of value
+    pub fn to_literal(&self) -> String {
+        let width = self.port.width;
+        let bits = if width >= 64 {
+            self.value as u64
+        } else {
+            (self.value as u64) & ((1 << width) - 1)
+        };
+        format!("{}'h{:x}", width, bits)
+    }
+}
+
+impl Testbench {
+    pub fn dut(&self) -> String {
+        self.dut.to_string()
+    }
+    pub fn input(&self) -> &Vec<Port> {
+        &self.input
+    }
+    pub fn output(&self) -> &Vec<Port> {
+        &self.output
+    }
+    pub fn cycle(&self) -> &Vec<Cycle> {
+        &self.cycle
+    }
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+}
+
+fn decl(kind: &str, port: &Port) -> String {
+    if port.width > 1 {
+        format!("{} [{}:0] {}", kind, port.width - 1, port.name)
+    } else {
+        format!("{} {}", kind, port.name)
+    }
+}
+
+impl fmt::Display for Testbench {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let clock = self.clock.clock();
+        let reset = self.clock.reset();
+        // reset value while asserted
+        let active = u8::from(!self.clock.is_active_low());
+        writeln!(f, "module testbench;")?;
+        writeln!(f, "    reg {} = 1'b0;", clock)?;
+        if self.clock.has_reset() {
+            writeln!(f, "    reg {} = 1'b{};", reset, active)?;
+        }
+        for p in self.input.iter() {
+            writeln!(f, "    {} = {}'h0;", decl("reg", p), p.width)?;
+        }
+        for p in self.output.iter() {
+            writeln!(f, "    {};", decl("wire", p))?;
+        }
+        writeln!(f, "    integer errors = 0;")?;
+        writeln!(f)?;
+        writeln!(f, "    {} dut (", self.dut)?;
+        let mut conn = vec![clock.to_string()];
+        if self.clock.has_reset() {
+            conn.push(reset.to_string());
+        }
+        conn.extend(self.input.iter().map(|p| p.name.to_string()));
+        conn.extend(self.output.iter().map(|p| p.name.to_string()));
+        for (i, c) in conn.iter().enumerate() {
+            let sep = if i + 1 < conn.len() { "," } else { "" };
+            writeln!(f, "        .{}({}){}", c, c, sep)?;
+        }
+        writeln!(f, "    );")?;
+        writeln!(f)?;
+        writeln!(f, "    always #{} {} = ~{};", HALF_PERIOD, clock, clock)?;
+        writeln!(f)?;
+        writeln!(f, "    initial begin")?;
+        writeln!(f, "        repeat ({}) @(posedge {});", RESET_CYCLES, clock)?;
+        if self.clock.has_reset() {
+            writeln!(f, "        #1 {} = 1'b{};", reset, 1 - active)?;
+        }
+        for (n, c) in self.cycle.iter().enumerate() {
+            writeln!(f, "        // cycle {}", n)?;
+            for v in c.input.iter() {
+                writeln!(f, "        {} = {};", v.port.name, v.to_literal())?;
+            }
+            writeln!(f, "        #1;")?;
+            for v in c.output.iter() {
+                let exp = v.to_literal();
+                writeln!(f, "        if ({} !== {}) begin", v.port.name, exp)?;
+                writeln!(
+                    f,
+                    "            $display(\"cycle {}: {} is %h, expected {}\", {});",
+                    n, v.port.name, exp, v.port.name
+                )?;
+                writeln!(f, "            errors = errors + 1;")?;
+                writeln!(f, "        end")?;
+            }
+            writeln!(f, "        @(posedge {});", clock)?;
+            writeln!(f, "        #1;")?;
+        }
+        writeln!(f, "        if (errors == 0)")?;
+        writeln!(
+            f,
+            "            $display(\"PASS: {} cycles\");",
+            self.cycle.len()
+        )?;
+        writeln!(f, "        else")?;
+        writeln!(
+            f,
+            "            $display(\"FAIL: %0d errors in {} cycles\", errors);",
+            self.cycle.len()
+        )?;
+        writeln!(f, "        $finish;")?;
+        writeln!(f, "    end")?;
+        write!(f, "endmodule")
+    }
+}
+
+// verilog ports of an expr, vector lanes are named id_n
+fn ports_try_from_expr(expr: &Expr) -> Result<Vec<(Id, Vec<Port>)>, Error> {
+    let term: Vec<ExprTerm> = expr.clone().into();
+    let mut port = Vec::new();
+    for t in term.iter() {
+        let id = t.get_id()?;
+        let width = if let Some(width) = t.width() {
+            width
+        } else {
+            let err = format!("{} must have a width", id);
+            return Err(Error::new_tb_error(&err));
+        };
+        let lanes = if let Some(length) = t.length() {
+            (0..length)
+                .map(|n| Port::new(&format!("{}_{}", id, n), width))
+                .collect()
+        } else {
+            vec![Port::new(&id, width)]
+        };
+        port.push((id, lanes));
+    }
+    Ok(port)
+}
+
+fn values_from_env(port: &[(Id, Vec<Port>)], env: &Env) -> Vec<Value> {
+    let mut value = Vec::new();
+    for (id, lanes) in port.iter() {
+        if let Some(v) = env.get(id) {
+            for (p, v) in lanes.iter().zip(v.iter()) {
+                value.push(Value {
+                    port: p.clone(),
+                    value: *v,
+                });
+            }
+        }
+    }
+    value
+}
+
+/// testbench for main, where the expected outputs of every cycle come
+/// from interpreting the program with the stimulus
+pub fn try_from_ir_prog(prog: &Prog, stimulus: &Stimulus) -> Result<Testbench, Error> {
+    try_from_ir_prog_with_clock(prog, stimulus, &Clock::default())
+}
+
+/// same as try_from_ir_prog, driving the clock and reset ports of the dut
+pub fn try_from_ir_prog_with_clock(
+    prog: &Prog,
+    stimulus: &Stimulus,
+    clock: &Clock,
+) -> Result<Testbench, Error> {
+    let def = if let Some(def) = prog.get("main") {
+        def
+    } else {
+        return Err(Error::new_tb_error("main not found"));
+    };
+    let input = ports_try_from_expr(def.sig().input())?;
+    let output = ports_try_from_expr(def.sig().output())?;
+    let mut interp = Interp::new(def)?;
+    let mut tb = Testbench {
+        dut: def.id(),
+        input: input.iter().flat_map(|(_, p)| p.clone()).collect(),
+        output: output.iter().flat_map(|(_, p)| p.clone()).collect(),
+        cycle: Vec::new(),
+        clock: clock.clone(),
+    };
+    for env in stimulus.cycle() {
+        let res = interp.step(env)?;
+        tb.cycle.push(Cycle {
+            input: values_from_env(&input, env),
+            output: values_from_env(&output, &res),
+        });
+    }
+    Ok(tb)
+}
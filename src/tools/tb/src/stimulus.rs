@@ -0,0 +1,78 @@
+use crate::errors::Error;
+use ir::ast::{ExprTerm, Id, Sig, Ty};
+use ir::interp::{norm, Env};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::convert::TryFrom;
+
+/// input values for every cycle
+#[derive(Clone, Debug, Default)]
+pub struct Stimulus {
+    pub cycle: Vec<Env>,
+}
+
+// inputs of sig with their type and number of lanes
+fn input_try_from_sig(sig: &Sig) -> Result<Vec<(Id, Ty, usize)>, Error> {
+    let term: Vec<ExprTerm> = sig.input().clone().into();
+    let mut input = Vec::new();
+    for t in term.iter() {
+        let lanes = usize::try_from(t.length().unwrap_or(1))?;
+        input.push((t.get_id()?, t.get_ty()?.clone(), lanes));
+    }
+    Ok(input)
+}
+
+impl Stimulus {
+    pub fn cycle(&self) -> &Vec<Env> {
+        &self.cycle
+    }
+    /// One line per cycle with a value for every input lane, in the
+    /// order of the signature. Lines starting with # are comments
+    pub fn try_from_str(sig: &Sig, input: &str) -> Result<Stimulus, Error> {
+        let port = input_try_from_sig(sig)?;
+        let lanes: usize = port.iter().map(|(_, _, l)| l).sum();
+        let mut stimulus = Stimulus::default();
+        for (n, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let value = line
+                .split_whitespace()
+                .map(|v| v.parse::<i64>())
+                .collect::<Result<Vec<i64>, _>>()?;
+            if value.len() != lanes {
+                let err = format!(
+                    "line {} has {} values, but {} inputs are expected",
+                    n + 1,
+                    value.len(),
+                    lanes
+                );
+                return Err(Error::new_tb_error(&err));
+            }
+            let mut env = Env::new();
+            let mut value = value.into_iter();
+            for (id, ty, l) in port.iter() {
+                let lane = value.by_ref().take(*l).map(|v| norm(ty, v)).collect();
+                env.insert(id.clone(), lane);
+            }
+            stimulus.cycle.push(env);
+        }
+        Ok(stimulus)
+    }
+    /// random values for cycles, the same seed gives the same values
+    pub fn new_random(sig: &Sig, cycles: u64, seed: u64) -> Result<Stimulus, Error> {
+        let port = input_try_from_sig(sig)?;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut stimulus = Stimulus::default();
+        for _ in 0..cycles {
+            let mut env = Env::new();
+            for (id, ty, l) in port.iter() {
+                let lane = (0..*l).map(|_| norm(ty, rng.gen::<i64>())).collect();
+                env.insert(id.clone(), lane);
+            }
+            stimulus.cycle.push(env);
+        }
+        Ok(stimulus)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::errors::Error;
+use crate::stimulus::Stimulus;
+use ir::ast::{ExprTerm, Prog, Sig};
+use ir::interp::{Env, Interp};
+use std::fmt;
+
+/// Output values for every cycle, printed in the stimulus format with
+/// one line per cycle and a value for every output lane, in the order
+/// of the signature
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub sig: Sig,
+    pub cycle: Vec<Env>,
+}
+
+impl Trace {
+    pub fn new(sig: &Sig) -> Self {
+        Trace {
+            sig: sig.clone(),
+            cycle: Vec::new(),
+        }
+    }
+    pub fn sig(&self) -> &Sig {
+        &self.sig
+    }
+    pub fn cycle(&self) -> &Vec<Env> {
+        &self.cycle
+    }
+    pub fn add_cycle(&mut self, output: Env) {
+        self.cycle.push(output);
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let term: Vec<ExprTerm> = self.sig.output().clone().into();
+        let mut line: Vec<String> = Vec::new();
+        for env in self.cycle.iter() {
+            let mut value: Vec<String> = Vec::new();
+            for t in term.iter() {
+                if let Some(v) = t.get_id().ok().and_then(|id| env.get(&id)) {
+                    value.extend(v.iter().map(|v| v.to_string()));
+                }
+            }
+            line.push(value.join(" "));
+        }
+        write!(f, "{}", line.join("\n"))
+    }
+}
+
+/// outputs of main for every cycle of the stimulus
+pub fn try_from_ir_prog(prog: &Prog, stimulus: &Stimulus) -> Result<Trace, Error> {
+    let def = if let Some(def) = prog.get("main") {
+        def
+    } else {
+        return Err(Error::new_tb_error("main not found"));
+    };
+    let mut interp = Interp::new(def)?;
+    let mut trace = Trace::new(def.sig());
+    for env in stimulus.cycle() {
+        trace.add_cycle(interp.step(env)?);
+    }
+    Ok(trace)
+}
@@ -0,0 +1,56 @@
+use io::file::read_to_string;
+use ir::clock::Clock;
+use ir::parser::Parser;
+use std::path::Path;
+use tb::errors::Error;
+use tb::stimulus::Stimulus;
+use tb::{try_from_ir_prog, try_from_ir_prog_with_clock};
+
+fn test(name: &str) -> Result<(), Error> {
+    let mut i = Path::new("../../../examples/ir").join(name);
+    let mut s = Path::new("../../../examples/stim").join(name);
+    let mut o = Path::new("../../../examples/tb").join(name);
+    i.set_extension("ir");
+    s.set_extension("stim");
+    o.set_extension("v");
+    let p = Parser::parse_from_file(i)?;
+    let sig = p.get("main").unwrap().sig();
+    let stimulus = Stimulus::try_from_str(sig, &read_to_string(s))?;
+    let e = read_to_string(o);
+    let r = try_from_ir_prog(&p, &stimulus)?;
+    assert_eq!(r.to_string(), e);
+    Ok(())
+}
+
+#[test]
+fn add() -> Result<(), Error> {
+    test("add")
+}
+
+#[test]
+fn add_rst_n() -> Result<(), Error> {
+    let p = Parser::parse_from_file("../../../examples/ir/add.ir")?;
+    let sig = p.get("main").unwrap().sig();
+    let stimulus = Stimulus::try_from_str(sig, &read_to_string("../../../examples/stim/add.stim"))?;
+    let mut clock = Clock::default();
+    clock.set_clock("clk");
+    clock.set_reset("rst_n");
+    clock.set_active_low(true);
+    let e = read_to_string("../../../examples/tb/add_rst_n.v");
+    let r = try_from_ir_prog_with_clock(&p, &stimulus, &clock)?;
+    assert_eq!(r.to_string(), e);
+    Ok(())
+}
+
+#[test]
+fn random() -> Result<(), Error> {
+    let p = Parser::parse_from_file("../../../examples/ir/tadd.ir")?;
+    let sig = p.get("main").unwrap().sig();
+    let s0 = Stimulus::new_random(sig, 8, 7)?;
+    let s1 = Stimulus::new_random(sig, 8, 7)?;
+    let r0 = try_from_ir_prog(&p, &s0)?;
+    let r1 = try_from_ir_prog(&p, &s1)?;
+    assert_eq!(r0.cycle().len(), 8);
+    assert_eq!(r0, r1);
+    Ok(())
+}
@@ -1,14 +1,23 @@
 use crate::errors::Error;
-use crate::opt::{Lang, Opt};
+use crate::opt::{Command, Lang, Opt};
+use crate::pipeline::Pipeline;
 use asm::parser::Parser as AsmParser;
 use bler::try_from_asm_prog as asm_try_into_xir;
-use bline::try_from_ir_prog as ir_try_into_behav;
-use io::file::write_to_file;
+use compiler::compiler::{Compiler, Options};
+use device::ast::Device;
+use io::file::{read_to_string, write_to_file};
 use ir::parser::Parser as IrParser;
-use isel::try_from_ir_prog as ir_try_into_asm;
-use std::path::PathBuf;
+use optimizer::target::Target;
+use placer::check::check_xir_prog;
+use placer::resolver::resolve;
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
 use structopt::StructOpt;
-use xpand::try_from_xir_prog as xir_try_into_struct;
+use tb::stimulus::Stimulus;
+use xir::parser::Parser as XirParser;
+use yosys::blif::Parser as BlifParser;
+use yosys::json::Parser as YosysParser;
+use yosys::try_from_module as netlist_try_into_ir;
 
 #[derive(Clone, Debug)]
 pub struct Driver {
@@ -38,51 +47,109 @@ impl Driver {
     pub fn opts(&self) -> &Opt {
         &self.opts
     }
-    pub fn run(&self) -> Result<(), Error> {
-        let input = self.opts().input();
-        let output = self.opts().output();
-        match (self.opts().from(), self.opts().to()) {
-            (Lang::Ir, Lang::Asm) => {
-                let ir_prog = IrParser::parse_from_file(input)?;
-                let asm_prog = ir_try_into_asm(&ir_prog)?;
-                write_output(output, &asm_prog.to_string());
-                Ok(())
-            }
-            (Lang::Ir, Lang::Xir) => {
-                let ir_prog = IrParser::parse_from_file(input)?;
-                let asm_prog = ir_try_into_asm(&ir_prog)?;
-                let xir_prog = asm_try_into_xir(&asm_prog)?;
-                write_output(output, &xir_prog.to_string());
-                Ok(())
-            }
-            (Lang::Ir, Lang::Behav) => {
-                let ir_prog = IrParser::parse_from_file(input)?;
-                let behav_prog = ir_try_into_behav(&ir_prog)?;
-                write_output(output, &behav_prog.to_string());
-                Ok(())
-            }
-            (Lang::Ir, Lang::Struct) => {
-                let ir_prog = IrParser::parse_from_file(input)?;
-                let asm_prog = ir_try_into_asm(&ir_prog)?;
-                let xir_prog = asm_try_into_xir(&asm_prog)?;
-                let struct_prog = xir_try_into_struct(&xir_prog)?;
-                write_output(output, &struct_prog.to_string());
-                Ok(())
-            }
-            (Lang::Asm, Lang::Xir) => {
+    pub fn device(&self) -> Result<Option<Device>, Error> {
+        if let Some(path) = self.opts().device() {
+            Ok(Some(Device::from_file(path)?))
+        } else {
+            Ok(None)
+        }
+    }
+    // ir program from an ir file, or from a blif or yosys json netlist
+    pub fn ir_from_input(&self, input: &Path) -> Result<ir::ast::Prog, Error> {
+        match self.opts().from() {
+            Lang::Blif => Ok(netlist_try_into_ir(&BlifParser::parse_from_file(input)?)?),
+            Lang::Yosys => Ok(netlist_try_into_ir(&YosysParser::parse_from_file(input)?)?),
+            _ => Ok(IrParser::parse_from_file(input)?),
+        }
+    }
+    // compiler with the device, passes and backend settings of the flags
+    pub fn compiler(&self) -> Result<Compiler, Error> {
+        let target = if let Some(path) = self.opts().target() {
+            Some(Target::from_file(path)?)
+        } else {
+            None
+        };
+        Ok(Compiler::new(Options {
+            passes: self.opts().optimization().to_vec(),
+            target,
+            device: self.device()?,
+            max_chain: self.opts().max_chain(),
+            clock: self.opts().clock(),
+            no_loc_attr: self.opts().no_loc_attr(),
+        }))
+    }
+    // stimulus from a file, or random inputs if there is none
+    pub fn stimulus(&self, sig: &ir::ast::Sig) -> Result<Stimulus, Error> {
+        if let Some(path) = self.opts().stimulus() {
+            Ok(Stimulus::try_from_str(sig, &read_to_string(path))?)
+        } else {
+            Ok(Stimulus::new_random(
+                sig,
+                self.opts().cycles(),
+                self.opts().seed(),
+            )?)
+        }
+    }
+    pub fn check_place(
+        &self,
+        input: &Path,
+        from: &Lang,
+        device: Option<&Device>,
+    ) -> Result<(), Error> {
+        let device = if let Some(device) = device {
+            device
+        } else {
+            return Err(Error::new_driver_error("check-place requires a --device"));
+        };
+        let prog = match from {
+            Lang::Asm => {
                 let prog = AsmParser::parse_from_file(input)?;
-                let xir_prog = asm_try_into_xir(&prog)?;
-                write_output(output, &xir_prog.to_string());
-                Ok(())
+                let prog = resolve(&prog, device)?;
+                asm_try_into_xir(&prog)?
             }
-            (Lang::Asm, Lang::Struct) => {
-                let prog = AsmParser::parse_from_file(input)?;
-                let xir_prog = asm_try_into_xir(&prog)?;
-                let struct_prog = xir_try_into_struct(&xir_prog)?;
-                write_output(output, &struct_prog.to_string());
-                Ok(())
+            Lang::Xir => XirParser::parse_from_file(input)?,
+            _ => {
+                let err = format!("check-place does not support {} programs", from);
+                return Err(Error::new_driver_error(&err));
             }
-            (_, _) => Err(Error::new_driver_error("Unsupported conversion")),
+        };
+        check_xir_prog(&prog, device)?;
+        Ok(())
+    }
+    // every stage in emit is written to the output directory, named after
+    // the input file, e.g. add.asm and add.struct.v
+    pub fn emit(&self, pipeline: &mut Pipeline, input: &Path) -> Result<(), Error> {
+        let dir = if let Some(dir) = self.opts().output() {
+            dir
+        } else {
+            return Err(Error::new_driver_error(
+                "--emit requires an output directory (-o)",
+            ));
+        };
+        if let Err(e) = create_dir_all(dir) {
+            return Err(Error::new_driver_error(&e.to_string()));
+        }
+        let stem = input.file_stem().and_then(|s| s.to_str()).unwrap_or("main");
+        for lang in self.opts().emit() {
+            let contents = pipeline.emit(lang)?;
+            let path = dir.join(format!("{}.{}", stem, lang.extension()));
+            write_to_file(path, &contents);
+        }
+        Ok(())
+    }
+    pub fn run(&self) -> Result<(), Error> {
+        let compiler = self.compiler()?;
+        if let Some(Command::CheckPlace { input, from }) = self.opts().command() {
+            return self.check_place(input, from, compiler.device());
+        }
+        let input = self.opts().input()?;
+        let mut pipeline = Pipeline::new(self, &compiler, input)?;
+        if self.opts().emit().is_empty() {
+            let contents = pipeline.emit(self.opts().to())?;
+            write_output(self.opts().output(), &contents);
+            Ok(())
+        } else {
+            self.emit(&mut pipeline, input)
         }
     }
 }
@@ -1,11 +1,19 @@
 use asm::errors::Error as AsmError;
 use bler::errors::Error as BlerError;
 use bline::errors::Error as BlineError;
+use compiler::errors::Error as CompilerError;
+use device::errors::Error as DeviceError;
 use ir::errors::Error as IrError;
 use isel::errors::Error as ISelError;
+use optimizer::errors::Error as OptimizerError;
+use placer::errors::Error as PlacerError;
 use std::fmt;
+use tb::errors::Error as TbError;
 use xir::errors::Error as XirError;
 use xpand::errors::Error as XpandError;
+use xsim::errors::Error as XsimError;
+use xtract::errors::Error as XtractError;
+use yosys::errors::Error as YosysError;
 
 #[derive(Debug)]
 pub enum Error {
@@ -17,7 +25,15 @@ pub enum Error {
     ISel(ISelError),
     Bler(BlerError),
     Bline(BlineError),
+    Compiler(CompilerError),
     Xpand(XpandError),
+    Device(DeviceError),
+    Optimizer(OptimizerError),
+    Placer(PlacerError),
+    Tb(TbError),
+    Xsim(XsimError),
+    Xtract(XtractError),
+    Yosys(YosysError),
 }
 
 impl Error {
@@ -29,6 +45,12 @@ impl Error {
     }
 }
 
+impl From<OptimizerError> for Error {
+    fn from(e: OptimizerError) -> Self {
+        Error::Optimizer(e)
+    }
+}
+
 impl From<IrError> for Error {
     fn from(e: IrError) -> Self {
         Error::Ir(e)
@@ -59,6 +81,12 @@ impl From<BlerError> for Error {
     }
 }
 
+impl From<CompilerError> for Error {
+    fn from(e: CompilerError) -> Self {
+        Error::Compiler(e)
+    }
+}
+
 impl From<BlineError> for Error {
     fn from(e: BlineError) -> Self {
         Error::Bline(e)
@@ -71,6 +99,42 @@ impl From<XpandError> for Error {
     }
 }
 
+impl From<DeviceError> for Error {
+    fn from(e: DeviceError) -> Self {
+        Error::Device(e)
+    }
+}
+
+impl From<PlacerError> for Error {
+    fn from(e: PlacerError) -> Self {
+        Error::Placer(e)
+    }
+}
+
+impl From<TbError> for Error {
+    fn from(e: TbError) -> Self {
+        Error::Tb(e)
+    }
+}
+
+impl From<XsimError> for Error {
+    fn from(e: XsimError) -> Self {
+        Error::Xsim(e)
+    }
+}
+
+impl From<XtractError> for Error {
+    fn from(e: XtractError) -> Self {
+        Error::Xtract(e)
+    }
+}
+
+impl From<YosysError> for Error {
+    fn from(e: YosysError) -> Self {
+        Error::Yosys(e)
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -80,9 +144,17 @@ impl fmt::Display for Error {
             Error::ISel(msg) => write!(f, "{}", msg),
             Error::Bler(msg) => write!(f, "{}", msg),
             Error::Bline(msg) => write!(f, "{}", msg),
+            Error::Compiler(msg) => write!(f, "{}", msg),
             Error::Opt(msg) => write!(f, "{}", msg),
             Error::Driver(msg) => write!(f, "{}", msg),
             Error::Xpand(msg) => write!(f, "{}", msg),
+            Error::Device(msg) => write!(f, "{}", msg),
+            Error::Optimizer(msg) => write!(f, "{}", msg),
+            Error::Placer(msg) => write!(f, "{}", msg),
+            Error::Tb(msg) => write!(f, "{}", msg),
+            Error::Xsim(msg) => write!(f, "{}", msg),
+            Error::Xtract(msg) => write!(f, "{}", msg),
+            Error::Yosys(msg) => write!(f, "{}", msg),
         }
     }
 }
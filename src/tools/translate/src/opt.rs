@@ -1,4 +1,6 @@
 use crate::errors::Error;
+use ir::clock::{Clock, ResetTy};
+use optimizer::opt::Optimization;
 use std::fmt;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -13,7 +15,7 @@ use structopt::StructOpt;
 pub struct Opt {
     // Input file
     #[structopt(parse(from_os_str))]
-    pub input: PathBuf,
+    pub input: Option<PathBuf>,
 
     // Output file
     #[structopt(short = "o", long = "output", parse(from_os_str))]
@@ -26,11 +28,84 @@ pub struct Opt {
     // To language
     #[structopt(long = "to", default_value = "asm")]
     pub to: Lang,
+
+    // Stages written to the output directory, e.g. asm,xir,struct
+    #[structopt(long = "emit", require_delimiter = true)]
+    pub emit: Vec<Lang>,
+
+    // Optimizations run on asm before placement, in order
+    #[structopt(long = "opt", require_delimiter = true)]
+    pub optimization: Vec<Optimization>,
+
+    // Target metadata file for cascade
+    #[structopt(long = "target", parse(from_os_str))]
+    pub target: Option<PathBuf>,
+
+    // Maximum number of instructions in a cascade, overrides device
+    #[structopt(long = "max-chain")]
+    pub max_chain: Option<usize>,
+
+    // Leave placement attributes out of structural Verilog
+    #[structopt(long = "no-loc-attr")]
+    pub no_loc_attr: bool,
+
+    // Stimulus file for the testbench or trace, one line of input values per cycle
+    #[structopt(long = "stimulus", parse(from_os_str))]
+    pub stimulus: Option<PathBuf>,
+
+    // Seed for random inputs, used when there is no stimulus file
+    #[structopt(long = "seed", default_value = "0")]
+    pub seed: u64,
+
+    // Number of random input cycles
+    #[structopt(long = "cycles", default_value = "16")]
+    pub cycles: u64,
+
+    // Clock port name
+    #[structopt(long = "clock", default_value = "clock")]
+    pub clock: String,
+
+    // Reset port name
+    #[structopt(long = "reset", default_value = "reset")]
+    pub reset: String,
+
+    // Reset style of registers, sync, async or none
+    #[structopt(long = "reset-style", default_value = "sync")]
+    pub reset_style: ResetTy,
+
+    // Reset is asserted when low
+    #[structopt(long = "reset-active-low")]
+    pub reset_active_low: bool,
+
+    // Device file
+    #[structopt(long = "device", parse(from_os_str), global = true)]
+    pub device: Option<PathBuf>,
+
+    #[structopt(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Clone, Debug, StructOpt)]
+pub enum Command {
+    // Check placed locations against a device
+    CheckPlace {
+        // Input file
+        #[structopt(parse(from_os_str))]
+        input: PathBuf,
+
+        // From language
+        #[structopt(long = "from", default_value = "xir")]
+        from: Lang,
+    },
 }
 
 impl Opt {
-    pub fn input(&self) -> &Path {
-        &self.input
+    pub fn input(&self) -> Result<&Path, Error> {
+        if let Some(input) = &self.input {
+            Ok(input)
+        } else {
+            Err(Error::new_opt_error("Missing input file"))
+        }
     }
     pub fn output(&self) -> Option<&PathBuf> {
         self.output.as_ref()
@@ -41,6 +116,44 @@ impl Opt {
     pub fn to(&self) -> &Lang {
         &self.to
     }
+    pub fn emit(&self) -> &Vec<Lang> {
+        &self.emit
+    }
+    pub fn optimization(&self) -> &Vec<Optimization> {
+        &self.optimization
+    }
+    pub fn target(&self) -> Option<&PathBuf> {
+        self.target.as_ref()
+    }
+    pub fn max_chain(&self) -> Option<usize> {
+        self.max_chain
+    }
+    pub fn no_loc_attr(&self) -> bool {
+        self.no_loc_attr
+    }
+    pub fn stimulus(&self) -> Option<&PathBuf> {
+        self.stimulus.as_ref()
+    }
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+    pub fn cycles(&self) -> u64 {
+        self.cycles
+    }
+    pub fn clock(&self) -> Clock {
+        let mut clock = Clock::default();
+        clock.set_clock(&self.clock);
+        clock.set_reset(&self.reset);
+        clock.set_reset_ty(self.reset_style.clone());
+        clock.set_active_low(self.reset_active_low);
+        clock
+    }
+    pub fn device(&self) -> Option<&PathBuf> {
+        self.device.as_ref()
+    }
+    pub fn command(&self) -> Option<&Command> {
+        self.command.as_ref()
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -50,6 +163,35 @@ pub enum Lang {
     Xir,
     Behav,
     Struct,
+    BehavVhdl,
+    StructVhdl,
+    Xdc,
+    Json,
+    Tb,
+    Trace,
+    Blif,
+    Yosys,
+}
+
+impl Lang {
+    /// file extension of an emitted stage
+    pub fn extension(&self) -> &str {
+        match self {
+            Lang::Ir => "ir",
+            Lang::Asm => "asm",
+            Lang::Xir => "xir",
+            Lang::Behav => "behav.v",
+            Lang::Struct => "struct.v",
+            Lang::BehavVhdl => "behav.vhd",
+            Lang::StructVhdl => "struct.vhd",
+            Lang::Xdc => "xdc",
+            Lang::Json => "json",
+            Lang::Tb => "tb.v",
+            Lang::Trace => "trace",
+            Lang::Blif => "blif",
+            Lang::Yosys => "yosys.json",
+        }
+    }
 }
 
 impl fmt::Display for Lang {
@@ -60,6 +202,14 @@ impl fmt::Display for Lang {
             Lang::Xir => "xir",
             Lang::Behav => "behav",
             Lang::Struct => "struct",
+            Lang::BehavVhdl => "behav-vhdl",
+            Lang::StructVhdl => "struct-vhdl",
+            Lang::Xdc => "xdc",
+            Lang::Json => "json",
+            Lang::Tb => "tb",
+            Lang::Trace => "trace",
+            Lang::Blif => "blif",
+            Lang::Yosys => "yosys",
         };
         write!(f, "{}", backend)
     }
@@ -74,6 +224,14 @@ impl FromStr for Lang {
             "xir" => Ok(Lang::Xir),
             "behav" => Ok(Lang::Behav),
             "struct" => Ok(Lang::Struct),
+            "behav-vhdl" => Ok(Lang::BehavVhdl),
+            "struct-vhdl" => Ok(Lang::StructVhdl),
+            "xdc" => Ok(Lang::Xdc),
+            "json" => Ok(Lang::Json),
+            "tb" => Ok(Lang::Tb),
+            "trace" => Ok(Lang::Trace),
+            "blif" => Ok(Lang::Blif),
+            "yosys" => Ok(Lang::Yosys),
             _ => Err(Error::new_opt_error("Unsupported language")),
         }
     }
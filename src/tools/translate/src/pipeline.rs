@@ -0,0 +1,131 @@
+use crate::driver::Driver;
+use crate::errors::Error;
+use crate::opt::Lang;
+use asm::parser::Parser as AsmParser;
+use bline::try_from_ir_prog_with_clock as ir_try_into_behav;
+use bline::vhdl::try_from_ir_prog_with_clock as ir_try_into_behav_vhdl;
+use compiler::compiler::Compiler;
+use std::path::Path;
+use tb::trace::try_from_ir_prog as ir_try_into_trace;
+use tb::try_from_ir_prog_with_clock as ir_try_into_tb;
+use xir::parser::Parser as XirParser;
+use xpand::json::try_from_xir_prog_with_device as xir_try_into_json;
+use xpand::vhdl::try_from_xir_prog_with_device as xir_try_into_struct_vhdl;
+use xpand::xdc::try_from_xir_prog_with_device as xir_try_into_xdc;
+use xsim::try_from_xir_prog as xir_try_into_trace;
+use xtract::parser::Parser as StructParser;
+use xtract::try_from_module as struct_try_into_xir;
+
+// Programs of every stage, each one is built at most once from the stage
+// before it, so emitting several stages does not repeat any work. Stages
+// before the input language are not available, e.g. ir from xir.
+
+pub struct Pipeline<'a> {
+    driver: &'a Driver,
+    compiler: &'a Compiler,
+    ir: Option<ir::ast::Prog>,
+    asm: Option<asm::ast::Prog>,
+    xir: Option<xir::ast::Prog>,
+}
+
+fn main_sig_try_from_ir_prog(prog: &ir::ast::Prog) -> Result<&ir::ast::Sig, Error> {
+    if let Some(def) = prog.get("main") {
+        Ok(def.sig())
+    } else {
+        Err(Error::new_driver_error("main not found"))
+    }
+}
+
+fn unsupported() -> Error {
+    Error::new_driver_error("Unsupported conversion")
+}
+
+impl<'a> Pipeline<'a> {
+    pub fn new(
+        driver: &'a Driver,
+        compiler: &'a Compiler,
+        input: &Path,
+    ) -> Result<Pipeline<'a>, Error> {
+        let mut pipeline = Pipeline {
+            driver,
+            compiler,
+            ir: None,
+            asm: None,
+            xir: None,
+        };
+        match driver.opts().from() {
+            Lang::Ir | Lang::Blif | Lang::Yosys => {
+                pipeline.ir = Some(driver.ir_from_input(input)?);
+            }
+            Lang::Asm => {
+                let prog = AsmParser::parse_from_file(input)?;
+                pipeline.asm = Some(compiler.place(&compiler.optimize(&prog)?)?);
+            }
+            Lang::Xir => {
+                pipeline.xir = Some(XirParser::parse_from_file(input)?);
+            }
+            Lang::Struct => {
+                let module = StructParser::parse_from_file(input)?;
+                pipeline.xir = Some(struct_try_into_xir(&module)?);
+            }
+            _ => return Err(unsupported()),
+        }
+        Ok(pipeline)
+    }
+    pub fn ir(&self) -> Result<&ir::ast::Prog, Error> {
+        self.ir.as_ref().ok_or_else(unsupported)
+    }
+    // selected, optimized and placed asm
+    pub fn asm(&mut self) -> Result<&asm::ast::Prog, Error> {
+        if self.asm.is_none() {
+            let compiler = self.compiler;
+            let prog = compiler.optimize(&compiler.select(self.ir()?)?)?;
+            self.asm = Some(compiler.place(&prog)?);
+        }
+        self.asm.as_ref().ok_or_else(unsupported)
+    }
+    pub fn xir(&mut self) -> Result<&xir::ast::Prog, Error> {
+        if self.xir.is_none() {
+            let compiler = self.compiler;
+            self.xir = Some(compiler.assemble(self.asm()?)?);
+        }
+        self.xir.as_ref().ok_or_else(unsupported)
+    }
+    /// program of lang, built from the earliest stage that has it
+    pub fn emit(&mut self, lang: &Lang) -> Result<String, Error> {
+        let (driver, compiler) = (self.driver, self.compiler);
+        let clock = compiler.clock().clone();
+        let device = compiler.device();
+        match lang {
+            Lang::Ir => Ok(self.ir()?.to_string()),
+            Lang::Asm => Ok(self.asm()?.to_string()),
+            Lang::Xir => Ok(self.xir()?.to_string()),
+            Lang::Behav => Ok(ir_try_into_behav(self.ir()?, &clock)?.to_string()),
+            Lang::BehavVhdl => Ok(ir_try_into_behav_vhdl(self.ir()?, &clock)?.to_string()),
+            Lang::Struct => Ok(compiler.expand(self.xir()?)?.to_string()),
+            Lang::StructVhdl => {
+                let prog = compiler.legalize(self.xir()?)?;
+                Ok(xir_try_into_struct_vhdl(&prog, &clock, device)?.to_string())
+            }
+            Lang::Xdc => Ok(xir_try_into_xdc(self.xir()?, device)?.to_string()),
+            Lang::Json => Ok(xir_try_into_json(self.xir()?, &clock, device)?.to_string()),
+            Lang::Tb => {
+                let prog = self.ir()?;
+                let stimulus = driver.stimulus(main_sig_try_from_ir_prog(prog)?)?;
+                Ok(ir_try_into_tb(prog, &stimulus, &clock)?.to_string())
+            }
+            // simulate the source program, ir if there is one
+            Lang::Trace => {
+                if let Some(prog) = &self.ir {
+                    let stimulus = driver.stimulus(main_sig_try_from_ir_prog(prog)?)?;
+                    Ok(ir_try_into_trace(prog, &stimulus)?.to_string())
+                } else {
+                    let prog = self.xir()?;
+                    let stimulus = driver.stimulus(prog.sig())?;
+                    Ok(xir_try_into_trace(prog, &stimulus)?.to_string())
+                }
+            }
+            Lang::Blif | Lang::Yosys => Err(unsupported()),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use asm::parser::Parser as AsmParser;
+use io::file::read_to_string;
+use std::env::temp_dir;
+use std::fs::remove_dir_all;
+use structopt::StructOpt;
+use translate::driver::Driver;
+use translate::errors::Error;
+use translate::opt::Opt;
+use translate::pipeline::Pipeline;
+
+fn driver_from_args(args: &[&str]) -> Driver {
+    Driver::new(Opt::from_iter(args))
+}
+
+#[test]
+fn cascade() -> Result<(), Error> {
+    let input = "../../../examples/ir/tdot_5_3.ir";
+    let driver = driver_from_args(&["rt", input, "--opt", "cascade"]);
+    let compiler = driver.compiler()?;
+    let mut pipeline = Pipeline::new(&driver, &compiler, input.as_ref())?;
+    let res = pipeline.asm()?.clone();
+    let exp = AsmParser::parse_from_file("../../../examples/asm/tdot_5_3_opt.asm")?;
+    assert_eq!(res, exp);
+    Ok(())
+}
+
+#[test]
+fn emit() -> Result<(), Error> {
+    let input = "../../../examples/ir/tdot.ir";
+    let dir = temp_dir().join("reticle_emit_tdot");
+    let output = dir.to_str().unwrap();
+    let driver = driver_from_args(&["rt", input, "-o", output, "--emit", "asm,xir,struct"]);
+    driver.run()?;
+    let stages = [
+        ("tdot.asm", "../../../examples/asm/tdot.asm"),
+        ("tdot.xir", "../../../examples/xir/tdot.xir"),
+        ("tdot.struct.v", "../../../examples/struct/tdot.v"),
+    ];
+    for (name, golden) in stages.iter() {
+        assert_eq!(read_to_string(dir.join(name)), read_to_string(golden));
+    }
+    remove_dir_all(dir).unwrap();
+    Ok(())
+}
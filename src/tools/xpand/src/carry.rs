@@ -1,7 +1,8 @@
 use crate::errors::Error;
-use crate::loc::Loc;
+use crate::loc::{site_name_from_mach, Loc};
 use crate::to_verilog::{ExprMap, ToVerilogExpr, ToVerilogInstance};
 use crate::{inst_name_try_from_instr, vec_expr_try_from_expr};
+use device::ast::Device;
 use prim::ultrascale::carry::{Carry, CarryParam, CarryType};
 use prim::{ParamSet, PortSet};
 use verilog::ast as vl;
@@ -39,17 +40,30 @@ impl ToVerilogInstance<CarryParam> for Carry {
     }
 }
 
+// sub is an add with the carry in set, because S holds a xor not b
 #[derive(Clone, Debug)]
 struct CarryAdd {
     pub prim: Carry,
     pub instr: xir::InstrMach,
+    pub site: String,
+    pub sub: bool,
 }
 
 impl CarryAdd {
-    pub fn new(instr: xir::InstrMach) -> Self {
+    pub fn new(instr: xir::InstrMach, device: Option<&Device>) -> Self {
         CarryAdd {
             prim: Carry::default(),
+            site: site_name_from_mach(&instr, device),
             instr,
+            sub: false,
+        }
+    }
+    pub fn new_sub(instr: xir::InstrMach, device: Option<&Device>) -> Self {
+        CarryAdd {
+            prim: Carry::default(),
+            site: site_name_from_mach(&instr, device),
+            instr,
+            sub: true,
         }
     }
 }
@@ -73,12 +87,17 @@ impl ToVerilogInstance<CarryParam> for CarryAdd {
     fn to_loc(&self) -> Option<&Loc> {
         self.instr.loc()
     }
+    fn to_site(&self) -> String {
+        self.site.clone()
+    }
     fn to_input_map(&self) -> ExprMap {
         let mut map = ExprMap::new();
         let arg: Vec<vl::Expr> = vec_expr_try_from_expr(self.instr.arg()).unwrap();
         for p in self.prim.input().iter() {
             let name = p.name();
-            if name.as_str() == "CI" || name.as_str() == "CI_TOP" {
+            if name.as_str() == "CI" && self.sub {
+                map.insert(name, vl::Expr::new_ulit_bin(1, "1"));
+            } else if name.as_str() == "CI" || name.as_str() == "CI_TOP" {
                 map.insert(name, vl::Expr::new_ulit_bin(1, "0"));
             } else if name.as_str() == "DI" {
                 map.insert(name, arg[0].clone());
@@ -103,7 +122,20 @@ impl ToVerilogInstance<CarryParam> for CarryAdd {
     }
 }
 
-pub fn carryadd_from_mach(instr: &xir::InstrMach) -> Result<Vec<vl::Stmt>, Error> {
-    let carry = CarryAdd::new(instr.clone());
+pub fn carryadd_from_mach(
+    instr: &xir::InstrMach,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    let carry = CarryAdd::new(instr.clone(), device);
+    Ok(carry.to_block())
+}
+
+/// carry chain with CI set, it takes a and S as arguments and only
+/// computes a - b if every bit of S is a xnor b
+pub fn carrysub_from_mach(
+    instr: &xir::InstrMach,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    let carry = CarryAdd::new_sub(instr.clone(), device);
     Ok(carry.to_block())
 }
@@ -3,20 +3,26 @@ use crate::vec_expr_try_from_expr;
 use verilog::ast as vl;
 use xir::ast as xir;
 
-// TODO: add vector type support
+// vector lanes are assigned in order, taking the same number of bits each
 pub fn cat_from_basc(instr: &xir::InstrBasc) -> Result<Vec<vl::Stmt>, Error> {
-    let term = instr.dst().get_term(0)?;
-    if term.is_vector() {
-        Err(Error::new_xpand_error("vector ext not supported yet"))
-    } else {
-        let arg: Vec<vl::Expr> = vec_expr_try_from_expr(instr.arg())?;
-        let dst: Vec<vl::Expr> = vec_expr_try_from_expr(instr.dst())?;
+    let arg: Vec<vl::Expr> = vec_expr_try_from_expr(instr.arg())?;
+    let dst: Vec<vl::Expr> = vec_expr_try_from_expr(instr.dst())?;
+    if dst.is_empty() || arg.len() % dst.len() != 0 {
+        let err = format!(
+            "cat with {} arguments can not be split into {} lanes",
+            arg.len(),
+            dst.len()
+        );
+        return Err(Error::new_xpand_error(&err));
+    }
+    let mut stmt: Vec<vl::Stmt> = Vec::new();
+    for (d, lane) in dst.iter().zip(arg.chunks(arg.len() / dst.len())) {
         let mut cat = vl::ExprConcat::default();
-        for a in arg {
+        for a in lane {
             cat.add_expr(a.clone());
         }
-        let assign = vl::Parallel::Assign(dst[0].clone(), vl::Expr::from(cat));
-        let stmt = vl::Stmt::from(assign);
-        Ok(vec![stmt])
+        let assign = vl::Parallel::Assign(d.clone(), vl::Expr::from(cat));
+        stmt.push(vl::Stmt::from(assign));
     }
+    Ok(stmt)
 }
@@ -1,110 +1,29 @@
 use crate::errors::Error;
 use crate::expr::ToExpr;
 use crate::instance::ToInstance;
-use crate::loc::attr_from_loc;
+use crate::loc::{attr_from_loc, site_name_from_device};
 use crate::loc::{Bel, BelDsp, ExprCoord, Loc};
 use crate::param::Param;
 use crate::port::{ConnectionMap, DefaultPort, Port, WidthMap};
+use crate::{clock_expr_from_clock, is_reset_inverted, reset_expr_from_clock, CLOCK, RESET};
 use crate::{
     create_literal, inst_name_try_from_instr, tmp_name_try_from_term, vec_expr_try_from_expr,
     vec_expr_try_from_term,
 };
-use crate::{CLOCK, RESET};
+use device::ast::Device;
+use ir::clock::Clock;
+use prim::ultrascale::dsp::{Dsp as DspPrim, DspParam};
 use prim::ultrascale::gnd::GND;
-use std::collections::HashSet;
+use prim::Validate;
+use std::collections::{HashMap, HashSet};
 use std::convert::TryFrom;
 use verilog::ast as vl;
 use xir::ast as xir;
 
-#[derive(Clone, Debug)]
-pub enum InputTy {
-    Direct,
-    Cascade,
-}
-
-#[derive(Clone, Debug)]
-pub enum AMultSel {
-    A,
-    AD,
-}
-
-#[derive(Clone, Debug)]
-pub enum BMultSel {
-    B,
-    AD,
-}
-
-#[derive(Clone, Debug)]
-pub enum PreAddInSel {
-    A,
-    B,
-}
-
-#[derive(Clone, Debug)]
-pub enum UseMult {
-    Multiply,
-    Dynamic,
-    None,
-}
-
-#[derive(Clone, Debug)]
-pub enum UseSimd {
-    One,
-    Two,
-    Four,
-}
-
-#[derive(Clone, Debug)]
-pub enum XorSimd {
-    One,
-    Two,
-}
-
-#[derive(Clone, Debug)]
-pub enum AutoResetPatDet {
-    NoReset,
-    ResetMatch,
-    ResetNotMatch,
-}
-
-#[derive(Clone, Debug)]
-pub enum AutoResetPriority {
-    Reset,
-    Cep,
-}
-
-#[derive(Clone, Debug)]
-pub enum SelMask {
-    C,
-    Mask,
-    RoundModeOne,
-    RoundModeTwo,
-}
-
-#[derive(Clone, Debug)]
-pub enum SelPattern {
-    C,
-    Pattern,
-}
-
-#[derive(Clone, Debug)]
-pub enum UsePatternDetect {
-    NoPatDet,
-    PatDet,
-}
-
-#[derive(Clone, Debug)]
-pub enum NumReg {
-    Zero,
-    One,
-}
-
-#[derive(Clone, Debug)]
-pub enum NumRegAB {
-    Zero,
-    One,
-    Two,
-}
+pub use prim::ultrascale::dsp::{
+    AMultSel, AutoResetPatDet, AutoResetPriority, BMultSel, InputTy, NumReg, NumRegAB, PreAddInSel,
+    SelMask, SelPattern, UseMult, UsePatternDetect, UseSimd, XorSimd,
+};
 
 #[derive(Clone, Debug)]
 pub enum ParamValue {
@@ -132,6 +51,7 @@ pub struct Dsp {
     pub name: String,
     pub prim: String,
     pub loc: Loc,
+    pub site: String,
     pub param: Param<ParamValue>,
     pub input: Port,
     pub output: Port,
@@ -144,7 +64,8 @@ impl Dsp {
     pub fn get_output_width(&self, output: &str) -> Option<&u32> {
         self.output.get_width(output)
     }
-    pub fn set_loc(&mut self, loc: Loc) {
+    pub fn set_loc(&mut self, loc: Loc, device: Option<&Device>) {
+        self.site = site_name_from_device(&loc, device);
         self.loc = loc;
     }
     pub fn set_param<P>(&mut self, name: &str, value: P) -> Result<(), Error>
@@ -154,89 +75,59 @@ impl Dsp {
         self.param.set_param(name, value.into())?;
         Ok(())
     }
-}
-
-impl Default for InputTy {
-    fn default() -> Self {
-        InputTy::Direct
-    }
-}
-
-impl Default for AMultSel {
-    fn default() -> Self {
-        AMultSel::A
-    }
-}
-
-impl Default for BMultSel {
-    fn default() -> Self {
-        BMultSel::B
-    }
-}
-
-impl Default for PreAddInSel {
-    fn default() -> Self {
-        PreAddInSel::A
-    }
-}
-
-impl Default for UseMult {
-    fn default() -> Self {
-        UseMult::None
-    }
-}
-
-impl Default for UseSimd {
-    fn default() -> Self {
-        UseSimd::One
-    }
-}
-
-impl Default for XorSimd {
-    fn default() -> Self {
-        XorSimd::Two
-    }
-}
-
-impl Default for AutoResetPatDet {
-    fn default() -> Self {
-        AutoResetPatDet::NoReset
-    }
-}
-
-impl Default for AutoResetPriority {
-    fn default() -> Self {
-        AutoResetPriority::Reset
-    }
-}
-
-impl Default for SelMask {
-    fn default() -> Self {
-        SelMask::Mask
-    }
-}
-
-impl Default for SelPattern {
-    fn default() -> Self {
-        SelPattern::Pattern
-    }
-}
-
-impl Default for UsePatternDetect {
-    fn default() -> Self {
-        UsePatternDetect::NoPatDet
-    }
-}
-
-impl Default for NumReg {
-    fn default() -> Self {
-        NumReg::Zero
+    // active-low resets are inverted by the dsp itself
+    pub fn set_reset_from_clock(&mut self, clock: &Clock) -> Result<(), Error> {
+        if is_reset_inverted(clock) {
+            let reset: Vec<String> = self
+                .input
+                .width()
+                .keys()
+                .filter(|p| p.starts_with("RST"))
+                .cloned()
+                .collect();
+            for r in reset.iter() {
+                self.set_param(&format!("IS_{}_INVERTED", r), true)?;
+            }
+        }
+        Ok(())
     }
-}
-
-impl Default for NumRegAB {
-    fn default() -> Self {
-        NumRegAB::Zero
+    /// DSP48E2 of the prim library with the parameters of dsp
+    pub fn to_prim(&self) -> Result<DspPrim, Error> {
+        let mut prim = DspPrim::default();
+        for (k, v) in self.param.param() {
+            prim.set_param(k, DspParam::from(v))
+                .map_err(|e| Error::new_xpand_error(&e.to_string()))?;
+        }
+        Ok(prim)
+    }
+    pub fn validate(&self) -> Result<(), Error> {
+        self.to_prim()?
+            .validate()
+            .map_err(|e| Error::new_xpand_error(&e.to_string()))
+    }
+}
+
+impl From<&ParamValue> for DspParam {
+    fn from(value: &ParamValue) -> Self {
+        match value {
+            ParamValue::InputTy(v) => v.clone().into(),
+            ParamValue::AMultSel(v) => v.clone().into(),
+            ParamValue::BMultSel(v) => v.clone().into(),
+            ParamValue::PreAddInSel(v) => v.clone().into(),
+            ParamValue::UseMult(v) => v.clone().into(),
+            ParamValue::UseSimd(v) => v.clone().into(),
+            ParamValue::UseWideXor(v) => (*v).into(),
+            ParamValue::XorSimd(v) => v.clone().into(),
+            ParamValue::AutoResetPatDet(v) => v.clone().into(),
+            ParamValue::AutoResetPriority(v) => v.clone().into(),
+            ParamValue::Val(_, v) => (*v as i64).into(),
+            ParamValue::Bool(v) => (*v).into(),
+            ParamValue::SelMask(v) => v.clone().into(),
+            ParamValue::SelPattern(v) => v.clone().into(),
+            ParamValue::UsePatternDetect(v) => v.clone().into(),
+            ParamValue::NumRegAB(v) => v.clone().into(),
+            ParamValue::NumReg(v) => v.clone().into(),
+        }
     }
 }
 
@@ -573,6 +464,7 @@ impl Default for Dsp {
             name: String::new(),
             prim: "DSP48E2".to_string(),
             loc,
+            site: String::new(),
             param: Param::<ParamValue>::default(),
             input: Dsp::default_input_port(),
             output: Dsp::default_output_port(),
@@ -729,7 +621,7 @@ impl ToInstance<ParamValue> for Dsp {
             inst.connect(&k, v.clone());
         }
         if self.loc.is_placed() {
-            let attr = attr_from_loc(&self.loc);
+            let attr = attr_from_loc(&self.loc, &self.site);
             inst.set_attr(attr);
         }
         inst
@@ -774,7 +666,7 @@ fn simd_opt_try_from_term(term: &xir::ExprTerm) -> Result<ParamValue, Error> {
     }
 }
 
-fn vec_word_width_try_from_term(term: &xir::ExprTerm) -> Result<i32, Error> {
+pub fn vec_word_width_try_from_term(term: &xir::ExprTerm) -> Result<i32, Error> {
     if let Some(length) = term.length() {
         match length {
             4 => Ok(12),
@@ -815,57 +707,97 @@ fn vl_expr_try_from_term(term: &xir::ExprTerm, lsb: usize, msb: usize) -> Result
     }
 }
 
-pub fn vaddrega_from_mach(instr: &xir::InstrMach) -> Result<Vec<vl::Stmt>, Error> {
-    let mut dsp = Dsp::default();
+/// wire connecting the PCOUT of the instruction defining term to the
+/// PCIN of the next one in the cascade
+pub fn cascade_name_try_from_term(term: &xir::ExprTerm) -> Result<xir::Id, Error> {
+    let id = xir::Id::try_from(term.clone())?;
+    Ok(format!("_{}_pcout", id))
+}
+
+/// wire connecting the ACOUT of the instruction defining term to the
+/// ACIN of the next one in the cascade
+pub fn a_cascade_name_try_from_term(term: &xir::ExprTerm) -> Result<xir::Id, Error> {
+    let id = xir::Id::try_from(term.clone())?;
+    Ok(format!("_{}_acout", id))
+}
+
+/// cascades of A, a multiply with a cascade input takes A from the ACOUT
+/// of the one defining its addend if both have the same A and A enable
+#[derive(Clone, Debug, Default)]
+pub struct ACascade {
+    acin: HashSet<xir::Id>,
+    acout: HashSet<xir::Id>,
+}
+
+impl ACascade {
+    pub fn try_from_prog(prog: &xir::Prog) -> Result<ACascade, Error> {
+        let mut cascade = ACascade::default();
+        let mut pcout: HashMap<xir::Id, &xir::InstrMach> = HashMap::new();
+        for instr in prog.body().iter().filter_map(|i| i.mach()) {
+            if matches!(
+                instr.op(),
+                xir::OpMach::MulAddRegACo | xir::OpMach::MulAddRegACio
+            ) {
+                pcout.insert(instr.dst().get_id(0)?, instr);
+            }
+        }
+        for instr in prog.body().iter().filter_map(|i| i.mach()) {
+            if matches!(
+                instr.op(),
+                xir::OpMach::MulAddRegACi | xir::OpMach::MulAddRegACio
+            ) {
+                let src = instr.arg().get_id(2)?;
+                if let Some(def) = pcout.get(&src) {
+                    let a = instr.arg().get_term(0)?;
+                    let cea = instr.arg().get_term(3)?;
+                    if def.arg().get_term(0)? == a && def.arg().get_term(3)? == cea {
+                        cascade.acin.insert(instr.dst().get_id(0)?);
+                        cascade.acout.insert(src);
+                    }
+                }
+            }
+        }
+        Ok(cascade)
+    }
+    /// instr takes A from ACIN
+    pub fn is_acin(&self, instr: &xir::InstrMach) -> bool {
+        instr
+            .dst()
+            .get_id(0)
+            .map_or(false, |id| self.acin.contains(&id))
+    }
+    /// instr drives ACOUT
+    pub fn is_acout(&self, instr: &xir::InstrMach) -> bool {
+        instr
+            .dst()
+            .get_id(0)
+            .map_or(false, |id| self.acout.contains(&id))
+    }
+}
+
+// place, connect clock, resets and P to a temporary wire and assign
+// every lane of dst from it
+fn stmt_try_from_dsp(
+    instr: &xir::InstrMach,
+    mut dsp: Dsp,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
     let mut stmt: Vec<vl::Stmt> = Vec::new();
-    let name = inst_name_try_from_instr(instr)?;
-    dsp.set_name(&name);
-    // loc
     if let Some(loc) = instr.loc() {
-        dsp.set_loc(loc.clone());
-    }
-    // simd
-    if let Some(t) = instr.dst().term() {
-        dsp.set_param("USE_SIMD", simd_opt_try_from_term(t)?)?;
+        dsp.set_loc(loc.clone(), device);
+    }
+    dsp.set_input("CLK", clock_expr_from_clock(clock))?;
+    let reset: Vec<String> = dsp
+        .input
+        .width()
+        .keys()
+        .filter(|p| p.starts_with("RST"))
+        .cloned()
+        .collect();
+    for r in reset.iter() {
+        dsp.set_input(r, reset_expr_from_clock(clock))?;
     }
-    // registers
-    dsp.set_param("CREG", ParamValue::from(NumReg::One))?;
-    dsp.set_param("AREG", ParamValue::from(NumRegAB::One))?;
-    dsp.set_param("BREG", ParamValue::from(NumRegAB::One))?;
-    dsp.set_param("ACASCREG", ParamValue::from(NumRegAB::One))?;
-    dsp.set_param("BCASCREG", ParamValue::from(NumRegAB::One))?;
-    dsp.set_param("PREG", ParamValue::from(NumReg::One))?;
-    // opcode
-    dsp.set_input("OPMODE", create_literal(9, 51))?;
-    // input
-    let left_term = instr.arg().get_term(0)?;
-    let c_msb = dsp.get_input_width("C").unwrap() - 1;
-    let c_expr = vl_expr_try_from_term(left_term, 0, c_msb as usize)?;
-    dsp.set_input("C", c_expr)?;
-    let right_term = instr.arg().get_term(1)?;
-    let b_width = *dsp.get_input_width("B").unwrap();
-    let b_expr = vl_expr_try_from_term(right_term, 0, (b_width - 1) as usize)?;
-    dsp.set_input("B", b_expr)?;
-    let a_width = dsp.get_input_width("A").unwrap();
-    let a_expr = vl_expr_try_from_term(
-        right_term,
-        b_width as usize,
-        (b_width + a_width - 1) as usize,
-    )?;
-    dsp.set_input("A", a_expr)?;
-    let l_en_term = instr.arg().get_term(2)?;
-    let r_en_term = instr.arg().get_term(3)?;
-    let o_en_term = instr.arg().get_term(4)?;
-    let l_en_name = String::try_from(l_en_term.clone())?;
-    let r_en_name = String::try_from(r_en_term.clone())?;
-    let o_en_name = String::try_from(o_en_term.clone())?;
-    dsp.set_input("CEC", vl::Expr::new_ref(&l_en_name))?;
-    dsp.set_input("CEA1", vl::Expr::new_ref(&r_en_name))?;
-    dsp.set_input("CEA2", vl::Expr::new_ref(&r_en_name))?;
-    dsp.set_input("CEB1", vl::Expr::new_ref(&r_en_name))?;
-    dsp.set_input("CEB2", vl::Expr::new_ref(&r_en_name))?;
-    dsp.set_input("CEP", vl::Expr::new_ref(&o_en_name))?;
-    // output
     let dst_term = instr.dst().get_term(0)?;
     let output = tmp_name_try_from_term(dst_term)?;
     dsp.set_output("P", vl::Expr::new_ref(&output))?;
@@ -889,73 +821,334 @@ pub fn vaddrega_from_mach(instr: &xir::InstrMach) -> Result<Vec<vl::Stmt>, Error
     Ok(stmt)
 }
 
-pub fn muladdrega_from_mach(instr: &xir::InstrMach) -> Result<Vec<vl::Stmt>, Error> {
+fn dsp_try_from_instr(instr: &xir::InstrMach) -> Result<Dsp, Error> {
     let mut dsp = Dsp::default();
-    let mut stmt: Vec<vl::Stmt> = Vec::new();
     let name = inst_name_try_from_instr(instr)?;
     dsp.set_name(&name);
-    // loc
-    if let Some(loc) = instr.loc() {
-        dsp.set_loc(loc.clone());
+    Ok(dsp)
+}
+
+fn set_enable(dsp: &mut Dsp, port: &[&str], term: &xir::ExprTerm) -> Result<(), Error> {
+    let name = String::try_from(term.clone())?;
+    for p in port {
+        dsp.set_input(p, vl::Expr::new_ref(&name))?;
+    }
+    Ok(())
+}
+
+// opcode of vector add and sub, z = c and x = a:b
+pub const VADD_OPMODE: i64 = 51;
+
+// alu mode of vector sub, z - (w + x + y + cin)
+pub const VSUB_ALUMODE: i64 = 3;
+
+/// dsp with the parameters of a vector add or sub, before any input is connected
+pub fn vadd_dsp_try_from_mach(
+    instr: &xir::InstrMach,
+    reg: bool,
+    clock: &Clock,
+) -> Result<Dsp, Error> {
+    let mut dsp = dsp_try_from_instr(instr)?;
+    dsp.set_reset_from_clock(clock)?;
+    // simd
+    if let Some(t) = instr.dst().term() {
+        dsp.set_param("USE_SIMD", simd_opt_try_from_term(t)?)?;
+    }
+    // registers
+    if reg {
+        dsp.set_param("CREG", ParamValue::from(NumReg::One))?;
+        dsp.set_param("AREG", ParamValue::from(NumRegAB::One))?;
+        dsp.set_param("BREG", ParamValue::from(NumRegAB::One))?;
+        dsp.set_param("ACASCREG", ParamValue::from(NumRegAB::One))?;
+        dsp.set_param("BCASCREG", ParamValue::from(NumRegAB::One))?;
+        dsp.set_param("PREG", ParamValue::from(NumReg::One))?;
+    }
+    dsp.validate()?;
+    Ok(dsp)
+}
+
+// vector add or sub, computes C + A:B or C - A:B for every lane
+fn vadd_try_from_mach(
+    instr: &xir::InstrMach,
+    reg: bool,
+    sub: bool,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    let mut dsp = vadd_dsp_try_from_mach(instr, reg, clock)?;
+    dsp.set_input("OPMODE", create_literal(9, VADD_OPMODE))?;
+    if sub {
+        dsp.set_input("ALUMODE", create_literal(4, VSUB_ALUMODE))?;
     }
+    // input
+    let left_term = instr.arg().get_term(0)?;
+    let c_msb = dsp.get_input_width("C").unwrap() - 1;
+    let c_expr = vl_expr_try_from_term(left_term, 0, c_msb as usize)?;
+    dsp.set_input("C", c_expr)?;
+    let right_term = instr.arg().get_term(1)?;
+    let b_width = *dsp.get_input_width("B").unwrap();
+    let b_expr = vl_expr_try_from_term(right_term, 0, (b_width - 1) as usize)?;
+    dsp.set_input("B", b_expr)?;
+    let a_width = dsp.get_input_width("A").unwrap();
+    let a_expr = vl_expr_try_from_term(
+        right_term,
+        b_width as usize,
+        (b_width + a_width - 1) as usize,
+    )?;
+    dsp.set_input("A", a_expr)?;
+    if reg {
+        set_enable(&mut dsp, &["CEC"], instr.arg().get_term(2)?)?;
+        set_enable(
+            &mut dsp,
+            &["CEA1", "CEA2", "CEB1", "CEB2"],
+            instr.arg().get_term(3)?,
+        )?;
+        set_enable(&mut dsp, &["CEP"], instr.arg().get_term(4)?)?;
+    }
+    stmt_try_from_dsp(instr, dsp, clock, device)
+}
+
+/// where the addend of a multiply comes from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Addend {
+    None,
+    Fabric,
+    Cascade,
+}
+
+impl Addend {
+    // opcode, x = y = m and z is either zero, c or pcin
+    pub fn opmode(&self) -> i64 {
+        match self {
+            Addend::None => 5,
+            Addend::Fabric => 53,
+            Addend::Cascade => 21,
+        }
+    }
+}
+
+/// addend, registers and cascade output of every multiply instruction
+pub fn mul_conf_from_op(op: &xir::OpMach) -> Option<(Addend, bool, bool)> {
+    match op {
+        xir::OpMach::Mul | xir::OpMach::VecMul => Some((Addend::None, false, false)),
+        xir::OpMach::MulAdd => Some((Addend::Fabric, false, false)),
+        xir::OpMach::MulAddRegA => Some((Addend::Fabric, true, false)),
+        xir::OpMach::MulAddRegACi => Some((Addend::Cascade, true, false)),
+        xir::OpMach::MulAddRegACo => Some((Addend::Fabric, true, true)),
+        xir::OpMach::MulAddRegACio => Some((Addend::Cascade, true, true)),
+        _ => None,
+    }
+}
+
+/// dsp with the parameters of a multiply, before any input is connected,
+/// with acin A arrives already registered from the ACOUT of the previous one
+pub fn mul_dsp_try_from_mach(
+    instr: &xir::InstrMach,
+    reg: bool,
+    acin: bool,
+    clock: &Clock,
+) -> Result<Dsp, Error> {
+    let mut dsp = dsp_try_from_instr(instr)?;
+    dsp.set_reset_from_clock(clock)?;
     // multiply
     dsp.set_param("USE_MULT", ParamValue::from(UseMult::Multiply))?;
     // registers
-    dsp.set_param("AREG", ParamValue::from(NumRegAB::One))?;
-    dsp.set_param("BREG", ParamValue::from(NumRegAB::One))?;
-    dsp.set_param("ACASCREG", ParamValue::from(NumRegAB::One))?;
-    dsp.set_param("BCASCREG", ParamValue::from(NumRegAB::One))?;
-    dsp.set_param("MREG", ParamValue::from(NumReg::One))?;
-    dsp.set_param("PREG", ParamValue::from(NumReg::One))?;
-    // opcode
-    dsp.set_input("OPMODE", create_literal(9, 53))?;
+    if reg {
+        dsp.set_param("AREG", ParamValue::from(NumRegAB::One))?;
+        dsp.set_param("BREG", ParamValue::from(NumRegAB::One))?;
+        dsp.set_param("ACASCREG", ParamValue::from(NumRegAB::One))?;
+        dsp.set_param("BCASCREG", ParamValue::from(NumRegAB::One))?;
+        dsp.set_param("MREG", ParamValue::from(NumReg::One))?;
+        dsp.set_param("PREG", ParamValue::from(NumReg::One))?;
+    }
+    if acin {
+        dsp.set_param("A_INPUT", ParamValue::from(InputTy::Cascade))?;
+        dsp.set_param("AREG", ParamValue::from(NumRegAB::Zero))?;
+        dsp.set_param("ACASCREG", ParamValue::from(NumRegAB::Zero))?;
+    }
+    dsp.validate()?;
+    Ok(dsp)
+}
+
+// multiply, optionally followed by an add of C or PCIN, registers use
+// the enables in args 3 to 6 and pcout drives the cascade output
+fn mul_try_from_mach(
+    instr: &xir::InstrMach,
+    addend: Addend,
+    reg: bool,
+    pcout: bool,
+    cascade: &ACascade,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    let acin = cascade.is_acin(instr);
+    let mut dsp = mul_dsp_try_from_mach(instr, reg, acin, clock)?;
+    dsp.set_input("OPMODE", create_literal(9, addend.opmode()))?;
     // input
-    let a_term = instr.arg().get_term(0)?;
-    let a_width = *dsp.get_input_width("A").unwrap();
-    let a_expr = vl_expr_try_from_term(a_term, 0, (a_width - 1) as usize)?;
-    dsp.set_input("A", a_expr)?;
+    if acin {
+        let acin = a_cascade_name_try_from_term(instr.arg().get_term(2)?)?;
+        dsp.set_input("ACIN", vl::Expr::new_ref(&acin))?;
+    } else {
+        let a_term = instr.arg().get_term(0)?;
+        let a_width = *dsp.get_input_width("A").unwrap();
+        let a_expr = vl_expr_try_from_term(a_term, 0, (a_width - 1) as usize)?;
+        dsp.set_input("A", a_expr)?;
+    }
     let b_term = instr.arg().get_term(1)?;
     let b_width = *dsp.get_input_width("B").unwrap();
     let b_expr = vl_expr_try_from_term(b_term, 0, (b_width - 1) as usize)?;
     dsp.set_input("B", b_expr)?;
-    let c_term = instr.arg().get_term(2)?;
-    let c_width = *dsp.get_input_width("C").unwrap();
-    let c_expr = vl_expr_try_from_term(c_term, 0, (c_width - 1) as usize)?;
-    dsp.set_input("C", c_expr)?;
-    let ena_term = instr.arg().get_term(3)?;
-    let enb_term = instr.arg().get_term(4)?;
-    let enm_term = instr.arg().get_term(5)?;
-    let enp_term = instr.arg().get_term(6)?;
-    let ena_name = String::try_from(ena_term.clone())?;
-    let enb_name = String::try_from(enb_term.clone())?;
-    let enm_name = String::try_from(enm_term.clone())?;
-    let enp_name = String::try_from(enp_term.clone())?;
-    dsp.set_input("CEA1", vl::Expr::new_ref(&ena_name))?;
-    dsp.set_input("CEA2", vl::Expr::new_ref(&ena_name))?;
-    dsp.set_input("CEB1", vl::Expr::new_ref(&enb_name))?;
-    dsp.set_input("CEB2", vl::Expr::new_ref(&enb_name))?;
-    dsp.set_input("CEM", vl::Expr::new_ref(&enm_name))?;
-    dsp.set_input("CEP", vl::Expr::new_ref(&enp_name))?;
-    // output
-    let dst_term = instr.dst().get_term(0)?;
-    let output = tmp_name_try_from_term(dst_term)?;
-    dsp.set_output("P", vl::Expr::new_ref(&output))?;
-    stmt.push(dsp.to_stmt());
-    let dst: Vec<vl::Expr> = vec_expr_try_from_expr(instr.dst())?;
-    let wbits = vec_word_width_try_from_term(dst_term)?;
-    if let Some(width) = dst_term.width() {
-        if let Ok(ebits) = i32::try_from(width) {
-            for (i, e) in dst.iter().enumerate() {
-                let i = i as i32;
-                let hi = i * wbits + (ebits - 1);
-                let lo = i * wbits;
-                let assign = vl::Parallel::Assign(
-                    e.clone(),
-                    vl::Expr::new_slice(&output, vl::Expr::new_int(hi), vl::Expr::new_int(lo)),
-                );
-                stmt.push(vl::Stmt::from(assign));
-            }
+    match addend {
+        Addend::Fabric => {
+            let c_term = instr.arg().get_term(2)?;
+            let c_width = *dsp.get_input_width("C").unwrap();
+            let c_expr = vl_expr_try_from_term(c_term, 0, (c_width - 1) as usize)?;
+            dsp.set_input("C", c_expr)?;
+        }
+        Addend::Cascade => {
+            let c_term = instr.arg().get_term(2)?;
+            let pcin = cascade_name_try_from_term(c_term)?;
+            dsp.set_input("PCIN", vl::Expr::new_ref(&pcin))?;
         }
+        Addend::None => (),
     }
-    Ok(stmt)
+    if reg {
+        set_enable(&mut dsp, &["CEA1", "CEA2"], instr.arg().get_term(3)?)?;
+        set_enable(&mut dsp, &["CEB1", "CEB2"], instr.arg().get_term(4)?)?;
+        set_enable(&mut dsp, &["CEM"], instr.arg().get_term(5)?)?;
+        set_enable(&mut dsp, &["CEP"], instr.arg().get_term(6)?)?;
+    }
+    // output
+    if pcout {
+        let dst_term = instr.dst().get_term(0)?;
+        let pcout = cascade_name_try_from_term(dst_term)?;
+        dsp.set_output("PCOUT", vl::Expr::new_ref(&pcout))?;
+    }
+    if cascade.is_acout(instr) {
+        let acout = a_cascade_name_try_from_term(instr.dst().get_term(0)?)?;
+        dsp.set_output("ACOUT", vl::Expr::new_ref(&acout))?;
+    }
+    stmt_try_from_dsp(instr, dsp, clock, device)
+}
+
+pub fn vaddrega_from_mach(
+    instr: &xir::InstrMach,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    vadd_try_from_mach(instr, true, false, clock, device)
+}
+
+pub fn vadd_from_mach(
+    instr: &xir::InstrMach,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    vadd_try_from_mach(instr, false, false, clock, device)
+}
+
+pub fn vsub_from_mach(
+    instr: &xir::InstrMach,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    vadd_try_from_mach(instr, false, true, clock, device)
+}
+
+// there is only one multiplier per dsp, so lanes are not supported
+pub fn vmul_from_mach(
+    instr: &xir::InstrMach,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    let dst_term = instr.dst().get_term(0)?;
+    match dst_term.length() {
+        Some(length) if length > 1 => Err(Error::new_xpand_error(
+            "vector multiply must have only one lane",
+        )),
+        _ => mul_try_from_mach(
+            instr,
+            Addend::None,
+            false,
+            false,
+            &ACascade::default(),
+            clock,
+            device,
+        ),
+    }
+}
+
+pub fn mul_from_mach(
+    instr: &xir::InstrMach,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    mul_try_from_mach(
+        instr,
+        Addend::None,
+        false,
+        false,
+        &ACascade::default(),
+        clock,
+        device,
+    )
+}
+
+pub fn muladd_from_mach(
+    instr: &xir::InstrMach,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    mul_try_from_mach(
+        instr,
+        Addend::Fabric,
+        false,
+        false,
+        &ACascade::default(),
+        clock,
+        device,
+    )
+}
+
+pub fn muladdrega_from_mach(
+    instr: &xir::InstrMach,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    mul_try_from_mach(
+        instr,
+        Addend::Fabric,
+        true,
+        false,
+        &ACascade::default(),
+        clock,
+        device,
+    )
+}
+
+pub fn muladdregaci_from_mach(
+    instr: &xir::InstrMach,
+    cascade: &ACascade,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    mul_try_from_mach(instr, Addend::Cascade, true, false, cascade, clock, device)
+}
+
+pub fn muladdregaco_from_mach(
+    instr: &xir::InstrMach,
+    cascade: &ACascade,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    mul_try_from_mach(instr, Addend::Fabric, true, true, cascade, clock, device)
+}
+
+pub fn muladdregacio_from_mach(
+    instr: &xir::InstrMach,
+    cascade: &ACascade,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    mul_try_from_mach(instr, Addend::Cascade, true, true, cascade, clock, device)
 }
@@ -1,4 +1,5 @@
 use bline::errors::Error as BlineError;
+use device::errors::Error as DeviceError;
 use ir::errors::Error as IrError;
 use std::fmt;
 use std::num::TryFromIntError;
@@ -9,6 +10,7 @@ pub enum Error {
     Ir(IrError),
     Xir(XirError),
     Bline(BlineError),
+    Device(DeviceError),
     Xpand(String),
     TryFromInt(TryFromIntError),
 }
@@ -37,6 +39,12 @@ impl From<BlineError> for Error {
     }
 }
 
+impl From<DeviceError> for Error {
+    fn from(e: DeviceError) -> Self {
+        Error::Device(e)
+    }
+}
+
 impl From<TryFromIntError> for Error {
     fn from(e: TryFromIntError) -> Self {
         Error::TryFromInt(e)
@@ -50,6 +58,7 @@ impl fmt::Display for Error {
             Error::Xir(msg) => write!(f, "{}", msg),
             Error::Xpand(msg) => write!(f, "{}", msg),
             Error::Bline(msg) => write!(f, "{}", msg),
+            Error::Device(msg) => write!(f, "{}", msg),
             Error::TryFromInt(msg) => write!(f, "{}", msg),
         }
     }
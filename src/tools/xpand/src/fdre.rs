@@ -1,12 +1,14 @@
 use crate::errors::Error;
 use crate::expr::ToExpr;
 use crate::instance::ToInstance;
-use crate::loc::attr_from_loc;
+use crate::loc::{attr_from_loc, site_name_from_device};
 use crate::loc::{Bel, BelReg, ExprCoord, Loc};
 use crate::param::Param;
 use crate::port::{ConnectionMap, DefaultPort, Port, WidthMap};
+use crate::{clock_expr_from_clock, is_reset_inverted, reset_expr_from_clock, CLOCK, RESET};
 use crate::{create_literal, inst_name_try_from_instr, vec_expr_try_from_expr};
-use crate::{CLOCK, RESET};
+use device::ast::Device;
+use ir::clock::Clock;
 use verilog::ast as vl;
 use xir::ast as xir;
 
@@ -21,6 +23,7 @@ pub struct Fdre {
     pub prim: String,
     pub param: Param<ParamValue>,
     pub loc: Loc,
+    pub site: String,
     pub input: Port,
     pub output: Port,
 }
@@ -102,6 +105,7 @@ impl Default for Fdre {
             name: String::new(),
             prim: "FDRE".to_string(),
             loc,
+            site: String::new(),
             param: Param::<ParamValue>::default(),
             input: Fdre::default_input_port(),
             output: Fdre::default_output_port(),
@@ -110,9 +114,29 @@ impl Default for Fdre {
 }
 
 impl Fdre {
-    pub fn set_loc(&mut self, loc: Loc) {
+    pub fn set_loc(&mut self, loc: Loc, device: Option<&Device>) {
+        self.site = site_name_from_device(&loc, device);
         self.loc = loc;
     }
+    // same register with an asynchronous clear, i.e. FDCE
+    pub fn set_async(&mut self) {
+        self.prim = "FDCE".to_string();
+        self.input.rename("R", "CLR");
+        if let Some(value) = self.param.remove("IS_R_INVERTED") {
+            self.param.insert("IS_CLR_INVERTED".to_string(), value);
+        }
+    }
+    // active-low resets are inverted by the register, asynchronous ones
+    // make it an FDCE
+    pub fn set_reset_from_clock(&mut self, clock: &Clock) -> Result<(), Error> {
+        if is_reset_inverted(clock) {
+            self.param.set_param("IS_R_INVERTED", true)?;
+        }
+        if clock.is_async() {
+            self.set_async();
+        }
+        Ok(())
+    }
 }
 
 impl ToInstance<ParamValue> for Fdre {
@@ -132,7 +156,7 @@ impl ToInstance<ParamValue> for Fdre {
             inst.connect(&k, v.clone());
         }
         if self.loc.is_placed() {
-            let attr = attr_from_loc(&self.loc);
+            let attr = attr_from_loc(&self.loc, &self.site);
             inst.set_attr(attr);
         }
         inst
@@ -163,18 +187,25 @@ impl ToInstance<ParamValue> for Fdre {
     }
 }
 
-pub fn fdre_from_mach(instr: &xir::InstrMach) -> Result<Vec<vl::Stmt>, Error> {
+pub fn fdre_from_mach(
+    instr: &xir::InstrMach,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
     let mut fdre = Fdre::default();
     let name = inst_name_try_from_instr(instr)?;
     fdre.set_name(&name);
     if let Some(loc) = instr.loc() {
-        fdre.set_loc(loc.clone());
+        fdre.set_loc(loc.clone(), device);
     }
     let input = ["D", "CE"];
     let arg: Vec<vl::Expr> = vec_expr_try_from_expr(instr.arg())?;
     for (i, e) in input.iter().zip(arg) {
         fdre.set_input(i, e)?;
     }
+    fdre.set_input("C", clock_expr_from_clock(clock))?;
+    fdre.set_input("R", reset_expr_from_clock(clock))?;
+    fdre.set_reset_from_clock(clock)?;
     let output = ["Q"];
     let dst: Vec<vl::Expr> = vec_expr_try_from_expr(instr.dst())?;
     for (o, e) in output.iter().zip(dst) {
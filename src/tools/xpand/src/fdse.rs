@@ -1,12 +1,14 @@
 use crate::errors::Error;
 use crate::expr::ToExpr;
 use crate::instance::ToInstance;
-use crate::loc::attr_from_loc;
+use crate::loc::{attr_from_loc, site_name_from_device};
 use crate::loc::{Bel, BelReg, ExprCoord, Loc};
 use crate::param::Param;
 use crate::port::{ConnectionMap, DefaultPort, Port, WidthMap};
+use crate::{clock_expr_from_clock, is_reset_inverted, reset_expr_from_clock, CLOCK, RESET};
 use crate::{create_literal, inst_name_try_from_instr, vec_expr_try_from_expr};
-use crate::{CLOCK, RESET};
+use device::ast::Device;
+use ir::clock::Clock;
 use verilog::ast as vl;
 use xir::ast as xir;
 
@@ -21,6 +23,7 @@ pub struct Fdse {
     pub prim: String,
     pub param: Param<ParamValue>,
     pub loc: Loc,
+    pub site: String,
     pub input: Port,
     pub output: Port,
 }
@@ -94,6 +97,7 @@ impl Default for Fdse {
             name: String::new(),
             prim: "FDSE".to_string(),
             loc,
+            site: String::new(),
             param: Param::<ParamValue>::default(),
             input: Fdse::default_input_port(),
             output: Fdse::default_output_port(),
@@ -102,9 +106,29 @@ impl Default for Fdse {
 }
 
 impl Fdse {
-    pub fn set_loc(&mut self, loc: Loc) {
+    pub fn set_loc(&mut self, loc: Loc, device: Option<&Device>) {
+        self.site = site_name_from_device(&loc, device);
         self.loc = loc;
     }
+    // same register with an asynchronous preset, i.e. FDPE
+    pub fn set_async(&mut self) {
+        self.prim = "FDPE".to_string();
+        self.input.rename("S", "PRE");
+        if let Some(value) = self.param.remove("IS_S_INVERTED") {
+            self.param.insert("IS_PRE_INVERTED".to_string(), value);
+        }
+    }
+    // active-low resets are inverted by the register, asynchronous ones
+    // make it an FDPE
+    pub fn set_reset_from_clock(&mut self, clock: &Clock) -> Result<(), Error> {
+        if is_reset_inverted(clock) {
+            self.param.set_param("IS_S_INVERTED", true)?;
+        }
+        if clock.is_async() {
+            self.set_async();
+        }
+        Ok(())
+    }
 }
 
 impl ToInstance<ParamValue> for Fdse {
@@ -124,7 +148,7 @@ impl ToInstance<ParamValue> for Fdse {
             inst.connect(&k, v.clone());
         }
         if self.loc.is_placed() {
-            let attr = attr_from_loc(&self.loc);
+            let attr = attr_from_loc(&self.loc, &self.site);
             inst.set_attr(attr);
         }
         inst
@@ -155,18 +179,25 @@ impl ToInstance<ParamValue> for Fdse {
     }
 }
 
-pub fn fdre_from_mach(instr: &xir::InstrMach) -> Result<Vec<vl::Stmt>, Error> {
+pub fn fdse_from_mach(
+    instr: &xir::InstrMach,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
     let mut fdse = Fdse::default();
     let name = inst_name_try_from_instr(instr)?;
     fdse.set_name(&name);
     if let Some(loc) = instr.loc() {
-        fdse.set_loc(loc.clone());
+        fdse.set_loc(loc.clone(), device);
     }
     let input = ["D", "CE"];
     let arg: Vec<vl::Expr> = vec_expr_try_from_expr(instr.arg())?;
     for (i, e) in input.iter().zip(arg) {
         fdse.set_input(i, e)?;
     }
+    fdse.set_input("C", clock_expr_from_clock(clock))?;
+    fdse.set_input("S", reset_expr_from_clock(clock))?;
+    fdse.set_reset_from_clock(clock)?;
     let output = ["Q"];
     let dst: Vec<vl::Expr> = vec_expr_try_from_expr(instr.dst())?;
     for (o, e) in output.iter().zip(dst) {
@@ -0,0 +1,884 @@
+use crate::dsp::{self, Addend, Dsp};
+use crate::errors::Error;
+use crate::expr::ToExpr;
+use crate::fdre::Fdre;
+use crate::fdse::Fdse;
+use crate::loc::{site_name_from_device, string_from_bel, Loc};
+use crate::lut;
+use crate::param::Param;
+use crate::port::WidthMap;
+use crate::ram::width_try_from_term;
+use crate::to_verilog::ToVerilogExpr;
+use crate::{check_async_reset, inst_name_try_from_instr, lram, ramb, uram};
+use device::ast::Device;
+use ir::clock::Clock;
+use prim::ultrascale::carry::Carry;
+use prim::PortSet;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use std::fmt;
+use verilog::ast as vl;
+use xir::ast as xir;
+
+// Yosys JSON netlist. Nets are numbered from 2 and constants are the
+// strings "0" and "1", so wires between instructions share bits
+// instead of becoming assignments.
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Bit {
+    Net(u64),
+    Const(String),
+}
+
+pub type Bits = Vec<Bit>;
+pub type StrMap = BTreeMap<String, String>;
+pub type BitsMap = BTreeMap<String, Bits>;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Port {
+    pub direction: String,
+    pub bits: Bits,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Cell {
+    pub hide_name: u8,
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub parameters: StrMap,
+    pub attributes: StrMap,
+    pub port_directions: StrMap,
+    pub connections: BitsMap,
+    // parameters as verilog literals, for backends that need their type
+    #[serde(skip)]
+    pub generics: StrMap,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Net {
+    pub hide_name: u8,
+    pub bits: Bits,
+    pub attributes: StrMap,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Module {
+    pub attributes: StrMap,
+    pub ports: BTreeMap<String, Port>,
+    pub cells: BTreeMap<String, Cell>,
+    pub netnames: BTreeMap<String, Net>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Netlist {
+    pub creator: String,
+    pub modules: BTreeMap<String, Module>,
+}
+
+impl Bit {
+    pub fn zero() -> Self {
+        Bit::Const("0".to_string())
+    }
+    pub fn one() -> Self {
+        Bit::Const("1".to_string())
+    }
+}
+
+impl fmt::Display for Netlist {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string_pretty(self).map_err(|_| fmt::Error)?;
+        write!(f, "{}", json)
+    }
+}
+
+// two's complement constant of width bits
+fn bits_from_val(width: u64, value: i64) -> Bits {
+    (0..width)
+        .map(|i| {
+            if (value >> i.min(63)) & 1 == 1 {
+                Bit::one()
+            } else {
+                Bit::zero()
+            }
+        })
+        .collect()
+}
+
+// digits of a verilog literal as a binary string of width bits
+pub(crate) fn binary_from_digits(width: usize, radix: char, digits: &str) -> Result<String, Error> {
+    let bits_per_digit = match radix {
+        'b' => 1,
+        'h' => 4,
+        _ => {
+            let err = format!("unsupported radix {}", radix);
+            return Err(Error::new_xpand_error(&err));
+        }
+    };
+    let mut bin = String::new();
+    for c in digits.chars().filter(|c| *c != '_') {
+        if let Some(d) = c.to_digit(1 << bits_per_digit) {
+            bin.push_str(&format!("{:0w$b}", d, w = bits_per_digit));
+        } else {
+            let err = format!("invalid digit {} in literal", c);
+            return Err(Error::new_xpand_error(&err));
+        }
+    }
+    if bin.len() > width {
+        Ok(bin.split_off(bin.len() - width))
+    } else {
+        Ok(format!("{}{}", "0".repeat(width - bin.len()), bin))
+    }
+}
+
+/// parameter value the way Yosys writes it, numbers become binary
+/// strings and strings are left as they are
+pub fn param_try_from_expr(expr: &vl::Expr) -> Result<String, Error> {
+    param_try_from_literal(&expr.to_string())
+}
+
+fn param_try_from_literal(value: &str) -> Result<String, Error> {
+    if value.starts_with('"') {
+        Ok(value.trim_matches('"').to_string())
+    } else if let Some(index) = value.find('\'') {
+        let width = value[..index]
+            .parse::<usize>()
+            .map_err(|e| Error::new_xpand_error(&e.to_string()))?;
+        let mut rest = value[index + 1..].chars();
+        if let Some(radix) = rest.next() {
+            binary_from_digits(width, radix, rest.as_str())
+        } else {
+            Err(Error::new_xpand_error("missing literal radix"))
+        }
+    } else {
+        let int = value
+            .parse::<i64>()
+            .map_err(|e| Error::new_xpand_error(&e.to_string()))?;
+        Ok(format!("{:032b}", int as u32))
+    }
+}
+
+fn generics_from_param<T: ToExpr>(param: &Param<T>) -> StrMap {
+    param
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_expr().to_string()))
+        .collect()
+}
+
+fn width_from_port_set(set: &PortSet) -> WidthMap {
+    set.iter().map(|p| (p.name(), p.width())).collect()
+}
+
+// net names of a term, vector lanes are separate wires as in Verilog
+pub(crate) fn names_try_from_term(term: &xir::ExprTerm) -> Result<Vec<(String, u64)>, Error> {
+    let id = xir::Id::try_from(term.clone())?;
+    let width = if let Some(width) = term.width() {
+        width
+    } else {
+        let err = format!("term {} must have a width", id);
+        return Err(Error::new_xpand_error(&err));
+    };
+    if let Some(length) = term.length() {
+        Ok((0..length)
+            .map(|n| (format!("{}_{}", id, n), width))
+            .collect())
+    } else {
+        Ok(vec![(id, width)])
+    }
+}
+
+pub(crate) fn terms_from_expr(expr: &xir::Expr) -> Vec<xir::ExprTerm> {
+    expr.clone().into()
+}
+
+// cell ports, their widths and what is connected to them
+#[derive(Clone, Debug, Default)]
+struct CellPort {
+    pub input: WidthMap,
+    pub output: WidthMap,
+    pub connection: HashMap<String, Bits>,
+}
+
+impl CellPort {
+    pub fn new(input: WidthMap, output: WidthMap) -> Self {
+        CellPort {
+            input,
+            output,
+            connection: HashMap::new(),
+        }
+    }
+    pub fn connect(&mut self, port: &str, bits: Bits) {
+        self.connection.insert(port.to_string(), bits);
+    }
+    pub fn connect_all(&mut self, port: &[&str], bits: &[Bit]) {
+        for p in port {
+            self.connect(p, bits.to_vec());
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Builder<'a> {
+    pub next: u64,
+    pub net: HashMap<String, Bits>,
+    pub module: Module,
+    pub clock: Bits,
+    pub reset: Bits,
+    pub device: Option<&'a Device>,
+}
+
+impl<'a> Builder<'a> {
+    pub fn new(device: Option<&'a Device>) -> Self {
+        Builder {
+            next: 2,
+            net: HashMap::new(),
+            module: Module::default(),
+            clock: Bits::new(),
+            reset: Bits::new(),
+            device,
+        }
+    }
+    pub fn new_bits(&mut self, width: u64) -> Bits {
+        let bits: Bits = (self.next..self.next + width).map(Bit::Net).collect();
+        self.next += width;
+        bits
+    }
+    pub fn add_net(&mut self, name: &str, bits: Bits) {
+        let net = Net {
+            hide_name: 0,
+            bits: bits.clone(),
+            attributes: StrMap::new(),
+        };
+        self.module.netnames.insert(name.to_string(), net);
+        self.net.insert(name.to_string(), bits);
+    }
+    pub fn add_port(&mut self, name: &str, direction: &str, bits: Bits) {
+        let port = Port {
+            direction: direction.to_string(),
+            bits,
+        };
+        self.module.ports.insert(name.to_string(), port);
+    }
+    pub fn get_bits(&self, name: &str) -> Result<Bits, Error> {
+        if let Some(bits) = self.net.get(name) {
+            Ok(bits.clone())
+        } else {
+            let err = format!("{} is not defined", name);
+            Err(Error::new_xpand_error(&err))
+        }
+    }
+    // bits of every lane of term, lsb first
+    pub fn bits_try_from_term(&self, term: &xir::ExprTerm) -> Result<Bits, Error> {
+        let mut bits = Bits::new();
+        for (name, _) in names_try_from_term(term)? {
+            bits.extend(self.get_bits(&name)?);
+        }
+        Ok(bits)
+    }
+    // bit i of term, zero past its width
+    pub fn bit_try_from_term(&self, term: &xir::ExprTerm, i: u64) -> Result<Bit, Error> {
+        let bits = self.bits_try_from_term(term)?;
+        Ok(bits.get(i as usize).cloned().unwrap_or_else(Bit::zero))
+    }
+    // bits of the lanes of term, each one padded to the dsp word width
+    pub fn lane_bits_try_from_term(&self, term: &xir::ExprTerm) -> Result<Bits, Error> {
+        let word = dsp::vec_word_width_try_from_term(term)? as u64;
+        let mut bits = Bits::new();
+        for (name, width) in names_try_from_term(term)? {
+            bits.extend(self.get_bits(&name)?);
+            bits.extend((width..word).map(|_| Bit::zero()));
+        }
+        Ok(bits)
+    }
+    pub fn add_cell(
+        &mut self,
+        name: &str,
+        ty: &str,
+        generics: StrMap,
+        loc: Option<&Loc>,
+        port: CellPort,
+    ) -> Result<(), Error> {
+        let mut parameters = StrMap::new();
+        for (k, v) in generics.iter() {
+            parameters.insert(k.to_string(), param_try_from_literal(v)?);
+        }
+        let mut cell = Cell {
+            ty: ty.to_string(),
+            parameters,
+            generics,
+            ..Cell::default()
+        };
+        if let Some(loc) = loc {
+            if loc.is_placed() {
+                if let Some(bel) = string_from_bel(loc.bel()) {
+                    cell.attributes.insert("BEL".to_string(), bel);
+                }
+                cell.attributes
+                    .insert("LOC".to_string(), site_name_from_device(loc, self.device));
+            }
+        }
+        // unconnected inputs are zero and unconnected outputs get new nets
+        for (p, w) in port.input.iter() {
+            let mut bits = port.connection.get(p).cloned().unwrap_or_default();
+            bits.resize(*w as usize, Bit::zero());
+            cell.port_directions.insert(p.clone(), "input".to_string());
+            cell.connections.insert(p.clone(), bits);
+        }
+        // in name order, so the new nets do not depend on the map order
+        let mut output: Vec<(&String, &u32)> = port.output.iter().collect();
+        output.sort();
+        for (p, w) in output {
+            let mut bits = port.connection.get(p).cloned().unwrap_or_default();
+            bits.truncate(*w as usize);
+            let rest = *w - bits.len() as u32;
+            bits.extend(self.new_bits(u64::from(rest)));
+            cell.port_directions.insert(p.clone(), "output".to_string());
+            cell.connections.insert(p.clone(), bits);
+        }
+        self.module.cells.insert(name.to_string(), cell);
+        Ok(())
+    }
+}
+
+// bits of a wire instruction, or none if some argument is still undefined
+fn bits_try_from_basc(
+    builder: &Builder,
+    instr: &xir::InstrBasc,
+) -> Result<Option<Vec<(String, Bits)>>, Error> {
+    let mut arg = Bits::new();
+    let mut lanes: Vec<Bits> = Vec::new();
+    for term in terms_from_expr(instr.arg()) {
+        for (name, _) in names_try_from_term(&term)? {
+            if let Some(bits) = builder.net.get(&name) {
+                arg.extend(bits.clone());
+                lanes.push(bits.clone());
+            } else {
+                return Ok(None);
+            }
+        }
+    }
+    let mut dst: Vec<(String, u64)> = Vec::new();
+    for term in terms_from_expr(instr.dst()) {
+        dst.extend(names_try_from_term(&term)?);
+    }
+    let mut res: Vec<(String, Bits)> = Vec::new();
+    match instr.op() {
+        xir::OpBasc::Gnd | xir::OpBasc::Vcc => {
+            let value = if *instr.op() == xir::OpBasc::Vcc {
+                -1
+            } else {
+                0
+            };
+            for (name, width) in dst {
+                res.push((name, bits_from_val(width, value)));
+            }
+        }
+        xir::OpBasc::Id => {
+            for ((name, width), bits) in dst.into_iter().zip(lanes) {
+                res.push((name, bits.into_iter().take(width as usize).collect()));
+            }
+        }
+        xir::OpBasc::Ext => {
+            let index = instr.attr().get_val(0)?;
+            let bit = arg.get(usize::try_from(index)?).cloned();
+            if let (Some((name, _)), Some(bit)) = (dst.into_iter().next(), bit) {
+                res.push((name, vec![bit]));
+            } else {
+                return Err(Error::new_xpand_error("invalid ext instruction"));
+            }
+        }
+        xir::OpBasc::Cat => {
+            if dst.is_empty() || lanes.len() % dst.len() != 0 {
+                return Err(Error::new_xpand_error(
+                    "cat arguments can not be split into lanes",
+                ));
+            }
+            let chunk = lanes.len() / dst.len();
+            for ((name, _), lane) in dst.into_iter().zip(lanes.chunks(chunk)) {
+                res.push((name, lane.concat()));
+            }
+        }
+    }
+    Ok(Some(res))
+}
+
+fn lut_try_from_mach(builder: &mut Builder, instr: &xir::InstrMach) -> Result<(), Error> {
+    let init = instr.attr().get_val(0)? as u64;
+    macro_rules! cell {
+        ($ty:ty) => {{
+            let mut lut = <$ty>::default();
+            lut.set_param("INIT", init)?;
+            (
+                lut.prim.to_string(),
+                generics_from_param(&lut.param),
+                CellPort::new(lut.input.width().clone(), lut.output.width().clone()),
+            )
+        }};
+    }
+    let (ty, param, mut port) = match instr.op() {
+        xir::OpMach::Lut1 => cell!(lut::Lut1),
+        xir::OpMach::Lut2 => cell!(lut::Lut2),
+        xir::OpMach::Lut3 => cell!(lut::Lut3),
+        xir::OpMach::Lut4 => cell!(lut::Lut4),
+        xir::OpMach::Lut5 => cell!(lut::Lut5),
+        _ => cell!(lut::Lut6),
+    };
+    for (i, term) in terms_from_expr(instr.arg()).iter().enumerate() {
+        port.connect(&format!("I{}", i), builder.bits_try_from_term(term)?);
+    }
+    port.connect("O", builder.bits_try_from_term(instr.dst().get_term(0)?)?);
+    let name = inst_name_try_from_instr(instr)?;
+    builder.add_cell(&name, &ty, param, instr.loc(), port)?;
+    Ok(())
+}
+
+fn reg_try_from_mach(
+    builder: &mut Builder,
+    instr: &xir::InstrMach,
+    clock: &Clock,
+) -> Result<(), Error> {
+    macro_rules! cell {
+        ($ty:ty, $rst:expr, $arst:expr) => {{
+            let mut reg = <$ty>::default();
+            reg.set_reset_from_clock(clock)?;
+            let rst = if clock.is_async() { $arst } else { $rst };
+            let port = CellPort::new(reg.input.width().clone(), reg.output.width().clone());
+            (reg.prim, generics_from_param(&reg.param), port, rst)
+        }};
+    }
+    let (ty, param, mut port, rst) = if *instr.op() == xir::OpMach::Fdse {
+        cell!(Fdse, "S", "PRE")
+    } else {
+        cell!(Fdre, "R", "CLR")
+    };
+    port.connect("C", builder.clock.clone());
+    port.connect(rst, builder.reset.clone());
+    port.connect("D", builder.bits_try_from_term(instr.arg().get_term(0)?)?);
+    port.connect("CE", builder.bits_try_from_term(instr.arg().get_term(1)?)?);
+    port.connect("Q", builder.bits_try_from_term(instr.dst().get_term(0)?)?);
+    let name = inst_name_try_from_instr(instr)?;
+    builder.add_cell(&name, &ty, param, instr.loc(), port)?;
+    Ok(())
+}
+
+fn carry_try_from_mach(builder: &mut Builder, instr: &xir::InstrMach) -> Result<(), Error> {
+    let carry = Carry::default();
+    let mut param = StrMap::new();
+    for p in carry.param().iter() {
+        param.insert(p.name(), p.value().to_expr().to_string());
+    }
+    let mut port = CellPort::new(
+        width_from_port_set(carry.input()),
+        width_from_port_set(carry.output()),
+    );
+    // same carry in as carrysub_from_mach
+    if *instr.op() == xir::OpMach::CarrySub {
+        port.connect("CI", vec![Bit::one()]);
+    }
+    port.connect("DI", builder.bits_try_from_term(instr.arg().get_term(0)?)?);
+    port.connect("S", builder.bits_try_from_term(instr.arg().get_term(1)?)?);
+    port.connect("O", builder.bits_try_from_term(instr.dst().get_term(0)?)?);
+    let name = inst_name_try_from_instr(instr)?;
+    builder.add_cell(&name, &carry.name(), param, instr.loc(), port)?;
+    Ok(())
+}
+
+fn dsp_port_from_dsp(builder: &Builder, dsp: &Dsp) -> Result<CellPort, Error> {
+    let mut port = CellPort::new(dsp.input.width().clone(), dsp.output.width().clone());
+    port.connect("CLK", builder.clock.clone());
+    let reset = builder.reset.clone();
+    for p in dsp.input.width().keys().filter(|p| p.starts_with("RST")) {
+        port.connect(p, reset.clone());
+    }
+    Ok(port)
+}
+
+// lanes of dst are taken from P, every one starting at a word boundary
+fn dsp_output_try_from_mach(
+    builder: &mut Builder,
+    instr: &xir::InstrMach,
+    port: &mut CellPort,
+) -> Result<(), Error> {
+    let term = instr.dst().get_term(0)?;
+    let word = dsp::vec_word_width_try_from_term(term)? as u64;
+    let mut p = Bits::new();
+    for (name, width) in names_try_from_term(term)? {
+        p.extend(builder.get_bits(&name)?);
+        let pad = builder.new_bits(word - width);
+        p.extend(pad);
+    }
+    port.connect("P", p);
+    Ok(())
+}
+
+fn vadd_try_from_mach(
+    builder: &mut Builder,
+    instr: &xir::InstrMach,
+    clock: &Clock,
+) -> Result<(), Error> {
+    let reg = *instr.op() == xir::OpMach::VecAddRegA;
+    let dsp = dsp::vadd_dsp_try_from_mach(instr, reg, clock)?;
+    let mut port = dsp_port_from_dsp(builder, &dsp)?;
+    port.connect("OPMODE", bits_from_val(9, dsp::VADD_OPMODE));
+    if *instr.op() == xir::OpMach::VecSub {
+        port.connect("ALUMODE", bits_from_val(4, dsp::VSUB_ALUMODE));
+    }
+    // C + A:B
+    let c = builder.lane_bits_try_from_term(instr.arg().get_term(0)?)?;
+    port.connect("C", c);
+    let ab = builder.lane_bits_try_from_term(instr.arg().get_term(1)?)?;
+    let b_width = dsp.get_input_width("B").map_or(18, |w| *w as usize);
+    let (b, a) = ab.split_at(b_width.min(ab.len()));
+    port.connect("B", b.to_vec());
+    port.connect("A", a.to_vec());
+    if reg {
+        let cec = builder.bits_try_from_term(instr.arg().get_term(2)?)?;
+        let ceab = builder.bits_try_from_term(instr.arg().get_term(3)?)?;
+        let cep = builder.bits_try_from_term(instr.arg().get_term(4)?)?;
+        port.connect("CEC", cec);
+        port.connect_all(&["CEA1", "CEA2", "CEB1", "CEB2"], &ceab);
+        port.connect("CEP", cep);
+    }
+    dsp_output_try_from_mach(builder, instr, &mut port)?;
+    let param = generics_from_param(&dsp.param);
+    builder.add_cell(&dsp.name, &dsp.prim, param, instr.loc(), port)?;
+    Ok(())
+}
+
+fn mul_try_from_mach(
+    builder: &mut Builder,
+    instr: &xir::InstrMach,
+    cascade: &dsp::ACascade,
+    clock: &Clock,
+) -> Result<(), Error> {
+    let (addend, reg, pcout) = if let Some(conf) = dsp::mul_conf_from_op(instr.op()) {
+        conf
+    } else {
+        return Err(Error::new_xpand_error("not a multiply instruction"));
+    };
+    let dst = instr.dst().get_term(0)?;
+    if *instr.op() == xir::OpMach::VecMul && dst.length().map_or(false, |l| l > 1) {
+        return Err(Error::new_xpand_error(
+            "vector multiply must have only one lane",
+        ));
+    }
+    let acin = cascade.is_acin(instr);
+    let dsp = dsp::mul_dsp_try_from_mach(instr, reg, acin, clock)?;
+    let mut port = dsp_port_from_dsp(builder, &dsp)?;
+    port.connect("OPMODE", bits_from_val(9, addend.opmode()));
+    if acin {
+        let acin = dsp::a_cascade_name_try_from_term(instr.arg().get_term(2)?)?;
+        port.connect("ACIN", builder.get_bits(&acin)?);
+    } else {
+        let a = builder.lane_bits_try_from_term(instr.arg().get_term(0)?)?;
+        port.connect("A", a);
+    }
+    let b = builder.lane_bits_try_from_term(instr.arg().get_term(1)?)?;
+    port.connect("B", b);
+    match addend {
+        Addend::Fabric => {
+            let c = builder.lane_bits_try_from_term(instr.arg().get_term(2)?)?;
+            port.connect("C", c);
+        }
+        Addend::Cascade => {
+            let pcin = dsp::cascade_name_try_from_term(instr.arg().get_term(2)?)?;
+            port.connect("PCIN", builder.get_bits(&pcin)?);
+        }
+        Addend::None => (),
+    }
+    if reg {
+        let cea = builder.bits_try_from_term(instr.arg().get_term(3)?)?;
+        let ceb = builder.bits_try_from_term(instr.arg().get_term(4)?)?;
+        let cem = builder.bits_try_from_term(instr.arg().get_term(5)?)?;
+        let cep = builder.bits_try_from_term(instr.arg().get_term(6)?)?;
+        port.connect_all(&["CEA1", "CEA2"], &cea);
+        port.connect_all(&["CEB1", "CEB2"], &ceb);
+        port.connect("CEM", cem);
+        port.connect("CEP", cep);
+    }
+    if pcout {
+        let pcout = dsp::cascade_name_try_from_term(dst)?;
+        port.connect("PCOUT", builder.get_bits(&pcout)?);
+    }
+    if cascade.is_acout(instr) {
+        let acout = dsp::a_cascade_name_try_from_term(dst)?;
+        port.connect("ACOUT", builder.get_bits(&acout)?);
+    }
+    dsp_output_try_from_mach(builder, instr, &mut port)?;
+    let param = generics_from_param(&dsp.param);
+    builder.add_cell(&dsp.name, &dsp.prim, param, instr.loc(), port)?;
+    Ok(())
+}
+
+// address of width bits, where the first shift bits are zero
+fn addr_try_from_term(
+    builder: &Builder,
+    term: &xir::ExprTerm,
+    shift: u64,
+    width: u64,
+) -> Result<Bits, Error> {
+    let mut bits = bits_from_val(shift, 0);
+    bits.extend(builder.bits_try_from_term(term)?);
+    bits.resize(width as usize, Bit::zero());
+    Ok(bits)
+}
+
+fn bram_try_from_mach(
+    builder: &mut Builder,
+    instr: &xir::InstrMach,
+    clock: &Clock,
+) -> Result<(), Error> {
+    let (bram, conf) = ramb::bram_prim_try_from_mach(instr, clock)?;
+    let mut param = StrMap::new();
+    for p in bram.param().iter() {
+        param.insert(p.name(), ramb::expr_from_bram_param(p).to_string());
+    }
+    let mut port = CellPort::new(
+        width_from_port_set(bram.input()),
+        width_from_port_set(bram.output()),
+    );
+    let clock = builder.clock.clone();
+    let reset = builder.reset.clone();
+    port.connect_all(&["CLKARDCLK", "CLKBWRCLK"], &clock);
+    port.connect_all(&ramb::BRAM_RESET, &reset);
+    port.connect_all(&["ENARDEN", "ENBWREN", "ADDRENA", "ADDRENB"], &[Bit::one()]);
+    let raddr = addr_try_from_term(builder, instr.arg().get_term(0)?, conf.shift, 14)?;
+    let waddr = addr_try_from_term(builder, instr.arg().get_term(1)?, conf.shift, 14)?;
+    port.connect("ADDRARDADDR", raddr);
+    port.connect("ADDRBWRADDR", waddr);
+    let wdata = instr.arg().get_term(2)?;
+    let din: Bits = (0..conf.data)
+        .map(|i| builder.bit_try_from_term(wdata, i))
+        .collect::<Result<Bits, Error>>()?;
+    let dinp: Bits = (conf.data..conf.data + conf.parity)
+        .map(|i| builder.bit_try_from_term(wdata, i))
+        .collect::<Result<Bits, Error>>()?;
+    port.connect("DINBDIN", din);
+    port.connect("DINPBDINP", dinp);
+    let we = builder.bit_try_from_term(instr.arg().get_term(3)?, 0)?;
+    port.connect("WEBWE", vec![we.clone(), we, Bit::zero(), Bit::zero()]);
+    // dst takes data bits first and parity bits after them
+    let dst = builder.bits_try_from_term(instr.dst().get_term(0)?)?;
+    let split = (conf.data as usize).min(dst.len());
+    port.connect("DOUTADOUT", dst[..split].to_vec());
+    port.connect("DOUTPADOUTP", dst[split..].to_vec());
+    let name = inst_name_try_from_instr(instr)?;
+    builder.add_cell(&name, &bram.name(), param, instr.loc(), port)?;
+    Ok(())
+}
+
+fn uram_try_from_mach(
+    builder: &mut Builder,
+    instr: &xir::InstrMach,
+    clock: &Clock,
+) -> Result<(), Error> {
+    let ram = uram::uram_prim_try_from_mach(instr, clock)?;
+    let mut param = StrMap::new();
+    for p in ram.param().iter() {
+        param.insert(p.name(), uram::expr_from_uram_param(p).to_string());
+    }
+    let mut port = CellPort::new(
+        width_from_port_set(ram.input()),
+        width_from_port_set(ram.output()),
+    );
+    port.connect("CLK", builder.clock.clone());
+    let reset = builder.reset.clone();
+    port.connect_all(&["RST_A", "RST_B"], &reset);
+    let raddr = addr_try_from_term(builder, instr.arg().get_term(0)?, 0, 23)?;
+    let waddr = addr_try_from_term(builder, instr.arg().get_term(1)?, 0, 23)?;
+    port.connect("ADDR_A", raddr);
+    port.connect("ADDR_B", waddr);
+    port.connect_all(&["EN_A", "RDB_WR_B"], &[Bit::one()]);
+    let we = builder.bit_try_from_term(instr.arg().get_term(3)?, 0)?;
+    port.connect("EN_B", vec![we]);
+    port.connect("BWE_B", bits_from_val(9, -1));
+    port.connect(
+        "DIN_B",
+        builder.bits_try_from_term(instr.arg().get_term(2)?)?,
+    );
+    port.connect(
+        "DOUT_A",
+        builder.bits_try_from_term(instr.dst().get_term(0)?)?,
+    );
+    let name = inst_name_try_from_instr(instr)?;
+    builder.add_cell(&name, &ram.name(), param, instr.loc(), port)?;
+    Ok(())
+}
+
+fn lram_try_from_mach(builder: &mut Builder, instr: &xir::InstrMach) -> Result<(), Error> {
+    let prims = lram::lram_prim_try_from_mach(instr)?;
+    let num = prims.len();
+    let dst = instr.dst().get_term(0)?;
+    let width = width_try_from_term(dst)?;
+    let raddr = addr_try_from_term(builder, instr.arg().get_term(0)?, 0, 6)?;
+    let waddr = addr_try_from_term(builder, instr.arg().get_term(1)?, 0, 6)?;
+    let wdata = instr.arg().get_term(2)?;
+    let we = builder.bit_try_from_term(instr.arg().get_term(3)?, 0)?;
+    let clock = builder.clock.clone();
+    for (k, ram) in prims.iter().enumerate() {
+        let mut param = StrMap::new();
+        for p in ram.param().iter() {
+            param.insert(p.name(), p.value().to_expr().to_string());
+        }
+        let mut port = CellPort::new(
+            width_from_port_set(ram.input()),
+            width_from_port_set(ram.output()),
+        );
+        port.connect("ADDRH", waddr.clone());
+        port.connect("WE", vec![we.clone()]);
+        port.connect("WCLK", clock.clone());
+        for (j, l) in lram::LUT.iter().enumerate() {
+            let b = (k as u64) * lram::LRAM_BITS + j as u64;
+            if b < width {
+                port.connect(
+                    &format!("DI{}", l),
+                    vec![builder.bit_try_from_term(wdata, b)?],
+                );
+                port.connect(&format!("ADDR{}", l), raddr.clone());
+                port.connect(
+                    &format!("DO{}", l),
+                    vec![builder.bit_try_from_term(dst, b)?],
+                );
+            }
+        }
+        let name = lram::lram_name_try_from_mach(instr, k, num)?;
+        builder.add_cell(&name, &ram.name(), param, instr.loc(), port)?;
+    }
+    Ok(())
+}
+
+fn cell_try_from_mach(
+    builder: &mut Builder,
+    instr: &xir::InstrMach,
+    cascade: &dsp::ACascade,
+    clock: &Clock,
+) -> Result<(), Error> {
+    match instr.op() {
+        xir::OpMach::Lut1
+        | xir::OpMach::Lut2
+        | xir::OpMach::Lut3
+        | xir::OpMach::Lut4
+        | xir::OpMach::Lut5
+        | xir::OpMach::Lut6 => lut_try_from_mach(builder, instr),
+        xir::OpMach::Fdre | xir::OpMach::Fdse => reg_try_from_mach(builder, instr, clock),
+        xir::OpMach::CarryAdd | xir::OpMach::CarrySub => carry_try_from_mach(builder, instr),
+        xir::OpMach::VecAddRegA | xir::OpMach::VecAdd | xir::OpMach::VecSub => {
+            vadd_try_from_mach(builder, instr, clock)
+        }
+        xir::OpMach::Lram => lram_try_from_mach(builder, instr),
+        xir::OpMach::Bram => bram_try_from_mach(builder, instr, clock),
+        xir::OpMach::Uram => uram_try_from_mach(builder, instr, clock),
+        _ => mul_try_from_mach(builder, instr, cascade, clock),
+    }
+}
+
+pub fn try_from_xir_prog(prog: &xir::Prog) -> Result<Netlist, Error> {
+    try_from_xir_prog_with_clock(prog, &Clock::default())
+}
+
+pub fn try_from_xir_prog_with_clock(prog: &xir::Prog, clock: &Clock) -> Result<Netlist, Error> {
+    try_from_xir_prog_with_device(prog, clock, None)
+}
+
+/// locations use the raw site names of the device, if there is one
+pub fn try_from_xir_prog_with_device(
+    prog: &xir::Prog,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Netlist, Error> {
+    check_async_reset(prog, clock)?;
+    let cascade = dsp::ACascade::try_from_prog(prog)?;
+    let mut builder = Builder::new(device);
+    // input ports, registers without reset have it tied to zero
+    let mut names = vec![clock.clock()];
+    if clock.has_reset() {
+        names.push(clock.reset());
+    }
+    for name in names.iter() {
+        let bits = builder.new_bits(1);
+        builder.add_port(name, "input", bits.clone());
+        builder.add_net(name, bits);
+    }
+    builder.clock = builder.get_bits(&clock.clock())?;
+    builder.reset = if clock.has_reset() {
+        builder.get_bits(&clock.reset())?
+    } else {
+        vec![Bit::zero()]
+    };
+    for term in terms_from_expr(prog.sig().input()) {
+        for (name, width) in names_try_from_term(&term)? {
+            let bits = builder.new_bits(width);
+            builder.add_port(&name, "input", bits.clone());
+            builder.add_net(&name, bits);
+        }
+    }
+    // machine instructions drive new nets, including the cascades
+    for instr in prog.body() {
+        if let Some(mach) = instr.mach() {
+            for term in terms_from_expr(mach.dst()) {
+                for (name, width) in names_try_from_term(&term)? {
+                    let bits = builder.new_bits(width);
+                    builder.add_net(&name, bits);
+                }
+            }
+            if matches!(
+                mach.op(),
+                xir::OpMach::MulAddRegACo | xir::OpMach::MulAddRegACio
+            ) {
+                let name = dsp::cascade_name_try_from_term(mach.dst().get_term(0)?)?;
+                let bits = builder.new_bits(48);
+                builder.add_net(&name, bits);
+            }
+            if cascade.is_acout(mach) {
+                let name = dsp::a_cascade_name_try_from_term(mach.dst().get_term(0)?)?;
+                let bits = builder.new_bits(30);
+                builder.add_net(&name, bits);
+            }
+        }
+    }
+    // wire instructions only rename bits, so they are resolved once
+    // all of their arguments are
+    let mut basc: Vec<&xir::InstrBasc> = Vec::new();
+    for instr in prog.body() {
+        if let xir::Instr::Basc(instr) = instr {
+            basc.push(instr);
+        }
+    }
+    while !basc.is_empty() {
+        let mut pending: Vec<&xir::InstrBasc> = Vec::new();
+        for instr in basc.iter() {
+            if let Some(nets) = bits_try_from_basc(&builder, instr)? {
+                for (name, bits) in nets {
+                    builder.add_net(&name, bits);
+                }
+            } else {
+                pending.push(instr);
+            }
+        }
+        if pending.len() == basc.len() {
+            return Err(Error::new_xpand_error(
+                "wire instructions use undefined or cyclic arguments",
+            ));
+        }
+        basc = pending;
+    }
+    // output ports
+    for term in terms_from_expr(prog.sig().output()) {
+        for (name, _) in names_try_from_term(&term)? {
+            let bits = builder.get_bits(&name)?;
+            builder.add_port(&name, "output", bits);
+        }
+    }
+    for instr in prog.body() {
+        if let Some(mach) = instr.mach() {
+            cell_try_from_mach(&mut builder, mach, &cascade, clock)?;
+        }
+    }
+    let mut netlist = Netlist {
+        creator: "reticle".to_string(),
+        ..Netlist::default()
+    };
+    netlist.modules.insert(prog.sig().id(), builder.module);
+    Ok(netlist)
+}
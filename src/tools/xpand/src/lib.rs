@@ -11,14 +11,20 @@ pub mod fdse;
 pub mod gnd;
 pub mod id;
 pub mod instance;
+pub mod json;
 pub mod loc;
 pub mod lram;
 pub mod lut;
 pub mod param;
 pub mod port;
+pub mod ram;
 pub mod ramb;
+pub mod site;
 pub mod to_verilog;
+pub mod uram;
 pub mod vcc;
+pub mod vhdl;
+pub mod xdc;
 
 use crate::decl::ToDecl;
 use crate::errors::Error;
@@ -29,15 +35,56 @@ use crate::vcc::Vcc;
 use bline::{
     input_try_from_sig, vec_expr_try_from_expr, vec_expr_try_from_term, wire_try_from_expr,
 };
+use device::ast::Device;
+use ir::clock::Clock;
 use prim::ultrascale::gnd::Gnd;
 use std::collections::HashSet;
 use std::convert::TryInto;
 use verilog::ast as vl;
 use xir::ast as xir;
 
-pub const CLOCK: &str = "clock";
-pub const RESET: &str = "reset";
+pub use ir::clock::{CLOCK, RESET};
 
+pub fn clock_expr_from_clock(clock: &Clock) -> vl::Expr {
+    vl::Expr::new_ref(&clock.clock())
+}
+
+// reset pins are tied to ground when there is no reset, active-low
+// resets are inverted by the primitives with their IS_*_INVERTED params
+pub fn reset_expr_from_clock(clock: &Clock) -> vl::Expr {
+    if clock.has_reset() {
+        vl::Expr::new_ref(&clock.reset())
+    } else {
+        create_literal(1, 0)
+    }
+}
+
+pub fn is_reset_inverted(clock: &Clock) -> bool {
+    clock.has_reset() && clock.is_active_low()
+}
+
+/// only fabric registers have an asynchronous reset, the registers of
+/// DSP48E2, RAMB18E2 and URAM288 are always synchronous
+pub fn check_async_reset(prog: &xir::Prog, clock: &Clock) -> Result<(), Error> {
+    if !clock.has_reset() || !clock.is_async() {
+        return Ok(());
+    }
+    for instr in prog.body() {
+        if let Some(instr) = instr.mach() {
+            let op = instr.op();
+            if op.is_dsp() || matches!(op, xir::OpMach::Bram | xir::OpMach::Uram) {
+                let err = format!(
+                    "{} does not support an asynchronous reset, only fabric registers do",
+                    op
+                );
+                return Err(Error::new_xpand_error(&err));
+            }
+        }
+    }
+    Ok(())
+}
+
+// two's complement literal, value is sign-extended past 64 bits
 pub fn create_literal(width: u64, value: i64) -> vl::Expr {
     use prim::ultrascale::gnd::GND;
     if width == 1 {
@@ -51,8 +98,7 @@ pub fn create_literal(width: u64, value: i64) -> vl::Expr {
     } else {
         let mut concat = vl::ExprConcat::default();
         for i in 0..width {
-            let i = i as i64;
-            let shift = value >> i;
+            let shift = value >> i.min(63);
             let mask = shift & 1;
             let is_one = mask == 1;
             if is_one {
@@ -90,18 +136,36 @@ fn inst_name_try_from_instr(instr: &xir::InstrMach) -> Result<vl::Id, Error> {
     Ok(format!("__{}", dst[0]))
 }
 
-fn stmt_from_mach(instr: &xir::InstrMach) -> Result<Vec<vl::Stmt>, Error> {
+fn stmt_from_mach(
+    instr: &xir::InstrMach,
+    cascade: &dsp::ACascade,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
     match instr.op() {
-        xir::OpMach::Lut2 => lut::lut2_from_mach(instr),
-        xir::OpMach::Lut3 => lut::lut3_from_mach(instr),
-        xir::OpMach::Lut4 => lut::lut4_from_mach(instr),
-        xir::OpMach::Lut5 => lut::lut5_from_mach(instr),
-        xir::OpMach::Lut6 => lut::lut6_from_mach(instr),
-        xir::OpMach::Fdre => fdre::fdre_from_mach(instr),
-        xir::OpMach::CarryAdd => carry::carryadd_from_mach(instr),
-        xir::OpMach::VecAddRegA => dsp::vaddrega_from_mach(instr),
-        xir::OpMach::MulAddRegA => dsp::muladdrega_from_mach(instr),
-        _ => Err(Error::new_xpand_error("unsupported machine instruction")),
+        xir::OpMach::Lut1 => lut::lut1_from_mach(instr, device),
+        xir::OpMach::Lut2 => lut::lut2_from_mach(instr, device),
+        xir::OpMach::Lut3 => lut::lut3_from_mach(instr, device),
+        xir::OpMach::Lut4 => lut::lut4_from_mach(instr, device),
+        xir::OpMach::Lut5 => lut::lut5_from_mach(instr, device),
+        xir::OpMach::Lut6 => lut::lut6_from_mach(instr, device),
+        xir::OpMach::Fdre => fdre::fdre_from_mach(instr, clock, device),
+        xir::OpMach::Fdse => fdse::fdse_from_mach(instr, clock, device),
+        xir::OpMach::CarryAdd => carry::carryadd_from_mach(instr, device),
+        xir::OpMach::CarrySub => carry::carrysub_from_mach(instr, device),
+        xir::OpMach::VecAddRegA => dsp::vaddrega_from_mach(instr, clock, device),
+        xir::OpMach::VecAdd => dsp::vadd_from_mach(instr, clock, device),
+        xir::OpMach::VecSub => dsp::vsub_from_mach(instr, clock, device),
+        xir::OpMach::VecMul => dsp::vmul_from_mach(instr, clock, device),
+        xir::OpMach::Mul => dsp::mul_from_mach(instr, clock, device),
+        xir::OpMach::MulAdd => dsp::muladd_from_mach(instr, clock, device),
+        xir::OpMach::MulAddRegA => dsp::muladdrega_from_mach(instr, clock, device),
+        xir::OpMach::MulAddRegACi => dsp::muladdregaci_from_mach(instr, cascade, clock, device),
+        xir::OpMach::MulAddRegACo => dsp::muladdregaco_from_mach(instr, cascade, clock, device),
+        xir::OpMach::MulAddRegACio => dsp::muladdregacio_from_mach(instr, cascade, clock, device),
+        xir::OpMach::Lram => lram::lram_from_mach(instr, clock, device),
+        xir::OpMach::Bram => ramb::bram_from_mach(instr, clock, device),
+        xir::OpMach::Uram => uram::uram_from_mach(instr, clock, device),
     }
 }
 
@@ -115,17 +179,37 @@ fn stmt_from_basc(instr: &xir::InstrBasc) -> Result<Vec<vl::Stmt>, Error> {
     }
 }
 
-fn stmt_from_instr(instr: &xir::Instr) -> Result<Vec<vl::Stmt>, Error> {
+fn stmt_from_instr(
+    instr: &xir::Instr,
+    cascade: &dsp::ACascade,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
     match instr {
         xir::Instr::Basc(basc) => Ok(stmt_from_basc(basc)?),
-        xir::Instr::Mach(mach) => Ok(stmt_from_mach(mach)?),
+        xir::Instr::Mach(mach) => Ok(stmt_from_mach(mach, cascade, clock, device)?),
     }
 }
 
 pub fn try_from_xir_prog(prog: &xir::Prog) -> Result<vl::Module, Error> {
+    try_from_xir_prog_with_clock(prog, &Clock::default())
+}
+
+pub fn try_from_xir_prog_with_clock(prog: &xir::Prog, clock: &Clock) -> Result<vl::Module, Error> {
+    try_from_xir_prog_with_device(prog, clock, None)
+}
+
+/// locations use the raw site names of the device, if there is one
+pub fn try_from_xir_prog_with_device(
+    prog: &xir::Prog,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<vl::Module, Error> {
+    check_async_reset(prog, clock)?;
+    let cascade = dsp::ACascade::try_from_prog(prog)?;
     let id = prog.sig().id();
     let mut module = vl::Module::new(&id);
-    let input = input_try_from_sig(prog.sig())?;
+    let input = input_try_from_sig(prog.sig(), clock)?;
     for i in input {
         module.add_port(i.clone());
     }
@@ -141,6 +225,24 @@ pub fn try_from_xir_prog(prog: &xir::Prog) -> Result<vl::Module, Error> {
                 if let Some(width) = dsp_outputs.get_width("P") {
                     decl.push(vl::Decl::new_wire(&name, u64::from(*width)));
                 }
+                if matches!(
+                    instr.op(),
+                    xir::OpMach::MulAddRegACo | xir::OpMach::MulAddRegACio
+                ) {
+                    let name = dsp::cascade_name_try_from_term(term)?;
+                    if let Some(width) = dsp_outputs.get_width("PCOUT") {
+                        decl.push(vl::Decl::new_wire(&name, u64::from(*width)));
+                    }
+                }
+                if cascade.is_acout(instr) {
+                    let name = dsp::a_cascade_name_try_from_term(term)?;
+                    if let Some(width) = dsp_outputs.get_width("ACOUT") {
+                        decl.push(vl::Decl::new_wire(&name, u64::from(*width)));
+                    }
+                }
+            }
+            if instr.op().is_ram() {
+                decl.extend(ram::tmp_decl_try_from_mach(instr)?);
             }
         }
     }
@@ -162,10 +264,21 @@ pub fn try_from_xir_prog(prog: &xir::Prog) -> Result<vl::Module, Error> {
     module.add_stmt(gnd.to_stmt());
     module.add_stmt(vcc.to_stmt());
     for i in prog.body() {
-        let stmt: Vec<vl::Stmt> = stmt_from_instr(i)?;
+        let stmt: Vec<vl::Stmt> = stmt_from_instr(i, &cascade, clock, device)?;
         for s in stmt {
             module.add_stmt(s);
         }
     }
     Ok(module)
 }
+
+// placement goes to a constraint file instead, so instances have no attributes
+pub fn prog_without_loc(prog: &xir::Prog) -> xir::Prog {
+    let mut prog = prog.clone();
+    for instr in prog.body_mut().iter_mut() {
+        if let xir::Instr::Mach(mach) = instr {
+            mach.clear_loc();
+        }
+    }
+    prog
+}
@@ -1,8 +1,13 @@
+use device::ast::Device;
+use device::helpers::prim_from_bel;
 use verilog::ast as vl;
 use xir::ast as xir;
 
 pub type BelCarry = xir::BelCarry;
 pub type BelDsp = xir::BelDsp;
+pub type BelLram = xir::BelLram;
+pub type BelBram = xir::BelBram;
+pub type BelUram = xir::BelUram;
 pub type BelLut = xir::BelLut;
 pub type BelReg = xir::BelReg;
 pub type Bel = xir::Bel;
@@ -64,33 +69,52 @@ fn string_from_bel_dsp(bel: &BelDsp) -> String {
     }
 }
 
-fn string_from_bel(bel: &Bel) -> String {
+// memories fill their whole site, so only the site is constrained
+pub fn string_from_bel(bel: &Bel) -> Option<String> {
     match bel {
-        Bel::Lut(lut) => string_from_bel_lut(lut),
-        Bel::Reg(reg) => string_from_bel_reg(reg),
-        Bel::Carry(carry) => string_from_bel_carry(carry),
-        Bel::Dsp(dsp) => string_from_bel_dsp(dsp),
+        Bel::Lut(lut) => Some(string_from_bel_lut(lut)),
+        Bel::Reg(reg) => Some(string_from_bel_reg(reg)),
+        Bel::Carry(carry) => Some(string_from_bel_carry(carry)),
+        Bel::Dsp(dsp) => Some(string_from_bel_dsp(dsp)),
+        Bel::Lram(_) | Bel::Bram(_) | Bel::Uram(_) => None,
     }
 }
 
-fn attr_stmt_from_bel(bel: &Bel) -> vl::AttributeTy {
-    let val = string_from_bel(bel);
-    vl::AttributeTy::new_stmt("BEL", &val)
+fn attr_stmt_from_bel(bel: &Bel) -> Option<vl::AttributeTy> {
+    string_from_bel(bel).map(|val| vl::AttributeTy::new_stmt("BEL", &val))
 }
 
-fn attr_stmt_from_loc(loc: &Loc) -> vl::AttributeTy {
-    let val = match loc.bel() {
+pub fn site_name_from_loc(loc: &Loc) -> String {
+    match loc.bel() {
         Bel::Dsp(_) => format!("DSP48E2_X{}Y{}", loc.x(), loc.y()),
+        Bel::Bram(_) => format!("RAMB18_X{}Y{}", loc.x(), loc.y()),
+        Bel::Uram(_) => format!("URAM288_X{}Y{}", loc.x(), loc.y()),
         _ => format!("SLICE_X{}Y{}", loc.x(), loc.y()),
-    };
-    vl::AttributeTy::new_stmt("LOC", &val)
+    }
+}
+
+// raw name of the site in the device, if there is one that has it
+pub fn site_name_from_device(loc: &Loc, device: Option<&Device>) -> String {
+    if let (Some(device), ExprCoord::Val(x), ExprCoord::Val(y)) = (device, loc.x(), loc.y()) {
+        if let Some(name) = device.raw_name(&prim_from_bel(loc.bel()), *x, *y) {
+            return name;
+        }
+    }
+    site_name_from_loc(loc)
 }
 
-pub fn attr_from_loc(loc: &Loc) -> vl::Attribute {
+pub fn site_name_from_mach(instr: &xir::InstrMach, device: Option<&Device>) -> String {
+    instr
+        .loc()
+        .map(|loc| site_name_from_device(loc, device))
+        .unwrap_or_default()
+}
+
+pub fn attr_from_loc(loc: &Loc, site: &str) -> vl::Attribute {
     let mut attr = vl::Attribute::default();
-    let site = attr_stmt_from_bel(loc.bel());
-    let bel = attr_stmt_from_loc(loc);
-    attr.add_attr(site);
-    attr.add_attr(bel);
+    if let Some(bel) = attr_stmt_from_bel(loc.bel()) {
+        attr.add_attr(bel);
+    }
+    attr.add_attr(vl::AttributeTy::new_stmt("LOC", site));
     attr
 }
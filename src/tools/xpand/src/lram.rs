@@ -1,13 +1,16 @@
-//use crate::errors::Error;
-//use crate::loc::Loc;
-//use crate::{inst_name_try_from_instr, vec_expr_try_from_expr};
-//use prim::ultrascale::carry::{Carry, CarryParam, CarryType};
-//use xir::ast as xir;
-
-use crate::to_verilog::{ToVerilogExpr, ToVerilogInstance};
+use crate::errors::Error;
+use crate::loc::{site_name_from_mach, Loc};
+use crate::ram::{
+    addr_try_from_term, bit_try_from_term, check_depth, init_try_from_instr, width_try_from_term,
+};
+use crate::to_verilog::{ExprMap, ToVerilogExpr, ToVerilogInstance};
+use crate::{clock_expr_from_clock, create_literal, inst_name_try_from_instr};
+use device::ast::Device;
+use ir::clock::Clock;
 use prim::ultrascale::lram::{Lram, LramParam};
-use prim::{ParamSet, PortSet};
+use prim::{ParamSet, PortSet, Validate};
 use verilog::ast as vl;
+use xir::ast as xir;
 
 impl ToVerilogExpr for LramParam {
     fn to_expr(&self) -> vl::Expr {
@@ -47,71 +50,156 @@ impl ToVerilogInstance<LramParam> for Lram {
     }
 }
 
-//#[derive(Clone, Debug)]
-//struct CarryAdd {
-//    pub prim: Carry,
-//    pub instr: xir::InstrMach,
-//}
-//
-//impl CarryAdd {
-//    pub fn new(instr: xir::InstrMach) -> Self {
-//        CarryAdd {
-//            prim: Carry::default(),
-//            instr,
-//        }
-//    }
-//}
-//
-//impl ToVerilogInstance<CarryParam> for CarryAdd {
-//    fn to_name(&self) -> String {
-//        inst_name_try_from_instr(&self.instr).unwrap()
-//    }
-//    fn to_prim(&self) -> String {
-//        self.prim.name()
-//    }
-//    fn to_param_set(&self) -> &ParamSet<CarryParam> {
-//        self.prim.param()
-//    }
-//    fn to_input_set(&self) -> &PortSet {
-//        self.prim.input()
-//    }
-//    fn to_output_set(&self) -> &PortSet {
-//        self.prim.output()
-//    }
-//    fn to_loc(&self) -> Option<&Loc> {
-//        self.instr.loc()
-//    }
-//    fn to_input_map(&self) -> ExprMap {
-//        let mut map = ExprMap::new();
-//        let arg: Vec<vl::Expr> = vec_expr_try_from_expr(self.instr.arg()).unwrap();
-//        for p in self.prim.input().iter() {
-//            let name = p.name();
-//            if name.as_str() == "CI" || name.as_str() == "CI_TOP" {
-//                map.insert(name, vl::Expr::new_ulit_bin(1, "0"));
-//            } else if name.as_str() == "DI" {
-//                map.insert(name, arg[0].clone());
-//            } else {
-//                map.insert(name, arg[1].clone());
-//            }
-//        }
-//        map
-//    }
-//    fn to_output_map(&self) -> ExprMap {
-//        let mut map = ExprMap::new();
-//        let dst: Vec<vl::Expr> = vec_expr_try_from_expr(self.instr.dst()).unwrap();
-//        for p in self.prim.output().iter() {
-//            let name = p.name();
-//            if name.as_str() == "O" {
-//                map.insert(name, dst[0].clone());
-//            } else {
-//                map.insert(name, vl::Expr::new_ref(""));
-//            }
-//        }
-//        map
-//    }
-//}
-//
-//pub fn carryadd_from_mach(instr: &xir::InstrMach) -> Result<Vec<vl::Stmt>, Error> {
-//    let carry = CarryAdd::new(instr.clone());
-//    Ok(carry.to_block())
-//}
+#[derive(Clone, Debug)]
+struct Ram64 {
+    pub prim: Lram,
+    pub name: String,
+    pub loc: Option<Loc>,
+    pub site: String,
+    pub input: ExprMap,
+    pub output: ExprMap,
+}
+
+impl ToVerilogInstance<LramParam> for Ram64 {
+    fn to_name(&self) -> String {
+        self.name.to_string()
+    }
+    fn to_prim(&self) -> String {
+        self.prim.name()
+    }
+    fn to_param_set(&self) -> &ParamSet<LramParam> {
+        self.prim.param()
+    }
+    fn to_input_set(&self) -> &PortSet {
+        self.prim.input()
+    }
+    fn to_output_set(&self) -> &PortSet {
+        self.prim.output()
+    }
+    fn to_loc(&self) -> Option<&Loc> {
+        self.loc.as_ref()
+    }
+    fn to_site(&self) -> String {
+        self.site.clone()
+    }
+    fn to_input_map(&self) -> ExprMap {
+        let mut map = ExprMap::new();
+        for p in self.prim.input().iter() {
+            let expr = if let Some(expr) = self.input.get(&p.name()) {
+                expr.clone()
+            } else {
+                create_literal(p.width() as u64, 0)
+            };
+            map.insert(p.name(), expr);
+        }
+        map
+    }
+    fn to_output_map(&self) -> ExprMap {
+        let mut map = ExprMap::new();
+        for p in self.prim.output().iter() {
+            let expr = if let Some(expr) = self.output.get(&p.name()) {
+                expr.clone()
+            } else {
+                vl::Expr::new_ref("")
+            };
+            map.insert(p.name(), expr);
+        }
+        map
+    }
+}
+
+const LRAM_DEPTH: u64 = 64;
+
+// the eighth lut is written and read through ADDRH, so every
+// RAM64M8 provides seven read ports
+pub const LRAM_BITS: u64 = 7;
+
+pub const LUT: [&str; 7] = ["A", "B", "C", "D", "E", "F", "G"];
+
+/// RAM64M8 primitives of instr with their init parameters, where
+/// instance k holds bits 7k to 7k + 6 of every word
+pub fn lram_prim_try_from_mach(instr: &xir::InstrMach) -> Result<Vec<Lram>, Error> {
+    let width = width_try_from_term(instr.dst().get_term(0)?)?;
+    check_depth(instr, LRAM_DEPTH)?;
+    let init = init_try_from_instr(instr, LRAM_DEPTH)?;
+    let num = (width + LRAM_BITS - 1) / LRAM_BITS;
+    if num > 1 && instr.loc().map_or(false, |l| l.is_placed()) {
+        return Err(Error::new_xpand_error(
+            "placed lram must be at most 7 bits wide",
+        ));
+    }
+    let mut prims: Vec<Lram> = Vec::new();
+    for k in 0..num {
+        let mut prim = Lram::default();
+        for (j, l) in LUT.iter().enumerate() {
+            let b = k * LRAM_BITS + j as u64;
+            if b < width {
+                let mut column: u64 = 0;
+                for (word, value) in init.iter().enumerate() {
+                    column |= (((value >> b) & 1) as u64) << word;
+                }
+                prim.set_param(&format!("INIT_{}", l), column.to_be_bytes().to_vec())
+                    .map_err(|e| Error::new_xpand_error(&e.to_string()))?;
+            }
+        }
+        prim.validate()
+            .map_err(|e| Error::new_xpand_error(&e.to_string()))?;
+        prims.push(prim);
+    }
+    Ok(prims)
+}
+
+/// name of RAM64M8 k out of num
+pub fn lram_name_try_from_mach(
+    instr: &xir::InstrMach,
+    k: usize,
+    num: usize,
+) -> Result<String, Error> {
+    let name = inst_name_try_from_instr(instr)?;
+    if num > 1 {
+        Ok(format!("{}_{}", name, k))
+    } else {
+        Ok(name)
+    }
+}
+
+/// simple dual port memory made of RAM64M8, one per seven bits of data
+pub fn lram_from_mach(
+    instr: &xir::InstrMach,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    let dst = instr.dst().get_term(0)?;
+    let width = width_try_from_term(dst)?;
+    let prims = lram_prim_try_from_mach(instr)?;
+    let num = prims.len();
+    let raddr = instr.arg().get_term(0)?;
+    let waddr = instr.arg().get_term(1)?;
+    let wdata = instr.arg().get_term(2)?;
+    let mut stmt: Vec<vl::Stmt> = Vec::new();
+    for (k, prim) in prims.into_iter().enumerate() {
+        let mut input = ExprMap::new();
+        let mut output = ExprMap::new();
+        input.insert("ADDRH".into(), addr_try_from_term(waddr, 0, 6)?);
+        input.insert("WE".into(), bit_try_from_term(instr.arg().get_term(3)?, 0)?);
+        input.insert("WCLK".into(), clock_expr_from_clock(clock));
+        for (j, l) in LUT.iter().enumerate() {
+            let b = (k as u64) * LRAM_BITS + j as u64;
+            if b < width {
+                input.insert(format!("DI{}", l), bit_try_from_term(wdata, b)?);
+                input.insert(format!("ADDR{}", l), addr_try_from_term(raddr, 0, 6)?);
+                output.insert(format!("DO{}", l), bit_try_from_term(dst, b)?);
+            }
+        }
+        let ram = Ram64 {
+            prim,
+            name: lram_name_try_from_mach(instr, k, num)?,
+            loc: instr.loc().cloned(),
+            site: site_name_from_mach(instr, device),
+            input,
+            output,
+        };
+        stmt.extend(ram.to_block());
+    }
+    Ok(stmt)
+}
@@ -1,11 +1,12 @@
 use crate::errors::Error;
 use crate::expr::ToExpr;
 use crate::instance::ToInstance;
-use crate::loc::attr_from_loc;
+use crate::loc::{attr_from_loc, site_name_from_device};
 use crate::loc::{Bel, BelLut, ExprCoord, Loc};
 use crate::param::Param;
 use crate::port::{ConnectionMap, DefaultPort, Port, WidthMap};
 use crate::{create_literal, inst_name_try_from_instr, vec_expr_try_from_expr};
+use device::ast::Device;
 use verilog::ast as vl;
 use xir::ast as xir;
 
@@ -21,6 +22,7 @@ macro_rules! lut {
             pub name: String,
             pub prim: String,
             pub loc: Loc,
+            pub site: String,
             pub param: Param<$val>,
             pub input: Port,
             pub output: Port,
@@ -92,6 +94,7 @@ macro_rules! lut {
                     name: String::new(),
                     prim: $prim.to_string(),
                     loc,
+                    site: String::new(),
                     param: Param::<$val>::default(),
                     input: $ty::default_input_port(),
                     output: $ty::default_output_port(),
@@ -116,7 +119,7 @@ macro_rules! lut {
                     inst.connect(&k, v.clone());
                 }
                 if self.loc.is_placed() {
-                    let attr = attr_from_loc(&self.loc);
+                    let attr = attr_from_loc(&self.loc, &self.site);
                     inst.set_attr(attr);
                 }
                 inst
@@ -148,7 +151,8 @@ macro_rules! lut {
         }
 
         impl $ty {
-            pub fn set_loc(&mut self, loc: Loc) {
+            pub fn set_loc(&mut self, loc: Loc, device: Option<&Device>) {
+                self.site = site_name_from_device(&loc, device);
                 self.loc = loc;
             }
             pub fn set_param<P>(&mut self, name: &str, value: P) -> Result<(), Error>
@@ -160,12 +164,15 @@ macro_rules! lut {
             }
         }
 
-        pub fn $fn(instr: &xir::InstrMach) -> Result<Vec<vl::Stmt>, Error> {
+        pub fn $fn(
+            instr: &xir::InstrMach,
+            device: Option<&Device>,
+        ) -> Result<Vec<vl::Stmt>, Error> {
             let mut lut = $ty::default();
             let name = inst_name_try_from_instr(instr)?;
             lut.set_name(&name);
             if let Some(loc) = instr.loc() {
-                lut.set_loc(loc.clone());
+                lut.set_loc(loc.clone(), device);
             }
             let init = instr.attr().get_val(0)?;
             lut.set_param("INIT", init as u64)?;
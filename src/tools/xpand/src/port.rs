@@ -27,4 +27,12 @@ impl Port {
     pub fn connection(&self) -> &ConnectionMap {
         &self.connection
     }
+    pub fn rename(&mut self, port: &str, name: &str) {
+        if let Some(width) = self.width.remove(port) {
+            self.width.insert(name.to_string(), width);
+        }
+        if let Some(expr) = self.connection.remove(port) {
+            self.connection.insert(name.to_string(), expr);
+        }
+    }
 }
@@ -0,0 +1,142 @@
+use crate::errors::Error;
+use crate::{create_literal, tmp_name_try_from_term};
+use std::convert::TryFrom;
+use verilog::ast as vl;
+use xir::ast as xir;
+
+// Memories are simple dual port, y = ram[init](raddr, waddr, wdata, we).
+// Words are written on the clock edge when we is set, and reads of an
+// address being written return the old word. Reads take one cycle in
+// bram and uram, lram reads are asynchronous. The attributes are the
+// initial contents, one value per word starting from address zero.
+// Bram attributes start with the write mode instead, y = bram[mode, init],
+// where 0 is read first, 1 write first and 2 no change. Every memory is
+// a single primitive, so addresses can not be wider than its depth.
+
+pub fn width_try_from_term(term: &xir::ExprTerm) -> Result<u64, Error> {
+    if term.length().is_some() {
+        Err(Error::new_xpand_error(
+            "memories do not support vector types",
+        ))
+    } else if let Some(width) = term.width() {
+        Ok(width)
+    } else {
+        Err(Error::new_xpand_error("memory terms must have a width"))
+    }
+}
+
+// bit i of term, gnd past its width
+pub fn bit_try_from_term(term: &xir::ExprTerm, i: u64) -> Result<vl::Expr, Error> {
+    let width = width_try_from_term(term)?;
+    let name = String::try_from(term.clone())?;
+    if i >= width {
+        Ok(create_literal(1, 0))
+    } else if width == 1 {
+        Ok(vl::Expr::new_ref(&name))
+    } else {
+        Ok(vl::Expr::new_index_bit(&name, i32::try_from(i)?))
+    }
+}
+
+// concat of bits, lsb first
+pub fn expr_from_bits(bits: Vec<vl::Expr>) -> vl::Expr {
+    let mut concat = vl::ExprConcat::default();
+    for b in bits {
+        concat.add_expr(b);
+    }
+    vl::Expr::from(concat)
+}
+
+// width bits of term starting at lsb, gnd past its width
+pub fn bits_try_from_term(term: &xir::ExprTerm, lsb: u64, width: u64) -> Result<vl::Expr, Error> {
+    let mut bits: Vec<vl::Expr> = Vec::new();
+    for i in lsb..lsb + width {
+        bits.push(bit_try_from_term(term, i)?);
+    }
+    Ok(expr_from_bits(bits))
+}
+
+// address port of width bits, where the first shift bits are unused
+pub fn addr_try_from_term(term: &xir::ExprTerm, shift: u64, width: u64) -> Result<vl::Expr, Error> {
+    let mut bits: Vec<vl::Expr> = Vec::new();
+    for i in 0..width {
+        if i < shift {
+            bits.push(create_literal(1, 0));
+        } else {
+            bits.push(bit_try_from_term(term, i - shift)?);
+        }
+    }
+    Ok(expr_from_bits(bits))
+}
+
+// initial contents, checking they fit in depth words
+pub fn init_try_from_instr(instr: &xir::InstrMach, depth: u64) -> Result<Vec<i64>, Error> {
+    let mut init = Vec::<i64>::try_from(instr.attr().clone())?;
+    if *instr.op() == xir::OpMach::Bram && !init.is_empty() {
+        init.remove(0);
+    }
+    if init.len() as u64 > depth {
+        let err = format!(
+            "{} init values do not fit in a memory of {} words",
+            init.len(),
+            depth
+        );
+        Err(Error::new_xpand_error(&err))
+    } else {
+        Ok(init)
+    }
+}
+
+// check that raddr and waddr do not address more than depth words
+pub fn check_depth(instr: &xir::InstrMach, depth: u64) -> Result<(), Error> {
+    for i in 0..2 {
+        let width = width_try_from_term(instr.arg().get_term(i)?)?;
+        if width >= 64 || (1 << width) > depth {
+            let err = format!(
+                "{} bits of address do not fit in a memory of {} words",
+                width, depth
+            );
+            return Err(Error::new_xpand_error(&err));
+        }
+    }
+    Ok(())
+}
+
+// temporary wire with the data output of a memory
+pub fn tmp_name_try_from_instr(instr: &xir::InstrMach) -> Result<String, Error> {
+    tmp_name_try_from_term(instr.dst().get_term(0)?)
+}
+
+// temporary wire with the parity output of a memory
+pub fn tmp_parity_name_try_from_instr(instr: &xir::InstrMach) -> Result<String, Error> {
+    Ok(format!("{}_p", tmp_name_try_from_instr(instr)?))
+}
+
+// assign dst from bits of the memory outputs, lsb first
+pub fn assign_try_from_bits(
+    instr: &xir::InstrMach,
+    bits: Vec<vl::Expr>,
+) -> Result<vl::Stmt, Error> {
+    let dst = String::try_from(instr.dst().get_term(0)?.clone())?;
+    let assign = vl::Parallel::Assign(vl::Expr::new_ref(&dst), expr_from_bits(bits));
+    Ok(vl::Stmt::from(assign))
+}
+
+// declarations of the temporary wires used by a memory
+pub fn tmp_decl_try_from_mach(instr: &xir::InstrMach) -> Result<Vec<vl::Decl>, Error> {
+    let mut decl: Vec<vl::Decl> = Vec::new();
+    match instr.op() {
+        xir::OpMach::Bram => {
+            let name = tmp_name_try_from_instr(instr)?;
+            let parity = tmp_parity_name_try_from_instr(instr)?;
+            decl.push(vl::Decl::new_wire(&name, 16));
+            decl.push(vl::Decl::new_wire(&parity, 2));
+        }
+        xir::OpMach::Uram => {
+            let name = tmp_name_try_from_instr(instr)?;
+            decl.push(vl::Decl::new_wire(&name, 72));
+        }
+        _ => (),
+    }
+    Ok(decl)
+}
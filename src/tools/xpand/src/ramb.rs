@@ -1,13 +1,27 @@
 use crate::create_literal;
 use crate::errors::Error;
 use crate::expr::ToExpr;
+use crate::inst_name_try_from_instr;
 use crate::instance::ToInstance;
-use crate::loc::attr_from_loc;
+use crate::loc::{attr_from_loc, site_name_from_device, site_name_from_mach};
 use crate::loc::{Bel, BelReg, ExprCoord, Loc};
 use crate::param::Param;
 use crate::port::{ConnectionMap, DefaultPort, Port, WidthMap};
+use crate::ram::{
+    addr_try_from_term, assign_try_from_bits, bit_try_from_term, bits_try_from_term, check_depth,
+    expr_from_bits, init_try_from_instr, tmp_name_try_from_instr, tmp_parity_name_try_from_instr,
+    width_try_from_term,
+};
+use crate::to_verilog::{ExprMap, ToVerilogExpr, ToVerilogInstance};
+use crate::{clock_expr_from_clock, is_reset_inverted, reset_expr_from_clock};
 use derive_more::From;
+use device::ast::Device;
+use ir::clock::Clock;
+use prim::ultrascale::bram::{Bram, BramParam, ClockDomains as BramClockDomains, WriteMode};
+use prim::{Param as PrimParam, ParamSet, PortSet, Validate};
+use std::convert::TryFrom;
 use verilog::ast as vl;
+use xir::ast as xir;
 
 #[derive(Clone, Debug)]
 pub enum CascadeOrder {
@@ -35,6 +49,7 @@ pub struct Ramb18 {
     pub prim: String,
     pub param: Param<ParamValue>,
     pub loc: Loc,
+    pub site: String,
     pub input: Port,
     pub output: Port,
 }
@@ -125,6 +140,7 @@ impl Default for Ramb18 {
             name: String::new(),
             prim: "RAMB18E2".to_string(),
             loc,
+            site: String::new(),
             param: Param::<ParamValue>::default(),
             input: Ramb18::default_input_port(),
             output: Ramb18::default_output_port(),
@@ -133,7 +149,8 @@ impl Default for Ramb18 {
 }
 
 impl Ramb18 {
-    pub fn set_loc(&mut self, loc: Loc) {
+    pub fn set_loc(&mut self, loc: Loc, device: Option<&Device>) {
+        self.site = site_name_from_device(&loc, device);
         self.loc = loc;
     }
 }
@@ -155,7 +172,7 @@ impl ToInstance<ParamValue> for Ramb18 {
             inst.connect(&k, v.clone());
         }
         if self.loc.is_placed() {
-            let attr = attr_from_loc(&self.loc);
+            let attr = attr_from_loc(&self.loc, &self.site);
             inst.set_attr(attr);
         }
         inst
@@ -185,3 +202,276 @@ impl ToInstance<ParamValue> for Ramb18 {
         }
     }
 }
+
+// RAMB18E2 widths, data and parity bits of each, and address shift
+const BRAM_CONF: [(u64, u64, u64, u64); 5] = [
+    (1, 1, 0, 0),
+    (2, 2, 0, 1),
+    (4, 4, 0, 2),
+    (9, 8, 1, 3),
+    (18, 16, 2, 4),
+];
+
+const BRAM_BITS: u64 = 16384;
+
+const BRAM_PARITY_BITS: u64 = 2048;
+
+// reset pins, all of them use the reset of the clock
+pub const BRAM_RESET: [&str; 4] = ["RSTRAMARSTRAM", "RSTRAMB", "RSTREGARSTREG", "RSTREGB"];
+
+impl ToVerilogExpr for BramParam {
+    fn to_expr(&self) -> vl::Expr {
+        match self {
+            BramParam::Bool(b) => vl::Expr::new_str(&b.to_string().to_uppercase()),
+            BramParam::Bytes(bytes) => {
+                let width = (bytes.len() as u32) * 8;
+                vl::Expr::new_ulit_hex(width, &hex_from_bytes(bytes))
+            }
+            BramParam::I64(v) => vl::Expr::new_int(*v as i32),
+            _ => vl::Expr::new_str(&self.to_string()),
+        }
+    }
+}
+
+fn hex_from_bytes(bytes: &[u8]) -> String {
+    let mut v = String::new();
+    for b in bytes.iter() {
+        v.push_str(&format!("{:02X}", b));
+    }
+    v
+}
+
+// register stages are integers and inverted pins are sized literals
+pub fn expr_from_bram_param(param: &PrimParam<BramParam>) -> vl::Expr {
+    match (param.value(), param.width()) {
+        (BramParam::Bool(b), Some(w)) => vl::Expr::new_ulit_bin(w, &u32::from(*b).to_string()),
+        (BramParam::Bool(b), None) if param.name().ends_with("_REG") => {
+            vl::Expr::new_int(i32::from(*b))
+        }
+        (BramParam::I64(v), Some(w)) => vl::Expr::new_ulit_hex(w, &format!("{:x}", v)),
+        (value, _) => value.to_expr(),
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Bram18 {
+    pub prim: Bram,
+    pub instr: xir::InstrMach,
+    pub site: String,
+    pub input: ExprMap,
+    pub output: ExprMap,
+}
+
+impl ToVerilogInstance<BramParam> for Bram18 {
+    fn to_name(&self) -> String {
+        inst_name_try_from_instr(&self.instr).unwrap()
+    }
+    fn to_prim(&self) -> String {
+        self.prim.name()
+    }
+    fn to_param_set(&self) -> &ParamSet<BramParam> {
+        self.prim.param()
+    }
+    fn to_input_set(&self) -> &PortSet {
+        self.prim.input()
+    }
+    fn to_output_set(&self) -> &PortSet {
+        self.prim.output()
+    }
+    fn to_loc(&self) -> Option<&Loc> {
+        self.instr.loc()
+    }
+    fn to_site(&self) -> String {
+        self.site.clone()
+    }
+    fn to_param_map(&self) -> ExprMap {
+        let mut map = ExprMap::new();
+        for p in self.prim.param().iter() {
+            map.insert(p.name(), expr_from_bram_param(p));
+        }
+        map
+    }
+    fn to_input_map(&self) -> ExprMap {
+        let mut map = ExprMap::new();
+        for p in self.prim.input().iter() {
+            let expr = if let Some(expr) = self.input.get(&p.name()) {
+                expr.clone()
+            } else {
+                create_literal(p.width() as u64, 0)
+            };
+            map.insert(p.name(), expr);
+        }
+        map
+    }
+    fn to_output_map(&self) -> ExprMap {
+        let mut map = ExprMap::new();
+        for p in self.prim.output().iter() {
+            let expr = if let Some(expr) = self.output.get(&p.name()) {
+                expr.clone()
+            } else {
+                vl::Expr::new_ref("")
+            };
+            map.insert(p.name(), expr);
+        }
+        map
+    }
+}
+
+fn set_bram_param<P: Into<BramParam>>(bram: &mut Bram, name: &str, value: P) -> Result<(), Error> {
+    bram.set_param(name, value)
+        .map_err(|e| Error::new_xpand_error(&e.to_string()))
+}
+
+// rows of 256 bits, most significant byte first
+fn rows_from_bits(bits: &[u8]) -> Vec<Vec<u8>> {
+    bits.chunks(32)
+        .map(|row| row.iter().rev().cloned().collect())
+        .collect()
+}
+
+/// width configuration of a bram: port width, data bits, parity bits
+/// and address shift
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BramConf {
+    pub width: u64,
+    pub data: u64,
+    pub parity: u64,
+    pub shift: u64,
+}
+
+// first attribute of a bram, read first if there are none
+fn write_mode_try_from_instr(instr: &xir::InstrMach) -> Result<WriteMode, Error> {
+    let attr = Vec::<i64>::try_from(instr.attr().clone())?;
+    match attr.first() {
+        None | Some(0) => Ok(WriteMode::ReadFirst),
+        Some(1) => Ok(WriteMode::WriteFirst),
+        Some(2) => Ok(WriteMode::NoChange),
+        Some(mode) => {
+            let err = format!("{} is not a valid bram write mode", mode);
+            Err(Error::new_xpand_error(&err))
+        }
+    }
+}
+
+/// RAMB18E2 with the width, write mode, reset and init parameters of
+/// instr, before any port is connected
+pub fn bram_prim_try_from_mach(
+    instr: &xir::InstrMach,
+    clock: &Clock,
+) -> Result<(Bram, BramConf), Error> {
+    let width = width_try_from_term(instr.dst().get_term(0)?)?;
+    let conf = BRAM_CONF.iter().find(|c| c.0 >= width);
+    let conf = if let Some((w, data, parity, shift)) = conf {
+        BramConf {
+            width: *w,
+            data: *data,
+            parity: *parity,
+            shift: *shift,
+        }
+    } else {
+        return Err(Error::new_xpand_error(
+            "bram words must be at most 18 bits wide",
+        ));
+    };
+    let (data, parity) = (conf.data, conf.parity);
+    let depth = BRAM_BITS >> conf.shift;
+    check_depth(instr, depth)?;
+    let init = init_try_from_instr(instr, depth)?;
+    let mut bram = Bram::default();
+    set_bram_param(&mut bram, "CLOCK_DOMAINS", BramClockDomains::Common)?;
+    set_bram_param(&mut bram, "READ_WIDTH_A", conf.width as i64)?;
+    set_bram_param(&mut bram, "WRITE_WIDTH_B", conf.width as i64)?;
+    let mode = write_mode_try_from_instr(instr)?;
+    set_bram_param(&mut bram, "WRITE_MODE_A", mode.clone())?;
+    set_bram_param(&mut bram, "WRITE_MODE_B", mode)?;
+    if is_reset_inverted(clock) {
+        for p in BRAM_RESET.iter() {
+            set_bram_param(&mut bram, &format!("IS_{}_INVERTED", p), true)?;
+        }
+    }
+    // initial contents, data and parity bits are stored apart
+    let mut data_bits = vec![0u8; (BRAM_BITS / 8) as usize];
+    let mut parity_bits = vec![0u8; (BRAM_PARITY_BITS / 8) as usize];
+    for (word, value) in init.iter().enumerate() {
+        let word = word as u64;
+        for i in 0..width {
+            if (value >> i) & 1 == 1 {
+                let (bits, index) = if i < data {
+                    (&mut data_bits, word * data + i)
+                } else {
+                    (&mut parity_bits, word * parity + i - data)
+                };
+                bits[(index / 8) as usize] |= 1 << (index % 8);
+            }
+        }
+    }
+    for (i, row) in rows_from_bits(&data_bits).into_iter().enumerate() {
+        set_bram_param(&mut bram, &format!("INIT_{:02X}", i), row)?;
+    }
+    for (i, row) in rows_from_bits(&parity_bits).into_iter().enumerate() {
+        set_bram_param(&mut bram, &format!("INITP_{:02X}", i), row)?;
+    }
+    bram.validate()
+        .map_err(|e| Error::new_xpand_error(&e.to_string()))?;
+    Ok((bram, conf))
+}
+
+/// simple dual port RAMB18E2, reading from port A and writing to port B
+pub fn bram_from_mach(
+    instr: &xir::InstrMach,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    let width = width_try_from_term(instr.dst().get_term(0)?)?;
+    let (bram, conf) = bram_prim_try_from_mach(instr, clock)?;
+    let (data, parity, shift) = (conf.data, conf.parity, conf.shift);
+    // input
+    let raddr = instr.arg().get_term(0)?;
+    let waddr = instr.arg().get_term(1)?;
+    let wdata = instr.arg().get_term(2)?;
+    let we = bit_try_from_term(instr.arg().get_term(3)?, 0)?;
+    let mut input = ExprMap::new();
+    input.insert("CLKARDCLK".into(), clock_expr_from_clock(clock));
+    input.insert("CLKBWRCLK".into(), clock_expr_from_clock(clock));
+    for p in BRAM_RESET.iter() {
+        input.insert(p.to_string(), reset_expr_from_clock(clock));
+    }
+    input.insert("ENARDEN".into(), create_literal(1, 1));
+    input.insert("ENBWREN".into(), create_literal(1, 1));
+    input.insert("ADDRENA".into(), create_literal(1, 1));
+    input.insert("ADDRENB".into(), create_literal(1, 1));
+    input.insert("ADDRARDADDR".into(), addr_try_from_term(raddr, shift, 14)?);
+    input.insert("ADDRBWRADDR".into(), addr_try_from_term(waddr, shift, 14)?);
+    input.insert("DINBDIN".into(), bits_try_from_term(wdata, 0, data)?);
+    if parity > 0 {
+        input.insert("DINPBDINP".into(), bits_try_from_term(wdata, data, parity)?);
+    }
+    let gnd = create_literal(1, 0);
+    let webwe = vec![we.clone(), we, gnd.clone(), gnd];
+    input.insert("WEBWE".into(), expr_from_bits(webwe));
+    // output
+    let name = tmp_name_try_from_instr(instr)?;
+    let parity_name = tmp_parity_name_try_from_instr(instr)?;
+    let mut output = ExprMap::new();
+    output.insert("DOUTADOUT".into(), vl::Expr::new_ref(&name));
+    output.insert("DOUTPADOUTP".into(), vl::Expr::new_ref(&parity_name));
+    let ram = Bram18 {
+        prim: bram,
+        instr: instr.clone(),
+        site: site_name_from_mach(instr, device),
+        input,
+        output,
+    };
+    let mut bits: Vec<vl::Expr> = Vec::new();
+    for i in 0..width {
+        let i = i32::try_from(i)?;
+        if i < data as i32 {
+            bits.push(vl::Expr::new_index_bit(&name, i));
+        } else {
+            bits.push(vl::Expr::new_index_bit(&parity_name, i - data as i32));
+        }
+    }
+    let mut stmt = ram.to_block();
+    stmt.push(assign_try_from_bits(instr, bits)?);
+    Ok(stmt)
+}
@@ -0,0 +1,15 @@
+use crate::errors::Error;
+use device::ast::Device;
+use xir::ast as xir;
+
+pub fn check_prog(prog: &xir::Prog, device: &Device) -> Result<(), Error> {
+    for instr in prog.body() {
+        if let Some(mach) = instr.mach() {
+            if let Some(loc) = mach.loc() {
+                let id = mach.dst().get_id(0)?;
+                device.check_loc(&id, loc)?;
+            }
+        }
+    }
+    Ok(())
+}
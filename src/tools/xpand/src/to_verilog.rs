@@ -1,5 +1,5 @@
 use crate::create_literal;
-use crate::loc::{attr_from_loc, Loc};
+use crate::loc::{attr_from_loc, site_name_from_loc, Loc};
 use prim::{ParamSet, PortSet};
 use std::collections::HashMap;
 use verilog::ast as vl;
@@ -41,6 +41,10 @@ pub trait ToVerilogInstance<T: ToVerilogExpr> {
     fn to_loc(&self) -> Option<&Loc> {
         None
     }
+    /// name of the site of the location
+    fn to_site(&self) -> String {
+        self.to_loc().map(site_name_from_loc).unwrap_or_default()
+    }
     /// parameter map
     fn to_param_map(&self) -> ExprMap {
         let mut map = ExprMap::new();
@@ -82,7 +86,7 @@ pub trait ToVerilogInstance<T: ToVerilogExpr> {
         }
         if let Some(loc) = self.to_loc() {
             if loc.is_placed() {
-                let attr = attr_from_loc(&loc);
+                let attr = attr_from_loc(&loc, &self.to_site());
                 inst.set_attr(attr);
             }
         }
@@ -0,0 +1,176 @@
+use crate::errors::Error;
+use crate::inst_name_try_from_instr;
+use crate::loc::{site_name_from_mach, Loc};
+use crate::ram::{
+    addr_try_from_term, assign_try_from_bits, bit_try_from_term, bits_try_from_term, check_depth,
+    init_try_from_instr, tmp_name_try_from_instr, width_try_from_term,
+};
+use crate::to_verilog::{ExprMap, ToVerilogExpr, ToVerilogInstance};
+use crate::{clock_expr_from_clock, create_literal, is_reset_inverted, reset_expr_from_clock};
+use device::ast::Device;
+use ir::clock::Clock;
+use prim::ultrascale::uram::{Uram, UramParam};
+use prim::{Param as PrimParam, ParamSet, PortSet, Validate};
+use std::convert::TryFrom;
+use verilog::ast as vl;
+use xir::ast as xir;
+
+const URAM_DEPTH: u64 = 4096;
+
+pub const URAM_WIDTH: u64 = 72;
+
+impl ToVerilogExpr for UramParam {
+    fn to_expr(&self) -> vl::Expr {
+        match self {
+            UramParam::Bool(b) => vl::Expr::new_str(&b.to_string().to_uppercase()),
+            UramParam::I64(v) => vl::Expr::new_int(*v as i32),
+            _ => vl::Expr::new_str(&self.to_string()),
+        }
+    }
+}
+
+// inverted pins and self addresses are sized literals
+pub fn expr_from_uram_param(param: &PrimParam<UramParam>) -> vl::Expr {
+    match (param.value(), param.width()) {
+        (UramParam::Bool(b), Some(w)) => vl::Expr::new_ulit_bin(w, &u32::from(*b).to_string()),
+        (UramParam::I64(v), Some(w)) => vl::Expr::new_ulit_hex(w, &format!("{:x}", v)),
+        (value, _) => value.to_expr(),
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Uram288 {
+    pub prim: Uram,
+    pub instr: xir::InstrMach,
+    pub site: String,
+    pub input: ExprMap,
+    pub output: ExprMap,
+}
+
+impl ToVerilogInstance<UramParam> for Uram288 {
+    fn to_name(&self) -> String {
+        inst_name_try_from_instr(&self.instr).unwrap()
+    }
+    fn to_prim(&self) -> String {
+        self.prim.name()
+    }
+    fn to_param_set(&self) -> &ParamSet<UramParam> {
+        self.prim.param()
+    }
+    fn to_input_set(&self) -> &PortSet {
+        self.prim.input()
+    }
+    fn to_output_set(&self) -> &PortSet {
+        self.prim.output()
+    }
+    fn to_loc(&self) -> Option<&Loc> {
+        self.instr.loc()
+    }
+    fn to_site(&self) -> String {
+        self.site.clone()
+    }
+    fn to_param_map(&self) -> ExprMap {
+        let mut map = ExprMap::new();
+        for p in self.prim.param().iter() {
+            map.insert(p.name(), expr_from_uram_param(p));
+        }
+        map
+    }
+    fn to_input_map(&self) -> ExprMap {
+        let mut map = ExprMap::new();
+        for p in self.prim.input().iter() {
+            let expr = if let Some(expr) = self.input.get(&p.name()) {
+                expr.clone()
+            } else {
+                create_literal(p.width() as u64, 0)
+            };
+            map.insert(p.name(), expr);
+        }
+        map
+    }
+    fn to_output_map(&self) -> ExprMap {
+        let mut map = ExprMap::new();
+        for p in self.prim.output().iter() {
+            let expr = if let Some(expr) = self.output.get(&p.name()) {
+                expr.clone()
+            } else {
+                vl::Expr::new_ref("")
+            };
+            map.insert(p.name(), expr);
+        }
+        map
+    }
+}
+
+/// URAM288 for instr, which has no initial contents, so init values
+/// must be zero
+pub fn uram_prim_try_from_mach(instr: &xir::InstrMach, clock: &Clock) -> Result<Uram, Error> {
+    let width = width_try_from_term(instr.dst().get_term(0)?)?;
+    if width > URAM_WIDTH {
+        return Err(Error::new_xpand_error(
+            "uram words must be at most 72 bits wide",
+        ));
+    }
+    check_depth(instr, URAM_DEPTH)?;
+    let init = init_try_from_instr(instr, URAM_DEPTH)?;
+    if init.iter().any(|v| *v != 0) {
+        return Err(Error::new_xpand_error(
+            "uram does not support initial contents",
+        ));
+    }
+    let mut uram = Uram::default();
+    if is_reset_inverted(clock) {
+        for p in ["RST_A", "RST_B"].iter() {
+            uram.set_param(&format!("IS_{}_INVERTED", p), true)
+                .map_err(|e| Error::new_xpand_error(&e.to_string()))?;
+        }
+    }
+    uram.validate()
+        .map_err(|e| Error::new_xpand_error(&e.to_string()))?;
+    Ok(uram)
+}
+
+/// simple dual port URAM288, reading from port A and writing to port B
+pub fn uram_from_mach(
+    instr: &xir::InstrMach,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<Vec<vl::Stmt>, Error> {
+    let width = width_try_from_term(instr.dst().get_term(0)?)?;
+    let prim = uram_prim_try_from_mach(instr, clock)?;
+    let raddr = instr.arg().get_term(0)?;
+    let waddr = instr.arg().get_term(1)?;
+    let wdata = instr.arg().get_term(2)?;
+    let mut input = ExprMap::new();
+    input.insert("CLK".into(), clock_expr_from_clock(clock));
+    input.insert("RST_A".into(), reset_expr_from_clock(clock));
+    input.insert("RST_B".into(), reset_expr_from_clock(clock));
+    input.insert("ADDR_A".into(), addr_try_from_term(raddr, 0, 23)?);
+    input.insert("ADDR_B".into(), addr_try_from_term(waddr, 0, 23)?);
+    input.insert("EN_A".into(), create_literal(1, 1));
+    input.insert("RDB_WR_A".into(), create_literal(1, 0));
+    input.insert(
+        "EN_B".into(),
+        bit_try_from_term(instr.arg().get_term(3)?, 0)?,
+    );
+    input.insert("RDB_WR_B".into(), create_literal(1, 1));
+    input.insert("BWE_B".into(), create_literal(9, 0x1ff));
+    input.insert("DIN_B".into(), bits_try_from_term(wdata, 0, URAM_WIDTH)?);
+    let name = tmp_name_try_from_instr(instr)?;
+    let mut output = ExprMap::new();
+    output.insert("DOUT_A".into(), vl::Expr::new_ref(&name));
+    let ram = Uram288 {
+        prim,
+        instr: instr.clone(),
+        site: site_name_from_mach(instr, device),
+        input,
+        output,
+    };
+    let mut bits: Vec<vl::Expr> = Vec::new();
+    for i in 0..width {
+        bits.push(vl::Expr::new_index_bit(&name, i32::try_from(i)?));
+    }
+    let mut stmt = ram.to_block();
+    stmt.push(assign_try_from_bits(instr, bits)?);
+    Ok(stmt)
+}
@@ -0,0 +1,275 @@
+use crate::errors::Error;
+use crate::json::{self, Bit};
+use device::ast::Device;
+use ir::clock::Clock;
+use std::collections::{HashMap, HashSet};
+use vhdl::ast as vh;
+use xir::ast as xir;
+
+// Structural VHDL is written from the json netlist, so wires between
+// instructions are already resolved to shared bits. Every net bit gets
+// the first name that carries it: input ports, output ports and then
+// the other nets.
+
+#[derive(Clone, Debug)]
+struct Name {
+    id: String,
+    index: u64,
+    width: u64,
+}
+
+type NameMap = HashMap<u64, Name>;
+
+// consecutive bits of one signal or constants, lsb first
+#[derive(Clone, Debug)]
+enum Run {
+    Name(String, u64, u64, u64),
+    Const(Vec<bool>),
+}
+
+impl Run {
+    fn len(&self) -> u64 {
+        match self {
+            Run::Name(_, lsb, msb, _) => msb - lsb + 1,
+            Run::Const(bits) => bits.len() as u64,
+        }
+    }
+    fn to_expr(&self) -> vh::Expr {
+        match self {
+            Run::Name(id, lsb, msb, width) if *lsb == 0 && *msb + 1 == *width => {
+                vh::Expr::new_ref(id)
+            }
+            Run::Name(id, lsb, msb, _) if lsb == msb => vh::Expr::new_index(id, *lsb),
+            Run::Name(id, lsb, msb, _) => vh::Expr::new_slice(id, *msb, *lsb),
+            Run::Const(bits) if bits.len() == 1 => vh::Expr::new_logic(bits[0]),
+            Run::Const(bits) => {
+                let bits: String = bits
+                    .iter()
+                    .rev()
+                    .map(|b| if *b { '1' } else { '0' })
+                    .collect();
+                vh::Expr::Bits(bits)
+            }
+        }
+    }
+}
+
+// generic value from a verilog literal, e.g. 64'h1 or "TRUE"
+fn generic_try_from_literal(value: &str) -> Result<vh::Expr, Error> {
+    if value.starts_with('"') {
+        Ok(vh::Expr::Str(value.trim_matches('"').to_string()))
+    } else if let Some(index) = value.find('\'') {
+        let width = value[..index]
+            .parse::<usize>()
+            .map_err(|e| Error::new_xpand_error(&e.to_string()))?;
+        let mut rest = value[index + 1..].chars();
+        if let Some(radix) = rest.next() {
+            let bits = json::binary_from_digits(width, radix, rest.as_str())?;
+            if width == 1 {
+                Ok(vh::Expr::new_logic(bits == "1"))
+            } else {
+                Ok(vh::Expr::Bits(bits))
+            }
+        } else {
+            Err(Error::new_xpand_error("missing literal radix"))
+        }
+    } else {
+        let int = value
+            .parse::<i64>()
+            .map_err(|e| Error::new_xpand_error(&e.to_string()))?;
+        Ok(vh::Expr::Int(int))
+    }
+}
+
+// name the bits of id that do not have one yet, true if any did not
+fn add_name(names: &mut NameMap, id: &str, bits: &[Bit]) -> bool {
+    let mut named = false;
+    for (i, bit) in bits.iter().enumerate() {
+        if let Bit::Net(n) = bit {
+            if !names.contains_key(n) {
+                let name = Name {
+                    id: id.to_string(),
+                    index: i as u64,
+                    width: bits.len() as u64,
+                };
+                names.insert(*n, name);
+                named = true;
+            }
+        }
+    }
+    named
+}
+
+fn runs_try_from_bits(names: &NameMap, bits: &[Bit]) -> Result<Vec<Run>, Error> {
+    let mut runs: Vec<Run> = Vec::new();
+    for bit in bits.iter() {
+        match bit {
+            Bit::Net(n) => {
+                let name = if let Some(name) = names.get(n) {
+                    name
+                } else {
+                    let err = format!("net {} does not have a name", n);
+                    return Err(Error::new_xpand_error(&err));
+                };
+                if let Some(Run::Name(id, _, msb, _)) = runs.last_mut() {
+                    if *id == name.id && *msb + 1 == name.index {
+                        *msb += 1;
+                        continue;
+                    }
+                }
+                runs.push(Run::Name(
+                    name.id.to_string(),
+                    name.index,
+                    name.index,
+                    name.width,
+                ));
+            }
+            Bit::Const(value) => {
+                let value = value == "1";
+                if let Some(Run::Const(consts)) = runs.last_mut() {
+                    consts.push(value);
+                } else {
+                    runs.push(Run::Const(vec![value]));
+                }
+            }
+        }
+    }
+    Ok(runs)
+}
+
+// formal and actual pairs of the runs of port
+fn assoc_try_from_bits(
+    names: &NameMap,
+    port: &str,
+    bits: &[Bit],
+) -> Result<Vec<(vh::Expr, vh::Expr)>, Error> {
+    let width = bits.len() as u64;
+    let mut assoc: Vec<(vh::Expr, vh::Expr)> = Vec::new();
+    let mut lsb = 0;
+    for run in runs_try_from_bits(names, bits)? {
+        let len = run.len();
+        let formal = Run::Name(port.to_string(), lsb, lsb + len - 1, width).to_expr();
+        assoc.push((formal, run.to_expr()));
+        lsb += len;
+    }
+    Ok(assoc)
+}
+
+pub fn try_from_xir_prog(prog: &xir::Prog) -> Result<vh::Module, Error> {
+    try_from_xir_prog_with_clock(prog, &Clock::default())
+}
+
+pub fn try_from_xir_prog_with_clock(prog: &xir::Prog, clock: &Clock) -> Result<vh::Module, Error> {
+    try_from_xir_prog_with_device(prog, clock, None)
+}
+
+/// locations use the raw site names of the device, if there is one
+pub fn try_from_xir_prog_with_device(
+    prog: &xir::Prog,
+    clock: &Clock,
+    device: Option<&Device>,
+) -> Result<vh::Module, Error> {
+    let netlist = json::try_from_xir_prog_with_device(prog, clock, device)?;
+    let module = if let Some(module) = netlist.modules.get(&prog.sig().id()) {
+        module
+    } else {
+        return Err(Error::new_xpand_error("netlist module not found"));
+    };
+    let mut input: Vec<String> = vec![clock.clock()];
+    if clock.has_reset() {
+        input.push(clock.reset());
+    }
+    for term in json::terms_from_expr(prog.sig().input()) {
+        input.extend(
+            json::names_try_from_term(&term)?
+                .into_iter()
+                .map(|(n, _)| n),
+        );
+    }
+    let mut output: Vec<String> = Vec::new();
+    for term in json::terms_from_expr(prog.sig().output()) {
+        output.extend(
+            json::names_try_from_term(&term)?
+                .into_iter()
+                .map(|(n, _)| n),
+        );
+    }
+    let mut vhdl = vh::Module::new(&prog.sig().id());
+    let mut names = NameMap::new();
+    for (id, is_input) in input
+        .iter()
+        .map(|i| (i, true))
+        .chain(output.iter().map(|o| (o, false)))
+    {
+        let bits = if let Some(port) = module.ports.get(id) {
+            &port.bits
+        } else {
+            let err = format!("port {} not found", id);
+            return Err(Error::new_xpand_error(&err));
+        };
+        let ty = vh::Ty::from_width(bits.len() as u64);
+        if is_input {
+            vhdl.add_port(vh::Port::new_input(id, ty));
+        } else {
+            vhdl.add_port(vh::Port::new_output(id, ty));
+        }
+        add_name(&mut names, id, bits);
+    }
+    let port: HashSet<&String> = input.iter().chain(output.iter()).collect();
+    for (id, net) in module.netnames.iter() {
+        if !port.contains(id) && add_name(&mut names, id, &net.bits) {
+            let ty = vh::Ty::from_width(net.bits.len() as u64);
+            vhdl.add_signal(vh::Signal::new(id, ty));
+        }
+    }
+    // outputs that are not used are left open, partly used ones get
+    // a signal named after the cell and port
+    let mut open: HashSet<(String, String)> = HashSet::new();
+    for (cell_id, cell) in module.cells.iter() {
+        for (p, bits) in cell.connections.iter() {
+            if cell.port_directions.get(p).map_or(true, |d| d != "output") {
+                continue;
+            }
+            let unnamed = bits
+                .iter()
+                .filter(|b| matches!(b, Bit::Net(n) if !names.contains_key(n)))
+                .count();
+            if unnamed == bits.len() {
+                open.insert((cell_id.to_string(), p.to_string()));
+            } else if unnamed > 0 {
+                let id = format!("{}_{}", cell_id, p);
+                add_name(&mut names, &id, bits);
+                let ty = vh::Ty::from_width(bits.len() as u64);
+                vhdl.add_signal(vh::Signal::new(&id, ty));
+            }
+        }
+    }
+    // output bits named elsewhere, or constant, are assigned
+    for id in output.iter() {
+        if let Some(p) = module.ports.get(id) {
+            for (formal, actual) in assoc_try_from_bits(&names, id, &p.bits)? {
+                if formal != actual {
+                    vhdl.add_stmt(vh::Stmt::Assign(formal, actual));
+                }
+            }
+        }
+    }
+    for (cell_id, cell) in module.cells.iter() {
+        let mut inst = vh::Instance::new(cell_id, &cell.ty);
+        for (k, v) in cell.generics.iter() {
+            inst.add_generic(k, generic_try_from_literal(v)?);
+        }
+        for (p, bits) in cell.connections.iter() {
+            if !open.contains(&(cell_id.to_string(), p.to_string())) {
+                for (formal, actual) in assoc_try_from_bits(&names, p, bits)? {
+                    inst.connect(formal, actual);
+                }
+            }
+        }
+        for (k, v) in cell.attributes.iter() {
+            inst.add_attr(k, v);
+        }
+        vhdl.add_stmt(vh::Stmt::Instance(inst));
+    }
+    Ok(vhdl)
+}
@@ -0,0 +1,94 @@
+use crate::errors::Error;
+use crate::inst_name_try_from_instr;
+use crate::loc::{site_name_from_device, string_from_bel};
+use device::ast::Device;
+use std::fmt;
+use xir::ast as xir;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Constraint {
+    pub prop: String,
+    pub value: String,
+    pub cell: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Xdc {
+    pub constraint: Vec<Constraint>,
+}
+
+impl Constraint {
+    pub fn new(prop: &str, value: &str, cell: &str) -> Self {
+        Constraint {
+            prop: prop.to_string(),
+            value: value.to_string(),
+            cell: cell.to_string(),
+        }
+    }
+    pub fn prop(&self) -> String {
+        self.prop.to_string()
+    }
+    pub fn value(&self) -> String {
+        self.value.to_string()
+    }
+    pub fn cell(&self) -> String {
+        self.cell.to_string()
+    }
+}
+
+impl Xdc {
+    pub fn constraint(&self) -> &Vec<Constraint> {
+        &self.constraint
+    }
+    pub fn add_constraint(&mut self, constraint: Constraint) {
+        self.constraint.push(constraint);
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "set_property {} {} [get_cells {}]",
+            self.prop, self.value, self.cell
+        )
+    }
+}
+
+impl fmt::Display for Xdc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.constraint.iter() {
+            writeln!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+/// BEL and LOC constraints for every placed instance, using the
+/// instance names of the structural Verilog
+pub fn try_from_xir_prog(prog: &xir::Prog) -> Result<Xdc, Error> {
+    try_from_xir_prog_with_device(prog, None)
+}
+
+/// locations use the raw site names of the device, if there is one
+pub fn try_from_xir_prog_with_device(
+    prog: &xir::Prog,
+    device: Option<&Device>,
+) -> Result<Xdc, Error> {
+    let mut xdc = Xdc::default();
+    for instr in prog.body() {
+        if let Some(mach) = instr.mach() {
+            if let Some(loc) = mach.loc() {
+                if loc.is_placed() {
+                    let cell = inst_name_try_from_instr(mach)?;
+                    if let Some(bel) = string_from_bel(loc.bel()) {
+                        xdc.add_constraint(Constraint::new("BEL", &bel, &cell));
+                    }
+                    let site = site_name_from_device(loc, device);
+                    xdc.add_constraint(Constraint::new("LOC", &site, &cell));
+                }
+            }
+        }
+    }
+    Ok(xdc)
+}
@@ -0,0 +1,37 @@
+use io::file::read_to_string;
+use std::path::Path;
+use xir::parser::Parser;
+use xpand::errors::Error;
+use xpand::json::try_from_xir_prog;
+
+fn test(name: &str) -> Result<(), Error> {
+    let mut i = Path::new("../../../examples/xir").join(name);
+    let mut o = Path::new("../../../examples/json").join(name);
+    i.set_extension("xir");
+    o.set_extension("json");
+    let p = Parser::parse_from_file(i)?;
+    let e = read_to_string(o);
+    let r = try_from_xir_prog(&p)?;
+    assert_eq!(r.to_string(), e);
+    Ok(())
+}
+
+#[test]
+fn add() -> Result<(), Error> {
+    test("add")
+}
+
+#[test]
+fn tdot() -> Result<(), Error> {
+    test("tdot")
+}
+
+#[test]
+fn macc() -> Result<(), Error> {
+    test("macc")
+}
+
+#[test]
+fn mem() -> Result<(), Error> {
+    test("mem")
+}
@@ -0,0 +1,45 @@
+use device::ast::Device;
+use io::file::read_to_string;
+use std::path::Path;
+use std::str::FromStr;
+use xir::parser::Parser;
+use xpand::errors::Error;
+use xpand::xdc::{try_from_xir_prog, try_from_xir_prog_with_device};
+
+fn test(name: &str) -> Result<(), Error> {
+    let mut i = Path::new("../../../examples/xir").join(name);
+    let mut o = Path::new("../../../examples/xdc").join(name);
+    i.set_extension("xir");
+    o.set_extension("xdc");
+    let p = Parser::parse_from_file(i)?;
+    let e = read_to_string(o);
+    let r = try_from_xir_prog(&p)?;
+    assert_eq!(r.to_string(), e);
+    Ok(())
+}
+
+#[test]
+fn addreduce_placed() -> Result<(), Error> {
+    test("addreduce_placed")
+}
+
+#[test]
+fn add() -> Result<(), Error> {
+    let p = Parser::parse_from_file("../../../examples/xir/add.xir")?;
+    let r = try_from_xir_prog(&p)?;
+    assert!(r.constraint().is_empty());
+    Ok(())
+}
+
+#[test]
+fn raw_site_name() -> Result<(), Error> {
+    let p = Parser::parse_from_str(
+        "def main(a:bool, b:bool) -> (y:bool) { y:bool = lut2[6](a, b) @a6lut(0, 0); }",
+    )?;
+    let d = Device::from_str("x,y,prim,raw\n0,0,LUT,SLICE_X12Y0\n").unwrap();
+    let r = try_from_xir_prog_with_device(&p, Some(&d))?;
+    let e =
+        "set_property BEL A6LUT [get_cells __y]\nset_property LOC SLICE_X12Y0 [get_cells __y]\n";
+    assert_eq!(r.to_string(), e);
+    Ok(())
+}
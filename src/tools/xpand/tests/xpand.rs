@@ -1,8 +1,11 @@
+use device::ast::Device;
 use io::file::read_to_string;
+use ir::clock::{Clock, ResetTy};
 use std::path::Path;
+use std::str::FromStr;
 use xir::parser::Parser;
 use xpand::errors::Error;
-use xpand::try_from_xir_prog;
+use xpand::{try_from_xir_prog, try_from_xir_prog_with_clock, try_from_xir_prog_with_device};
 
 fn test(name: &str) -> Result<(), Error> {
     let mut i = Path::new("../../../examples/xir").join(name);
@@ -16,6 +19,25 @@ fn test(name: &str) -> Result<(), Error> {
     Ok(())
 }
 
+fn test_with_clock(name: &str, golden: &str, clock: &Clock) -> Result<(), Error> {
+    let mut i = Path::new("../../../examples/xir").join(name);
+    let mut o = Path::new("../../../examples/struct").join(golden);
+    i.set_extension("xir");
+    o.set_extension("v");
+    let p = Parser::parse_from_file(i)?;
+    let e = read_to_string(o);
+    let r = try_from_xir_prog_with_clock(&p, clock)?;
+    assert_eq!(r.to_string(), e);
+    Ok(())
+}
+
+fn clock_from_reset(reset_ty: ResetTy, active_low: bool) -> Clock {
+    let mut clock = Clock::default();
+    clock.set_reset_ty(reset_ty);
+    clock.set_active_low(active_low);
+    clock
+}
+
 #[test]
 fn add() -> Result<(), Error> {
     test("add")
@@ -95,3 +117,92 @@ fn tdot_5_18() -> Result<(), Error> {
 fn tdot_5_36() -> Result<(), Error> {
     test("tdot_5_36")
 }
+
+#[test]
+fn tdot_5_3_opt() -> Result<(), Error> {
+    test("tdot_5_3_opt")
+}
+
+#[test]
+fn prims() -> Result<(), Error> {
+    test("prims")
+}
+
+#[test]
+fn macc() -> Result<(), Error> {
+    test("macc")
+}
+
+#[test]
+fn mem() -> Result<(), Error> {
+    test("mem")
+}
+
+#[test]
+fn bram_write_mode() -> Result<(), Error> {
+    let p = Parser::parse_from_str(
+        "def main(ra:i4, wa:i4, wd:i8, we:bool) -> (y:i8) {
+            y:i8 = bram[1](ra, wa, wd, we) @ramb18(0, 0);
+        }",
+    )?;
+    let r = try_from_xir_prog(&p)?.to_string();
+    assert!(r.contains(".WRITE_MODE_A(\"WRITE_FIRST\")"));
+    assert!(r.contains(".WRITE_MODE_B(\"WRITE_FIRST\")"));
+    let p = Parser::parse_from_str(
+        "def main(ra:i4, wa:i4, wd:i8, we:bool) -> (y:i8) {
+            y:i8 = bram[3](ra, wa, wd, we) @ramb18(0, 0);
+        }",
+    )?;
+    assert!(try_from_xir_prog(&p).is_err());
+    Ok(())
+}
+
+#[test]
+fn reg_active_low() -> Result<(), Error> {
+    test_with_clock(
+        "reg",
+        "reg_active_low",
+        &clock_from_reset(ResetTy::Sync, true),
+    )
+}
+
+#[test]
+fn reg_async() -> Result<(), Error> {
+    test_with_clock("reg", "reg_async", &clock_from_reset(ResetTy::Async, false))
+}
+
+#[test]
+fn reg_no_reset() -> Result<(), Error> {
+    test_with_clock(
+        "reg",
+        "reg_no_reset",
+        &clock_from_reset(ResetTy::None, false),
+    )
+}
+
+#[test]
+fn tadd_active_low() -> Result<(), Error> {
+    test_with_clock(
+        "tadd",
+        "tadd_active_low",
+        &clock_from_reset(ResetTy::Sync, true),
+    )
+}
+
+#[test]
+fn tadd_async() -> Result<(), Error> {
+    let p = Parser::parse_from_file("../../../examples/xir/tadd.xir")?;
+    let clock = clock_from_reset(ResetTy::Async, false);
+    assert!(try_from_xir_prog_with_clock(&p, &clock).is_err());
+    Ok(())
+}
+
+#[test]
+fn raw_site_name() -> Result<(), Error> {
+    let p = Parser::parse_from_file("../../../examples/xir/addreduce_placed.xir")?;
+    let d = Device::from_str("x,y,prim,raw\n0,0,LUT,SLICE_X12Y0\n").unwrap();
+    let r = try_from_xir_prog_with_device(&p, &Clock::default(), Some(&d))?.to_string();
+    assert!(r.contains("SLICE_X12Y0"));
+    assert!(r.contains("SLICE_X0Y1"));
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::errors::Error;
+use xir::ast as xir;
+
+/// CARRY8, every O is S xor the carry into its bit, and the carry out
+/// of a bit is the carry in when S is set or DI otherwise. Sub sets CI,
+/// because S holds a xor not b
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Carry {
+    pub ci: bool,
+}
+
+impl Carry {
+    pub fn new_add() -> Self {
+        Carry { ci: false }
+    }
+    pub fn new_sub() -> Self {
+        Carry { ci: true }
+    }
+    pub fn ci(&self) -> bool {
+        self.ci
+    }
+    /// O for the first width bits of DI and S
+    pub fn eval(&self, di: u64, s: u64, width: u64) -> u64 {
+        let mut carry = u64::from(self.ci);
+        let mut o: u64 = 0;
+        for i in 0..width.min(64) {
+            let s_i = (s >> i) & 1;
+            let di_i = (di >> i) & 1;
+            o |= (s_i ^ carry) << i;
+            carry = if s_i == 1 { carry } else { di_i };
+        }
+        o
+    }
+}
+
+pub fn carry_try_from_mach(instr: &xir::InstrMach) -> Result<Carry, Error> {
+    match instr.op() {
+        xir::OpMach::CarryAdd => Ok(Carry::new_add()),
+        xir::OpMach::CarrySub => Ok(Carry::new_sub()),
+        op => {
+            let err = format!("{} is not a carry", op);
+            Err(Error::new_sim_error(&err))
+        }
+    }
+}
@@ -0,0 +1,405 @@
+use crate::errors::Error;
+use crate::{bits_try_from_term, mask, value_try_from_word, word_try_from_term};
+use ir::interp::{Env, Value};
+use std::convert::TryFrom;
+use xir::ast as xir;
+
+pub const A_WIDTH: u64 = 30;
+pub const B_WIDTH: u64 = 18;
+pub const P_WIDTH: u64 = 48;
+
+// the multiplier takes the lower 27 bits of A
+const MULT_A_WIDTH: u64 = 27;
+
+// opcodes used by xpand, z = c and x = a:b for vector add and sub,
+// and x = y = m with z either zero, c or pcin for multiply
+pub const OPMODE_VADD: u64 = 51;
+pub const OPMODE_MUL: u64 = 5;
+pub const OPMODE_MULADD: u64 = 53;
+pub const OPMODE_MULADD_CASCADE: u64 = 21;
+
+// z + (w + x + y + cin) and z - (w + x + y + cin)
+pub const ALUMODE_ADD: u64 = 0;
+pub const ALUMODE_SUB: u64 = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Simd {
+    One,
+    Two,
+    Four,
+}
+
+// operand of the alu selected by the x and y multiplexers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SelXY {
+    Zero,
+    M,
+    AB,
+}
+
+// operand of the alu selected by the z multiplexer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SelZ {
+    Zero,
+    Pcin,
+    C,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DspConf {
+    pub opmode: u64,
+    pub alumode: u64,
+    pub simd: Simd,
+    pub areg: bool,
+    pub breg: bool,
+    pub creg: bool,
+    pub mreg: bool,
+    pub preg: bool,
+}
+
+/// values of the DSP48E2 ports used by xpand
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DspInput {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+    pub pcin: u64,
+    pub cea: bool,
+    pub ceb: bool,
+    pub cec: bool,
+    pub cem: bool,
+    pub cep: bool,
+}
+
+/// Subset of DSP48E2 with one register stage for A, B, C, M and P,
+/// SIMD add and sub, multiply and multiply-add of C or PCIN. PCOUT is
+/// the same as P
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dsp {
+    pub conf: DspConf,
+    xy: SelXY,
+    z: SelZ,
+    a: u64,
+    b: u64,
+    c: u64,
+    m: u64,
+    p: u64,
+}
+
+impl Default for DspConf {
+    fn default() -> Self {
+        DspConf {
+            opmode: 0,
+            alumode: ALUMODE_ADD,
+            simd: Simd::One,
+            areg: false,
+            breg: false,
+            creg: false,
+            mreg: false,
+            preg: false,
+        }
+    }
+}
+
+impl Simd {
+    pub fn word_width(&self) -> u64 {
+        match self {
+            Simd::One => 48,
+            Simd::Two => 24,
+            Simd::Four => 12,
+        }
+    }
+    pub fn lanes(&self) -> u64 {
+        P_WIDTH / self.word_width()
+    }
+}
+
+fn sign_extend(value: u64, width: u64) -> i64 {
+    let shift = 64 - width;
+    ((value << shift) as i64) >> shift
+}
+
+impl Dsp {
+    pub fn try_new(conf: DspConf) -> Result<Dsp, Error> {
+        let opmode = conf.opmode;
+        let xy = match (opmode & 3, (opmode >> 2) & 3) {
+            (0, 0) => SelXY::Zero,
+            (1, 1) => SelXY::M,
+            (3, 0) => SelXY::AB,
+            _ => {
+                let err = format!("OPMODE {} is not supported", opmode);
+                return Err(Error::new_sim_error(&err));
+            }
+        };
+        let z = match ((opmode >> 4) & 7, (opmode >> 7) & 3) {
+            (0, 0) => SelZ::Zero,
+            (1, 0) => SelZ::Pcin,
+            (3, 0) => SelZ::C,
+            _ => {
+                let err = format!("OPMODE {} is not supported", opmode);
+                return Err(Error::new_sim_error(&err));
+            }
+        };
+        if conf.alumode != ALUMODE_ADD && conf.alumode != ALUMODE_SUB {
+            let err = format!("ALUMODE {} is not supported", conf.alumode);
+            return Err(Error::new_sim_error(&err));
+        }
+        if xy == SelXY::M && conf.simd != Simd::One {
+            return Err(Error::new_sim_error(
+                "multiply is only supported without simd",
+            ));
+        }
+        Ok(Dsp {
+            conf,
+            xy,
+            z,
+            a: 0,
+            b: 0,
+            c: 0,
+            m: 0,
+            p: 0,
+        })
+    }
+    pub fn conf(&self) -> &DspConf {
+        &self.conf
+    }
+    pub fn reset(&mut self) {
+        self.a = 0;
+        self.b = 0;
+        self.c = 0;
+        self.m = 0;
+        self.p = 0;
+    }
+    fn a_out(&self, input: &DspInput) -> u64 {
+        if self.conf.areg {
+            self.a
+        } else {
+            input.a & mask(A_WIDTH)
+        }
+    }
+    fn b_out(&self, input: &DspInput) -> u64 {
+        if self.conf.breg {
+            self.b
+        } else {
+            input.b & mask(B_WIDTH)
+        }
+    }
+    fn c_out(&self, input: &DspInput) -> u64 {
+        if self.conf.creg {
+            self.c
+        } else {
+            input.c & mask(P_WIDTH)
+        }
+    }
+    // signed product of the lower 27 bits of A and B
+    fn mult(&self, input: &DspInput) -> u64 {
+        let a = sign_extend(self.a_out(input), MULT_A_WIDTH);
+        let b = sign_extend(self.b_out(input), B_WIDTH);
+        (a.wrapping_mul(b) as u64) & mask(P_WIDTH)
+    }
+    fn m_out(&self, input: &DspInput) -> u64 {
+        if self.conf.mreg {
+            self.m
+        } else {
+            self.mult(input)
+        }
+    }
+    // input of the P register, computed for every simd lane
+    fn alu(&self, input: &DspInput) -> u64 {
+        let xy = match self.xy {
+            SelXY::Zero => 0,
+            SelXY::M => self.m_out(input),
+            SelXY::AB => (self.a_out(input) << B_WIDTH) | self.b_out(input),
+        };
+        let z = match self.z {
+            SelZ::Zero => 0,
+            SelZ::Pcin => input.pcin & mask(P_WIDTH),
+            SelZ::C => self.c_out(input),
+        };
+        let word = self.conf.simd.word_width();
+        let mut p: u64 = 0;
+        for i in 0..self.conf.simd.lanes() {
+            let shift = i * word;
+            let xy = (xy >> shift) & mask(word);
+            let z = (z >> shift) & mask(word);
+            let lane = if self.conf.alumode == ALUMODE_SUB {
+                z.wrapping_sub(xy)
+            } else {
+                z.wrapping_add(xy)
+            };
+            p |= (lane & mask(word)) << shift;
+        }
+        p
+    }
+    /// P and PCOUT for the inputs in the current state
+    pub fn p(&self, input: &DspInput) -> u64 {
+        if self.conf.preg {
+            self.p
+        } else {
+            self.alu(input)
+        }
+    }
+    /// P when it is registered, so it does not depend on the inputs
+    pub fn p_reg(&self) -> Option<u64> {
+        if self.conf.preg {
+            Some(self.p)
+        } else {
+            None
+        }
+    }
+    pub fn clock(&mut self, input: &DspInput) {
+        let m = self.mult(input);
+        let p = self.alu(input);
+        if input.cea {
+            self.a = input.a & mask(A_WIDTH);
+        }
+        if input.ceb {
+            self.b = input.b & mask(B_WIDTH);
+        }
+        if input.cec {
+            self.c = input.c & mask(P_WIDTH);
+        }
+        if input.cem {
+            self.m = m;
+        }
+        if input.cep {
+            self.p = p;
+        }
+    }
+}
+
+/// env entry with the PCOUT of the instruction defining term, the same
+/// name xpand gives to the cascade wire
+pub fn cascade_name_try_from_term(term: &xir::ExprTerm) -> Result<xir::Id, Error> {
+    let id = xir::Id::try_from(term.clone())?;
+    Ok(format!("_{}_pcout", id))
+}
+
+/// words of 12, 24 or 48 bits depending on the number of lanes
+pub fn word_width_try_from_term(term: &xir::ExprTerm) -> Result<u64, Error> {
+    match term.length() {
+        Some(4) | Some(3) => Ok(12),
+        Some(2) => Ok(24),
+        Some(1) | None => Ok(48),
+        _ => Err(Error::new_sim_error("unsupported length")),
+    }
+}
+
+fn simd_try_from_term(term: &xir::ExprTerm) -> Result<Simd, Error> {
+    match word_width_try_from_term(term)? {
+        12 => Ok(Simd::Four),
+        24 => Ok(Simd::Two),
+        _ => Ok(Simd::One),
+    }
+}
+
+pub fn dsp_try_from_mach(instr: &xir::InstrMach) -> Result<Dsp, Error> {
+    let dst = instr.dst().get_term(0)?;
+    let mut conf = DspConf::default();
+    match instr.op() {
+        xir::OpMach::VecAddRegA | xir::OpMach::VecAdd | xir::OpMach::VecSub => {
+            conf.opmode = OPMODE_VADD;
+            conf.simd = simd_try_from_term(dst)?;
+            if *instr.op() == xir::OpMach::VecSub {
+                conf.alumode = ALUMODE_SUB;
+            }
+            if *instr.op() == xir::OpMach::VecAddRegA {
+                conf.areg = true;
+                conf.breg = true;
+                conf.creg = true;
+                conf.preg = true;
+            }
+        }
+        xir::OpMach::VecMul | xir::OpMach::Mul => {
+            if dst.length().map_or(false, |l| l > 1) {
+                return Err(Error::new_sim_error(
+                    "vector multiply must have only one lane",
+                ));
+            }
+            conf.opmode = OPMODE_MUL;
+        }
+        xir::OpMach::MulAdd => conf.opmode = OPMODE_MULADD,
+        xir::OpMach::MulAddRegA | xir::OpMach::MulAddRegACo => {
+            conf.opmode = OPMODE_MULADD;
+            conf.areg = true;
+            conf.breg = true;
+            conf.mreg = true;
+            conf.preg = true;
+        }
+        xir::OpMach::MulAddRegACi | xir::OpMach::MulAddRegACio => {
+            conf.opmode = OPMODE_MULADD_CASCADE;
+            conf.areg = true;
+            conf.breg = true;
+            conf.mreg = true;
+            conf.preg = true;
+        }
+        op => {
+            let err = format!("{} is not a dsp", op);
+            return Err(Error::new_sim_error(&err));
+        }
+    }
+    Dsp::try_new(conf)
+}
+
+fn enable_try_from_term(env: &Env, term: &xir::ExprTerm) -> Result<bool, Error> {
+    Ok(bits_try_from_term(env, term)? & 1 == 1)
+}
+
+/// port values of instr, where the cascade input comes from the env
+pub fn dsp_input_try_from_mach(instr: &xir::InstrMach, env: &Env) -> Result<DspInput, Error> {
+    let mut input = DspInput::default();
+    let arg = instr.arg();
+    match instr.op() {
+        xir::OpMach::VecAddRegA | xir::OpMach::VecAdd | xir::OpMach::VecSub => {
+            let left = arg.get_term(0)?;
+            let right = arg.get_term(1)?;
+            input.c = word_try_from_term(env, left, word_width_try_from_term(left)?)?;
+            let ab = word_try_from_term(env, right, word_width_try_from_term(right)?)?;
+            input.a = ab >> B_WIDTH;
+            input.b = ab & mask(B_WIDTH);
+            if *instr.op() == xir::OpMach::VecAddRegA {
+                input.cec = enable_try_from_term(env, arg.get_term(2)?)?;
+                input.cea = enable_try_from_term(env, arg.get_term(3)?)?;
+                input.ceb = input.cea;
+                input.cep = enable_try_from_term(env, arg.get_term(4)?)?;
+            }
+        }
+        op => {
+            let a = arg.get_term(0)?;
+            let b = arg.get_term(1)?;
+            input.a = word_try_from_term(env, a, word_width_try_from_term(a)?)? & mask(A_WIDTH);
+            input.b = word_try_from_term(env, b, word_width_try_from_term(b)?)? & mask(B_WIDTH);
+            let (addend, cascade, reg) = match op {
+                xir::OpMach::MulAdd => (true, false, false),
+                xir::OpMach::MulAddRegA | xir::OpMach::MulAddRegACo => (true, false, true),
+                xir::OpMach::MulAddRegACi | xir::OpMach::MulAddRegACio => (true, true, true),
+                _ => (false, false, false),
+            };
+            if cascade {
+                let c = arg.get_term(2)?;
+                if let Some(pcin) = env.get(&cascade_name_try_from_term(c)?) {
+                    input.pcin = pcin[0] as u64;
+                } else {
+                    let err = format!("{} is not a cascade output", c);
+                    return Err(Error::new_sim_error(&err));
+                }
+            } else if addend {
+                let c = arg.get_term(2)?;
+                input.c = word_try_from_term(env, c, word_width_try_from_term(c)?)?;
+            }
+            if reg {
+                input.cea = enable_try_from_term(env, arg.get_term(3)?)?;
+                input.ceb = enable_try_from_term(env, arg.get_term(4)?)?;
+                input.cem = enable_try_from_term(env, arg.get_term(5)?)?;
+                input.cep = enable_try_from_term(env, arg.get_term(6)?)?;
+            }
+        }
+    }
+    Ok(input)
+}
+
+/// every lane of dst from P
+pub fn value_try_from_p(instr: &xir::InstrMach, p: u64) -> Result<Value, Error> {
+    let dst = instr.dst().get_term(0)?;
+    value_try_from_word(dst, p, word_width_try_from_term(dst)?)
+}
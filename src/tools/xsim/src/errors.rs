@@ -0,0 +1,47 @@
+use ir::errors::Error as IrError;
+use std::fmt;
+use std::num::TryFromIntError;
+use xir::errors::Error as XirError;
+
+#[derive(Debug)]
+pub enum Error {
+    Ir(IrError),
+    Xir(XirError),
+    Sim(String),
+    TryFromInt(TryFromIntError),
+}
+
+impl Error {
+    pub fn new_sim_error(msg: &str) -> Self {
+        Error::Sim(msg.to_string())
+    }
+}
+
+impl From<IrError> for Error {
+    fn from(e: IrError) -> Self {
+        Error::Ir(e)
+    }
+}
+
+impl From<XirError> for Error {
+    fn from(e: XirError) -> Self {
+        Error::Xir(e)
+    }
+}
+
+impl From<TryFromIntError> for Error {
+    fn from(e: TryFromIntError) -> Self {
+        Error::TryFromInt(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Ir(msg) => write!(f, "{}", msg),
+            Error::Xir(msg) => write!(f, "{}", msg),
+            Error::Sim(msg) => write!(f, "{}", msg),
+            Error::TryFromInt(msg) => write!(f, "{}", msg),
+        }
+    }
+}
@@ -0,0 +1,363 @@
+pub mod carry;
+pub mod dsp;
+pub mod errors;
+pub mod lut;
+pub mod ram;
+pub mod reg;
+
+use crate::carry::{carry_try_from_mach, Carry};
+use crate::dsp::{
+    cascade_name_try_from_term, dsp_input_try_from_mach, dsp_try_from_mach, value_try_from_p, Dsp,
+};
+use crate::errors::Error;
+use crate::lut::{lut_try_from_mach, Lut};
+use crate::ram::{ram_try_from_mach, Ram};
+use crate::reg::{reg_try_from_mach, Reg};
+use ir::interp::{norm, Env, Value};
+use tb::stimulus::Stimulus;
+use tb::trace::Trace;
+use xir::ast as xir;
+
+/// functional model of the primitive behind a machine instruction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Model {
+    Lut(Lut),
+    Reg(Reg),
+    Carry(Carry),
+    Dsp(Dsp),
+    Ram(Ram),
+}
+
+/// Cycle based simulator for xir programs, with the same interface as
+/// the ir interpreter so the lowered design can be compared with its
+/// source. Every call to step evaluates the outputs for the current
+/// inputs and then clocks every primitive
+#[derive(Clone, Debug)]
+pub struct Sim {
+    prog: xir::Prog,
+    model: Vec<Option<Model>>,
+}
+
+pub fn mask(width: u64) -> u64 {
+    if width >= 64 {
+        u64::MAX
+    } else {
+        (1 << width) - 1
+    }
+}
+
+fn width_try_from_term(term: &xir::ExprTerm) -> Result<u64, Error> {
+    if let Some(width) = term.width() {
+        Ok(width)
+    } else {
+        let err = format!("{} must have a width", term);
+        Err(Error::new_sim_error(&err))
+    }
+}
+
+fn value_try_from_term(env: &Env, term: &xir::ExprTerm) -> Result<Value, Error> {
+    match term {
+        xir::ExprTerm::Val(v) => Ok(vec![*v]),
+        _ => {
+            let id = term.get_id()?;
+            if let Some(value) = env.get(&id) {
+                Ok(value.clone())
+            } else {
+                let err = format!("{} is not defined", id);
+                Err(Error::new_sim_error(&err))
+            }
+        }
+    }
+}
+
+/// bits of the first lane of term
+pub fn bits_try_from_term(env: &Env, term: &xir::ExprTerm) -> Result<u64, Error> {
+    let value = value_try_from_term(env, term)?;
+    Ok((value[0] as u64) & mask(term.width().unwrap_or(64)))
+}
+
+/// lanes of term packed in words of the given width, lsb first
+pub fn word_try_from_term(env: &Env, term: &xir::ExprTerm, word: u64) -> Result<u64, Error> {
+    let width = width_try_from_term(term)?;
+    if width > word {
+        let err = format!("{} has lanes wider than a word of {} bits", term, word);
+        return Err(Error::new_sim_error(&err));
+    }
+    let value = value_try_from_term(env, term)?;
+    let mut packed: u64 = 0;
+    for (i, v) in value.iter().enumerate() {
+        let shift = (i as u64) * word;
+        if shift < 64 {
+            packed |= ((*v as u64) & mask(width)) << shift;
+        }
+    }
+    Ok(packed)
+}
+
+/// every lane of term from words of the given width, lsb first
+pub fn value_try_from_word(term: &xir::ExprTerm, packed: u64, word: u64) -> Result<Value, Error> {
+    let width = width_try_from_term(term)?;
+    let lanes = term.length().unwrap_or(1);
+    let mut value: Value = Vec::new();
+    for i in 0..lanes {
+        let shift = i * word;
+        let bits = if shift < 64 { packed >> shift } else { 0 };
+        value.push(norm(term.get_ty()?, (bits & mask(width)) as i64));
+    }
+    Ok(value)
+}
+
+// operand values, or none if some of them are not computed yet
+fn args_from_expr(env: &Env, expr: &xir::Expr) -> Option<Vec<Value>> {
+    let term: Vec<xir::ExprTerm> = expr.clone().into();
+    term.iter()
+        .map(|t| value_try_from_term(env, t).ok())
+        .collect()
+}
+
+fn eval_instr_basc(instr: &xir::InstrBasc, env: &Env) -> Result<Value, Error> {
+    let dst = instr.dst().get_term(0)?;
+    let width = width_try_from_term(dst)?;
+    match instr.op() {
+        xir::OpBasc::Id => value_try_from_term(env, instr.arg().get_term(0)?),
+        xir::OpBasc::Gnd => value_try_from_word(dst, 0, width),
+        xir::OpBasc::Vcc => value_try_from_word(dst, u64::MAX, width),
+        xir::OpBasc::Ext => {
+            let index = instr.attr().get_val(0)?.min(63);
+            let bits = bits_try_from_term(env, instr.arg().get_term(0)?)?;
+            value_try_from_word(dst, bits >> index, width)
+        }
+        xir::OpBasc::Cat => {
+            let arg: Vec<xir::ExprTerm> = instr.arg().clone().into();
+            let lanes = dst.length().unwrap_or(1) as usize;
+            if lanes == 0 || arg.len() % lanes != 0 {
+                let err = format!(
+                    "cat with {} arguments can not be split into {} lanes",
+                    arg.len(),
+                    lanes
+                );
+                return Err(Error::new_sim_error(&err));
+            }
+            // vector lanes are assigned in order, taking the same number of args each
+            let mut value: Value = Vec::new();
+            for lane in arg.chunks(arg.len() / lanes) {
+                let mut bits: u64 = 0;
+                let mut offset: u64 = 0;
+                for t in lane {
+                    if offset < 64 {
+                        bits |= bits_try_from_term(env, t)? << offset;
+                    }
+                    offset += width_try_from_term(t)?;
+                }
+                value.push(norm(dst.get_ty()?, (bits & mask(width)) as i64));
+            }
+            Ok(value)
+        }
+    }
+}
+
+// output of a model that only depends on the state, like a register
+fn state_from_model(instr: &xir::InstrMach, model: &Model) -> Result<Option<Value>, Error> {
+    let dst = instr.dst().get_term(0)?;
+    match model {
+        Model::Reg(reg) => Ok(Some(value_try_from_word(dst, u64::from(reg.q()), 1)?)),
+        Model::Dsp(dsp) => match dsp.p_reg() {
+            Some(p) => Ok(Some(value_try_from_p(instr, p)?)),
+            None => Ok(None),
+        },
+        Model::Ram(ram) => match ram.read_reg() {
+            Some(data) => Ok(Some(value_try_from_word(dst, data, ram.width())?)),
+            None => Ok(None),
+        },
+        _ => Ok(None),
+    }
+}
+
+fn eval_instr_mach(instr: &xir::InstrMach, model: &Model, env: &Env) -> Result<Value, Error> {
+    let dst = instr.dst().get_term(0)?;
+    let width = width_try_from_term(dst)?;
+    let arg = instr.arg();
+    match model {
+        Model::Lut(lut) => {
+            let term: Vec<xir::ExprTerm> = arg.clone().into();
+            let input = term
+                .iter()
+                .map(|t| Ok(bits_try_from_term(env, t)? & 1 == 1))
+                .collect::<Result<Vec<bool>, Error>>()?;
+            value_try_from_word(dst, u64::from(lut.eval(&input)), width)
+        }
+        Model::Carry(carry) => {
+            let di = bits_try_from_term(env, arg.get_term(0)?)?;
+            let s = bits_try_from_term(env, arg.get_term(1)?)?;
+            value_try_from_word(dst, carry.eval(di, s, width), width)
+        }
+        Model::Dsp(dsp) => {
+            let input = dsp_input_try_from_mach(instr, env)?;
+            value_try_from_p(instr, dsp.p(&input))
+        }
+        Model::Ram(ram) => {
+            let raddr = bits_try_from_term(env, arg.get_term(0)?)?;
+            value_try_from_word(dst, ram.read(raddr), width)
+        }
+        Model::Reg(reg) => value_try_from_word(dst, u64::from(reg.q()), width),
+    }
+}
+
+fn clock_model(instr: &xir::InstrMach, model: &mut Model, env: &Env) -> Result<(), Error> {
+    let arg = instr.arg();
+    match model {
+        Model::Reg(reg) => {
+            let d = bits_try_from_term(env, arg.get_term(0)?)? & 1 == 1;
+            let ce = bits_try_from_term(env, arg.get_term(1)?)? & 1 == 1;
+            reg.clock(d, ce);
+        }
+        Model::Dsp(dsp) => {
+            let input = dsp_input_try_from_mach(instr, env)?;
+            dsp.clock(&input);
+        }
+        Model::Ram(ram) => {
+            let raddr = bits_try_from_term(env, arg.get_term(0)?)?;
+            let waddr = bits_try_from_term(env, arg.get_term(1)?)?;
+            let wdata = bits_try_from_term(env, arg.get_term(2)?)?;
+            let we = bits_try_from_term(env, arg.get_term(3)?)? & 1 == 1;
+            ram.clock(raddr, waddr, wdata, we);
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+pub fn model_try_from_mach(instr: &xir::InstrMach) -> Result<Model, Error> {
+    match instr.op() {
+        xir::OpMach::Lut1
+        | xir::OpMach::Lut2
+        | xir::OpMach::Lut3
+        | xir::OpMach::Lut4
+        | xir::OpMach::Lut5
+        | xir::OpMach::Lut6 => Ok(Model::Lut(lut_try_from_mach(instr)?)),
+        xir::OpMach::Fdre | xir::OpMach::Fdse => Ok(Model::Reg(reg_try_from_mach(instr)?)),
+        xir::OpMach::CarryAdd | xir::OpMach::CarrySub => {
+            Ok(Model::Carry(carry_try_from_mach(instr)?))
+        }
+        xir::OpMach::Lram | xir::OpMach::Bram | xir::OpMach::Uram => {
+            Ok(Model::Ram(ram_try_from_mach(instr)?))
+        }
+        _ => Ok(Model::Dsp(dsp_try_from_mach(instr)?)),
+    }
+}
+
+// the pcout of a dsp is named like the wire xpand creates for it
+fn insert_pcout(env: &mut Env, instr: &xir::InstrMach, dsp: &Dsp) -> Result<(), Error> {
+    if let Some(p) = dsp.p_reg() {
+        let name = cascade_name_try_from_term(instr.dst().get_term(0)?)?;
+        env.insert(name, vec![p as i64]);
+    }
+    Ok(())
+}
+
+impl Sim {
+    pub fn new(prog: &xir::Prog) -> Result<Sim, Error> {
+        let mut model: Vec<Option<Model>> = Vec::new();
+        for instr in prog.body() {
+            match instr {
+                xir::Instr::Mach(instr) => model.push(Some(model_try_from_mach(instr)?)),
+                _ => model.push(None),
+            }
+        }
+        let mut sim = Sim {
+            prog: prog.clone(),
+            model,
+        };
+        sim.reset();
+        Ok(sim)
+    }
+    /// what the reset port does to every primitive
+    pub fn reset(&mut self) {
+        for model in self.model.iter_mut() {
+            match model {
+                Some(Model::Reg(reg)) => reg.reset(),
+                Some(Model::Dsp(dsp)) => dsp.reset(),
+                Some(Model::Ram(ram)) => ram.reset(),
+                _ => (),
+            }
+        }
+    }
+    /// values of every term for the inputs in the current state
+    pub fn eval(&self, input: &Env) -> Result<Env, Error> {
+        let mut env = Env::new();
+        let sig: Vec<xir::ExprTerm> = self.prog.sig().input().clone().into();
+        for term in sig.iter() {
+            let id = term.get_id()?;
+            if let Some(value) = input.get(&id) {
+                let ty = term.get_ty()?;
+                env.insert(id, value.iter().map(|v| norm(ty, *v)).collect());
+            } else {
+                let err = format!("missing value for input {}", id);
+                return Err(Error::new_sim_error(&err));
+            }
+        }
+        let mut pending: Vec<usize> = Vec::new();
+        for (i, (instr, model)) in self.prog.body().iter().zip(self.model.iter()).enumerate() {
+            if let (xir::Instr::Mach(instr), Some(model)) = (instr, model) {
+                if let Some(value) = state_from_model(instr, model)? {
+                    env.insert(instr.dst().get_id(0)?, value);
+                    if let Model::Dsp(dsp) = model {
+                        insert_pcout(&mut env, instr, dsp)?;
+                    }
+                    continue;
+                }
+            }
+            pending.push(i);
+        }
+        while !pending.is_empty() {
+            let mut next: Vec<usize> = Vec::new();
+            for i in pending.iter() {
+                let instr = &self.prog.body()[*i];
+                if args_from_expr(&env, instr.arg()).is_some() {
+                    let value = match (instr, &self.model[*i]) {
+                        (xir::Instr::Mach(instr), Some(model)) => {
+                            eval_instr_mach(instr, model, &env)?
+                        }
+                        (xir::Instr::Basc(instr), _) => eval_instr_basc(instr, &env)?,
+                        _ => unreachable!(),
+                    };
+                    env.insert(instr.dst().get_id(0)?, value);
+                } else {
+                    next.push(*i);
+                }
+            }
+            if next.len() == pending.len() {
+                return Err(Error::new_sim_error(
+                    "combinational loop or undefined operand",
+                ));
+            }
+            pending = next;
+        }
+        Ok(env)
+    }
+    /// outputs for the inputs, then a clock edge
+    pub fn step(&mut self, input: &Env) -> Result<Env, Error> {
+        let env = self.eval(input)?;
+        let mut output = Env::new();
+        let sig: Vec<xir::ExprTerm> = self.prog.sig().output().clone().into();
+        for term in sig.iter() {
+            output.insert(term.get_id()?, value_try_from_term(&env, term)?);
+        }
+        for (instr, model) in self.prog.body().iter().zip(self.model.iter_mut()) {
+            if let (xir::Instr::Mach(instr), Some(model)) = (instr, model) {
+                clock_model(instr, model, &env)?;
+            }
+        }
+        Ok(output)
+    }
+}
+
+/// outputs of the program for every cycle of the stimulus
+pub fn try_from_xir_prog(prog: &xir::Prog, stimulus: &Stimulus) -> Result<Trace, Error> {
+    let mut sim = Sim::new(prog)?;
+    let mut trace = Trace::new(prog.sig());
+    for env in stimulus.cycle() {
+        trace.add_cycle(sim.step(env)?);
+    }
+    Ok(trace)
+}
@@ -0,0 +1,47 @@
+use crate::errors::Error;
+use xir::ast as xir;
+
+/// LUT1 to LUT6, O is the bit of INIT selected by the inputs where I0
+/// is the least significant bit of the index
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Lut {
+    pub init: u64,
+    pub inputs: usize,
+}
+
+impl Lut {
+    pub fn new(init: u64, inputs: usize) -> Self {
+        Lut { init, inputs }
+    }
+    pub fn init(&self) -> u64 {
+        self.init
+    }
+    pub fn inputs(&self) -> usize {
+        self.inputs
+    }
+    pub fn eval(&self, input: &[bool]) -> bool {
+        let index = input
+            .iter()
+            .take(self.inputs)
+            .enumerate()
+            .fold(0, |acc, (i, b)| acc | (u64::from(*b) << i));
+        (self.init >> index) & 1 == 1
+    }
+}
+
+pub fn lut_try_from_mach(instr: &xir::InstrMach) -> Result<Lut, Error> {
+    let inputs = match instr.op() {
+        xir::OpMach::Lut1 => 1,
+        xir::OpMach::Lut2 => 2,
+        xir::OpMach::Lut3 => 3,
+        xir::OpMach::Lut4 => 4,
+        xir::OpMach::Lut5 => 5,
+        xir::OpMach::Lut6 => 6,
+        op => {
+            let err = format!("{} is not a lut", op);
+            return Err(Error::new_sim_error(&err));
+        }
+    };
+    let init = instr.attr().get_val(0)?;
+    Ok(Lut::new(init as u64, inputs))
+}
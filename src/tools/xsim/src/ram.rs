@@ -0,0 +1,155 @@
+use crate::errors::Error;
+use crate::mask;
+use std::convert::TryFrom;
+use xir::ast as xir;
+
+// widest address supported, the memories are modeled as flat arrays
+const ADDR_WIDTH: u64 = 16;
+
+/// what a registered read of the word being written returns
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WriteMode {
+    ReadFirst,
+    WriteFirst,
+    NoChange,
+}
+
+impl Default for WriteMode {
+    fn default() -> Self {
+        WriteMode::ReadFirst
+    }
+}
+
+/// Simple dual port memory, y = ram[init](raddr, waddr, wdata, we).
+/// Words are written on the clock edge when we is set, and reads of an
+/// address being written return the old word. Reads take one cycle in
+/// bram and uram, lram reads are asynchronous. Reset restores the
+/// initial contents and clears the read register. Bram attributes start
+/// with the write mode, y = bram[mode, init], where write first reads
+/// the new word and no change keeps the read register while writing
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ram {
+    pub init: Vec<u64>,
+    pub width: u64,
+    pub sync: bool,
+    pub mode: WriteMode,
+    word: Vec<u64>,
+    out: u64,
+}
+
+impl Ram {
+    pub fn new(depth: usize, width: u64, init: &[u64], sync: bool, mode: WriteMode) -> Self {
+        let mut ram = Ram {
+            init: init.to_vec(),
+            width,
+            sync,
+            mode,
+            word: vec![0; depth],
+            out: 0,
+        };
+        ram.reset();
+        ram
+    }
+    pub fn width(&self) -> u64 {
+        self.width
+    }
+    pub fn depth(&self) -> usize {
+        self.word.len()
+    }
+    pub fn is_sync(&self) -> bool {
+        self.sync
+    }
+    pub fn reset(&mut self) {
+        let width = self.width;
+        for (i, w) in self.word.iter_mut().enumerate() {
+            *w = self.init.get(i).map_or(0, |v| v & mask(width));
+        }
+        self.out = 0;
+    }
+    fn get(&self, addr: u64) -> u64 {
+        let index = (addr as usize) % self.depth();
+        self.word[index]
+    }
+    /// data output for raddr in the current state
+    pub fn read(&self, raddr: u64) -> u64 {
+        if self.sync {
+            self.out
+        } else {
+            self.get(raddr)
+        }
+    }
+    /// data output when reads are registered, so it does not depend on raddr
+    pub fn read_reg(&self) -> Option<u64> {
+        if self.sync {
+            Some(self.out)
+        } else {
+            None
+        }
+    }
+    pub fn clock(&mut self, raddr: u64, waddr: u64, wdata: u64, we: bool) {
+        if !we || self.mode != WriteMode::NoChange {
+            self.out = self.get(raddr);
+        }
+        if we {
+            let index = (waddr as usize) % self.depth();
+            self.word[index] = wdata & mask(self.width);
+            if self.mode == WriteMode::WriteFirst && (raddr as usize) % self.depth() == index {
+                self.out = self.word[index];
+            }
+        }
+    }
+}
+
+fn width_try_from_term(term: &xir::ExprTerm) -> Result<u64, Error> {
+    if term.length().is_some() {
+        Err(Error::new_sim_error("memories do not support vector types"))
+    } else if let Some(width) = term.width() {
+        Ok(width)
+    } else {
+        Err(Error::new_sim_error("memory terms must have a width"))
+    }
+}
+
+pub fn ram_try_from_mach(instr: &xir::InstrMach) -> Result<Ram, Error> {
+    let sync = match instr.op() {
+        xir::OpMach::Lram => false,
+        xir::OpMach::Bram | xir::OpMach::Uram => true,
+        op => {
+            let err = format!("{} is not a memory", op);
+            return Err(Error::new_sim_error(&err));
+        }
+    };
+    let width = width_try_from_term(instr.dst().get_term(0)?)?;
+    let mut addr = 0;
+    for i in 0..2 {
+        addr = addr.max(width_try_from_term(instr.arg().get_term(i)?)?);
+    }
+    if addr > ADDR_WIDTH {
+        let err = format!("addresses wider than {} bits are not supported", ADDR_WIDTH);
+        return Err(Error::new_sim_error(&err));
+    }
+    let depth = 1 << addr;
+    let mut init = Vec::<i64>::try_from(instr.attr().clone())?;
+    let mut mode = WriteMode::default();
+    if *instr.op() == xir::OpMach::Bram && !init.is_empty() {
+        mode = match init.remove(0) {
+            0 => WriteMode::ReadFirst,
+            1 => WriteMode::WriteFirst,
+            2 => WriteMode::NoChange,
+            m => {
+                let err = format!("{} is not a valid bram write mode", m);
+                return Err(Error::new_sim_error(&err));
+            }
+        };
+    }
+    if init.len() > depth {
+        let err = format!(
+            "{} init values do not fit in a memory of {} words",
+            init.len(),
+            depth
+        );
+        return Err(Error::new_sim_error(&err));
+    }
+    let init: Vec<u64> = init.iter().map(|v| *v as u64).collect();
+    Ok(Ram::new(depth, width, &init, sync, mode))
+}
@@ -0,0 +1,41 @@
+use crate::errors::Error;
+use xir::ast as xir;
+
+/// FDRE and FDSE, reset clears or sets Q and a clock edge loads D when
+/// CE is set
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Reg {
+    pub set: bool,
+    pub q: bool,
+}
+
+impl Reg {
+    pub fn new_fdre() -> Self {
+        Reg::default()
+    }
+    pub fn new_fdse() -> Self {
+        Reg { set: true, q: true }
+    }
+    pub fn q(&self) -> bool {
+        self.q
+    }
+    pub fn reset(&mut self) {
+        self.q = self.set;
+    }
+    pub fn clock(&mut self, d: bool, ce: bool) {
+        if ce {
+            self.q = d;
+        }
+    }
+}
+
+pub fn reg_try_from_mach(instr: &xir::InstrMach) -> Result<Reg, Error> {
+    match instr.op() {
+        xir::OpMach::Fdre => Ok(Reg::new_fdre()),
+        xir::OpMach::Fdse => Ok(Reg::new_fdse()),
+        op => {
+            let err = format!("{} is not a register", op);
+            Err(Error::new_sim_error(&err))
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use io::file::read_to_string;
+use ir::parser::Parser as IrParser;
+use std::path::Path;
+use tb::stimulus::Stimulus;
+use tb::trace::try_from_ir_prog;
+use xir::parser::Parser as XirParser;
+use xsim::errors::Error;
+use xsim::try_from_xir_prog;
+
+// outputs of the xir program must match the ir program for the stimulus
+fn test(name: &str, stimulus: Option<&str>) -> Result<(), Error> {
+    let mut i = Path::new("../../../examples/ir").join(name);
+    let mut x = Path::new("../../../examples/xir").join(name);
+    i.set_extension("ir");
+    x.set_extension("xir");
+    let ir_prog = IrParser::parse_from_file(i)?;
+    let xir_prog = XirParser::parse_from_file(x)?;
+    let sig = ir_prog.get("main").unwrap().sig();
+    let s = if let Some(stimulus) = stimulus {
+        let mut s = Path::new("../../../examples/stim").join(stimulus);
+        s.set_extension("stim");
+        Stimulus::try_from_str(sig, &read_to_string(s)).unwrap()
+    } else {
+        Stimulus::new_random(sig, 32, 0).unwrap()
+    };
+    let e = try_from_ir_prog(&ir_prog, &s).unwrap();
+    let r = try_from_xir_prog(&xir_prog, &s)?;
+    assert_eq!(r, e);
+    Ok(())
+}
+
+#[test]
+fn add() -> Result<(), Error> {
+    test("add", Some("add"))
+}
+
+#[test]
+fn add_random() -> Result<(), Error> {
+    test("add", None)
+}
+
+#[test]
+fn tadd() -> Result<(), Error> {
+    test("tadd", None)
+}
+
+#[test]
+fn tdot() -> Result<(), Error> {
+    test("tdot", None)
+}
+
+#[test]
+fn fsm_3() -> Result<(), Error> {
+    test("fsm_3", None)
+}
+
+#[test]
+fn mem() -> Result<(), Error> {
+    let p = XirParser::parse_from_file("../../../examples/xir/mem.xir")?;
+    let s = Stimulus::try_from_str(p.sig(), "1 3 9 1\n3 0 0 0\n3 0 0 0").unwrap();
+    let r = try_from_xir_prog(&p, &s)?;
+    // lram reads are asynchronous, bram and uram reads take one cycle
+    assert_eq!(r.to_string(), "2 0 0\n9 2 0\n9 9 9");
+    Ok(())
+}
+
+#[test]
+fn bram_write_mode() -> Result<(), Error> {
+    let p = XirParser::parse_from_str(
+        "def main(ra:i4, wa:i4, wd:i8, we:bool) -> (y:i8, z:i8, w:i8) {
+            y:i8 = bram[0](ra, wa, wd, we) @ramb18(0, 0);
+            z:i8 = bram[1](ra, wa, wd, we) @ramb18(0, 1);
+            w:i8 = bram[2](ra, wa, wd, we) @ramb18(0, 2);
+        }",
+    )?;
+    let s = Stimulus::try_from_str(p.sig(), "3 3 9 1\n3 0 0 0\n3 0 0 0").unwrap();
+    let r = try_from_xir_prog(&p, &s)?;
+    // read first returns the old word, write first the new one and no
+    // change keeps the previous read
+    assert_eq!(r.to_string(), "0 0 0\n0 9 0\n9 9 9");
+    Ok(())
+}
+
+#[test]
+fn prims() -> Result<(), Error> {
+    test("prims", None)
+}
+
+#[test]
+fn macc() -> Result<(), Error> {
+    test("macc", None)
+}
@@ -0,0 +1,70 @@
+pub type Id = String;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Dir {
+    Input,
+    Output,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Port {
+    pub dir: Dir,
+    pub id: Id,
+    pub width: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Wire {
+    pub id: Id,
+    pub width: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Ref(Id),
+    Bit(Id, u64),
+    Slice(Id, u64, u64),
+    Concat(Vec<Expr>),
+    Lit(u64, u64),
+    Int(u64),
+    Str(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instance {
+    pub attr: Vec<(Id, String)>,
+    pub prim: Id,
+    pub param: Vec<(Id, Expr)>,
+    pub id: Id,
+    pub conn: Vec<(Id, Option<Expr>)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Stmt {
+    Wire(Wire),
+    Assign(Expr, Expr),
+    Instance(Instance),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Module {
+    pub id: Id,
+    pub port: Vec<Port>,
+    pub stmt: Vec<Stmt>,
+}
+
+impl Instance {
+    pub fn attr(&self, name: &str) -> Option<&String> {
+        self.attr.iter().find(|(k, _)| k == name).map(|(_, v)| v)
+    }
+    pub fn param(&self, name: &str) -> Option<&Expr> {
+        self.param.iter().find(|(k, _)| k == name).map(|(_, v)| v)
+    }
+    /// expression connected to port, None if the port is unconnected
+    pub fn conn(&self, name: &str) -> Option<&Expr> {
+        self.conn
+            .iter()
+            .find(|(k, _)| k == name)
+            .and_then(|(_, v)| v.as_ref())
+    }
+}
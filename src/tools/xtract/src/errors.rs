@@ -0,0 +1,66 @@
+use crate::parser;
+use ir::errors::Error as IrError;
+use pest_consume::Error as PestError;
+use std::fmt;
+use std::num::ParseIntError;
+use std::num::TryFromIntError;
+use xir::errors::Error as XirError;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(PestError<parser::Rule>),
+    Ir(IrError),
+    Xir(XirError),
+    Xtract(String),
+    ParseInt(ParseIntError),
+    TryFromInt(TryFromIntError),
+}
+
+impl Error {
+    pub fn new_xtract_error(msg: &str) -> Self {
+        Error::Xtract(msg.to_string())
+    }
+}
+
+impl From<PestError<parser::Rule>> for Error {
+    fn from(e: PestError<parser::Rule>) -> Self {
+        Error::Parser(e)
+    }
+}
+
+impl From<IrError> for Error {
+    fn from(e: IrError) -> Self {
+        Error::Ir(e)
+    }
+}
+
+impl From<XirError> for Error {
+    fn from(e: XirError) -> Self {
+        Error::Xir(e)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Error::ParseInt(e)
+    }
+}
+
+impl From<TryFromIntError> for Error {
+    fn from(e: TryFromIntError) -> Self {
+        Error::TryFromInt(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parser(msg) => write!(f, "{}", msg),
+            Error::Ir(msg) => write!(f, "{}", msg),
+            Error::Xir(msg) => write!(f, "{}", msg),
+            Error::Xtract(msg) => write!(f, "{}", msg),
+            Error::ParseInt(msg) => write!(f, "{}", msg),
+            Error::TryFromInt(msg) => write!(f, "{}", msg),
+        }
+    }
+}
@@ -0,0 +1,570 @@
+pub mod ast;
+pub mod errors;
+pub mod parser;
+
+use crate::ast::*;
+use crate::errors::Error;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::str::FromStr;
+use xir::ast as xir;
+
+pub const CLOCK: &str = "clock";
+pub const RESET: &str = "reset";
+pub const GND: &str = "gnd";
+pub const VCC: &str = "vcc";
+
+// dsp configurations written by xpand
+const VADD_OPMODE: i64 = 51;
+const VSUB_ALUMODE: i64 = 3;
+const MUL_OPMODE: i64 = 5;
+const MULADD_OPMODE: i64 = 53;
+const MULADD_CASCADE_OPMODE: i64 = 21;
+
+/// term driven by a wire, lane is only set for vector terms
+#[derive(Clone, Debug)]
+struct Net {
+    id: xir::Id,
+    lane: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct NetMap {
+    net: HashMap<Id, Net>,
+    ty: HashMap<xir::Id, xir::Ty>,
+}
+
+fn scalar_ty_from_width(width: u64) -> xir::Ty {
+    if width == 1 {
+        xir::Ty::Bool
+    } else {
+        xir::Ty::SInt(width)
+    }
+}
+
+// vector lanes are named {id}_{n} by xpand
+fn lane_from_name(name: &str) -> Option<(&str, u64)> {
+    let sep = name.rfind('_')?;
+    let lane = name[sep + 1..].parse::<u64>().ok()?;
+    if sep == 0 {
+        None
+    } else {
+        Some((&name[..sep], lane))
+    }
+}
+
+impl NetMap {
+    // lanes only form a vector if they are complete and have the same width, a
+    // single lane needs the temporary wire of a dsp to tell it from a scalar
+    pub fn new(decl: &[(Id, u64)], tmp: &[Id]) -> NetMap {
+        let mut map = NetMap::default();
+        let mut lanes: HashMap<&str, Vec<(u64, u64)>> = HashMap::new();
+        for (name, width) in decl {
+            if let Some((base, lane)) = lane_from_name(name) {
+                lanes.entry(base).or_default().push((lane, *width));
+            }
+        }
+        let is_decl = |id: &str| decl.iter().any(|(name, _)| name == id);
+        for (base, mut lane) in lanes {
+            lane.sort_unstable();
+            let width = lane[0].1;
+            let complete = lane
+                .iter()
+                .enumerate()
+                .all(|(i, (n, w))| *n == i as u64 && *w == width);
+            let length = lane.len() as u64;
+            let is_tmp = tmp.iter().any(|t| t == &format!("_{}", base));
+            if complete && !is_decl(base) && (length > 1 || is_tmp) {
+                let ty = xir::Ty::Vector(Rc::new(scalar_ty_from_width(width)), length);
+                map.ty.insert(base.to_string(), ty);
+                for (n, _) in lane {
+                    let net = Net {
+                        id: base.to_string(),
+                        lane: Some(n),
+                    };
+                    map.net.insert(format!("{}_{}", base, n), net);
+                }
+            }
+        }
+        for (name, width) in decl {
+            if !map.net.contains_key(name) {
+                let net = Net {
+                    id: name.to_string(),
+                    lane: None,
+                };
+                map.net.insert(name.to_string(), net);
+                map.ty
+                    .insert(name.to_string(), scalar_ty_from_width(*width));
+            }
+        }
+        map
+    }
+    pub fn get_net(&self, name: &str) -> Result<&Net, Error> {
+        if let Some(net) = self.net.get(name) {
+            Ok(net)
+        } else {
+            let err = format!("{} is not declared", name);
+            Err(Error::new_xtract_error(&err))
+        }
+    }
+    pub fn get_var(&self, id: &str) -> Result<xir::ExprTerm, Error> {
+        if let Some(ty) = self.ty.get(id) {
+            Ok(xir::ExprTerm::Var(id.to_string(), ty.clone()))
+        } else {
+            let err = format!("{} is not declared", id);
+            Err(Error::new_xtract_error(&err))
+        }
+    }
+    /// whole term driven by a wire, even if the wire is only one lane
+    pub fn get_term(&self, name: &str) -> Result<xir::ExprTerm, Error> {
+        let net = self.get_net(name)?;
+        self.get_var(&net.id)
+    }
+}
+
+fn sig_term_try_from_port(
+    map: &NetMap,
+    port: &[Port],
+    dir: Dir,
+) -> Result<Vec<xir::ExprTerm>, Error> {
+    let mut term: Vec<xir::ExprTerm> = Vec::new();
+    for p in port.iter().filter(|p| p.dir == dir) {
+        if p.id != CLOCK && p.id != RESET {
+            let t = map.get_term(&p.id)?;
+            if !term.contains(&t) {
+                term.push(t);
+            }
+        }
+    }
+    Ok(term)
+}
+
+fn tup_from_terms(term: Vec<xir::ExprTerm>) -> xir::Expr {
+    xir::Expr::from(xir::ExprTup::from(term))
+}
+
+fn ref_id_from_expr(expr: &Expr) -> Option<&Id> {
+    match expr {
+        Expr::Ref(id) => Some(id),
+        _ => None,
+    }
+}
+
+// constants are the gnd and vcc wires or a literal, concats are msb first
+fn value_try_from_expr(expr: &Expr) -> Result<i64, Error> {
+    match expr {
+        Expr::Ref(id) if id == GND => Ok(0),
+        Expr::Ref(id) if id == VCC => Ok(1),
+        Expr::Lit(_, value) => Ok(*value as i64),
+        Expr::Int(value) => Ok(*value as i64),
+        Expr::Concat(expr) => {
+            let mut value = 0;
+            for e in expr {
+                value = (value << 1) | value_try_from_expr(e)?;
+            }
+            Ok(value)
+        }
+        _ => {
+            let err = format!("{:?} is not a constant", expr);
+            Err(Error::new_xtract_error(&err))
+        }
+    }
+}
+
+fn param_try_from_instance(inst: &Instance, name: &str) -> Result<i64, Error> {
+    if let Some(expr) = inst.param(name) {
+        value_try_from_expr(expr)
+    } else {
+        let err = format!("{} has no {} parameter", inst.id, name);
+        Err(Error::new_xtract_error(&err))
+    }
+}
+
+fn conn_try_from_instance<'a>(inst: &'a Instance, port: &str) -> Result<&'a Expr, Error> {
+    if let Some(expr) = inst.conn(port) {
+        Ok(expr)
+    } else {
+        let err = format!("{} port of {} is not connected", port, inst.id);
+        Err(Error::new_xtract_error(&err))
+    }
+}
+
+fn input_value_try_from_instance(inst: &Instance, port: &str) -> Result<i64, Error> {
+    value_try_from_expr(conn_try_from_instance(inst, port)?)
+}
+
+// site names end with _X{x}Y{y}, and bel names are the xir ones in upper case
+fn loc_try_from_instance(inst: &Instance) -> Result<Option<xir::Loc>, Error> {
+    match (inst.attr("LOC"), inst.attr("BEL")) {
+        (Some(site), Some(bel)) => {
+            let err = format!("{} is not a valid site", site);
+            let coord = &site[site.rfind('_').map_or(0, |i| i + 1)..];
+            let coord = coord
+                .strip_prefix('X')
+                .ok_or_else(|| Error::new_xtract_error(&err))?;
+            let split = coord
+                .find('Y')
+                .ok_or_else(|| Error::new_xtract_error(&err))?;
+            let x = coord[..split].parse::<u64>()?;
+            let y = coord[split + 1..].parse::<u64>()?;
+            let bel = match bel.as_str() {
+                "DSP_ALU" => xir::Bel::Dsp(xir::BelDsp::Alu),
+                _ => xir::Bel::from_str(&bel.to_lowercase())?,
+            };
+            Ok(Some(xir::Loc {
+                bel,
+                x: xir::ExprCoord::Val(x),
+                y: xir::ExprCoord::Val(y),
+            }))
+        }
+        _ => Ok(None),
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Builder {
+    map: NetMap,
+    body: Vec<xir::Instr>,
+    // position of the cat defining every vector, the rest of lanes are appended
+    cat: HashMap<xir::Id, usize>,
+    // dsp driving every ACOUT wire
+    acout: HashMap<Id, Instance>,
+}
+
+impl Builder {
+    pub fn new(map: NetMap) -> Builder {
+        Builder {
+            map,
+            body: Vec::new(),
+            cat: HashMap::new(),
+            acout: HashMap::new(),
+        }
+    }
+    pub fn add_acout(&mut self, inst: &Instance) {
+        if let Some(id) = inst.conn("ACOUT").and_then(ref_id_from_expr) {
+            self.acout.insert(id.clone(), inst.clone());
+        }
+    }
+    fn term_try_from_conn(&self, inst: &Instance, port: &str) -> Result<xir::ExprTerm, Error> {
+        let expr = conn_try_from_instance(inst, port)?;
+        if let Some(id) = ref_id_from_expr(expr) {
+            self.map.get_term(id)
+        } else {
+            let err = format!("{} port of {} must be a wire", port, inst.id);
+            Err(Error::new_xtract_error(&err))
+        }
+    }
+    // dsp inputs are concats of operand bits and gnd padding
+    fn operand_try_from_conn(&self, inst: &Instance, port: &str) -> Result<xir::ExprTerm, Error> {
+        let expr = conn_try_from_instance(inst, port)?;
+        let bits = match expr {
+            Expr::Concat(expr) => expr.clone(),
+            _ => vec![expr.clone()],
+        };
+        for b in bits.iter() {
+            match b {
+                Expr::Bit(id, _) => return self.map.get_term(id),
+                Expr::Ref(id) if id != GND && id != VCC => return self.map.get_term(id),
+                _ => (),
+            }
+        }
+        let err = format!("{} port of {} has no operand", port, inst.id);
+        Err(Error::new_xtract_error(&err))
+    }
+    // A comes from the dsp driving ACIN when it is a cascade
+    fn a_operand_try_from_instance(&self, inst: &Instance) -> Result<xir::ExprTerm, Error> {
+        let acin = inst.conn("ACIN").and_then(ref_id_from_expr);
+        if let Some(prev) = acin.and_then(|id| self.acout.get(id)) {
+            self.a_operand_try_from_instance(prev)
+        } else {
+            self.operand_try_from_conn(inst, "A")
+        }
+    }
+    fn add_basc(&mut self, op: xir::OpBasc, attr: xir::Expr, dst: xir::ExprTerm, arg: xir::Expr) {
+        let instr = xir::InstrBasc {
+            op,
+            attr,
+            dst: xir::Expr::from(dst),
+            arg,
+        };
+        self.body.push(xir::Instr::from(instr));
+    }
+    fn add_cat(&mut self, dst: xir::ExprTerm, arg: Vec<xir::ExprTerm>) -> Result<(), Error> {
+        let id = dst.get_id()?;
+        if let Some(index) = self.cat.get(&id) {
+            if let xir::Instr::Basc(instr) = &mut self.body[*index] {
+                if let xir::Expr::Tup(tup) = &mut instr.arg {
+                    for a in arg {
+                        tup.add_term(a);
+                    }
+                }
+            }
+        } else {
+            self.cat.insert(id, self.body.len());
+            self.add_basc(
+                xir::OpBasc::Cat,
+                xir::Expr::default(),
+                dst,
+                tup_from_terms(arg),
+            );
+        }
+        Ok(())
+    }
+    pub fn add_assign(&mut self, lhs: &Expr, rhs: &Expr) -> Result<(), Error> {
+        let name = if let Some(name) = ref_id_from_expr(lhs) {
+            name
+        } else {
+            let err = format!("assign to {:?} is not supported", lhs);
+            return Err(Error::new_xtract_error(&err));
+        };
+        let lane = self.map.get_net(name)?.lane;
+        let dst = self.map.get_term(name)?;
+        match rhs {
+            // lanes of a dsp output, already defined by the instance
+            Expr::Slice(id, _, _) if id.starts_with('_') => Ok(()),
+            Expr::Ref(id) if id == GND && lane.is_none() => {
+                self.add_basc(
+                    xir::OpBasc::Gnd,
+                    xir::Expr::default(),
+                    dst,
+                    xir::Expr::default(),
+                );
+                Ok(())
+            }
+            Expr::Ref(id) if id == VCC && lane.is_none() => {
+                self.add_basc(
+                    xir::OpBasc::Vcc,
+                    xir::Expr::default(),
+                    dst,
+                    xir::Expr::default(),
+                );
+                Ok(())
+            }
+            // xpand only assigns the first lane of a vector id
+            Expr::Ref(id) => {
+                if lane.unwrap_or(0) == 0 {
+                    let arg = tup_from_terms(vec![self.map.get_term(id)?]);
+                    self.add_basc(xir::OpBasc::Id, xir::Expr::default(), dst, arg);
+                }
+                Ok(())
+            }
+            Expr::Bit(id, index) if lane.is_none() => {
+                let attr = tup_from_terms(vec![xir::ExprTerm::Val(*index as i64)]);
+                let arg = tup_from_terms(vec![self.map.get_term(id)?]);
+                self.add_basc(xir::OpBasc::Ext, attr, dst, arg);
+                Ok(())
+            }
+            Expr::Concat(expr) => {
+                let mut arg: Vec<xir::ExprTerm> = Vec::new();
+                for e in expr.iter().rev() {
+                    if let Some(id) = ref_id_from_expr(e) {
+                        arg.push(self.map.get_term(id)?);
+                    } else {
+                        let err = format!("cat of {:?} is not supported", e);
+                        return Err(Error::new_xtract_error(&err));
+                    }
+                }
+                self.add_cat(dst, arg)
+            }
+            _ => {
+                let err = format!("assign of {:?} to {} is not supported", rhs, name);
+                Err(Error::new_xtract_error(&err))
+            }
+        }
+    }
+    fn add_mach(
+        &mut self,
+        inst: &Instance,
+        op: xir::OpMach,
+        attr: xir::Expr,
+        dst: xir::ExprTerm,
+        arg: Vec<xir::ExprTerm>,
+    ) -> Result<(), Error> {
+        let instr = xir::InstrMach {
+            op,
+            attr,
+            dst: xir::Expr::from(dst),
+            arg: tup_from_terms(arg),
+            loc: loc_try_from_instance(inst)?,
+        };
+        self.body.push(xir::Instr::from(instr));
+        Ok(())
+    }
+    fn add_lut(&mut self, inst: &Instance, inputs: usize) -> Result<(), Error> {
+        let op = xir::OpMach::from_str(&inst.prim.to_lowercase())?;
+        let init = param_try_from_instance(inst, "INIT")?;
+        let attr = tup_from_terms(vec![xir::ExprTerm::Val(init)]);
+        let mut arg: Vec<xir::ExprTerm> = Vec::new();
+        for i in 0..inputs {
+            arg.push(self.term_try_from_conn(inst, &format!("I{}", i))?);
+        }
+        let dst = self.term_try_from_conn(inst, "O")?;
+        self.add_mach(inst, op, attr, dst, arg)
+    }
+    // asynchronous and inverted resets are not part of xir, so FDCE is
+    // read as fdre and FDPE as fdse
+    fn add_reg(&mut self, inst: &Instance, op: xir::OpMach) -> Result<(), Error> {
+        let d = self.term_try_from_conn(inst, "D")?;
+        let ce = self.term_try_from_conn(inst, "CE")?;
+        let dst = self.term_try_from_conn(inst, "Q")?;
+        self.add_mach(inst, op, xir::Expr::default(), dst, vec![d, ce])
+    }
+    // sub is an add with the carry in set
+    fn add_carry(&mut self, inst: &Instance) -> Result<(), Error> {
+        let op = if input_value_try_from_instance(inst, "CI")? == 1 {
+            xir::OpMach::CarrySub
+        } else {
+            xir::OpMach::CarryAdd
+        };
+        let di = self.term_try_from_conn(inst, "DI")?;
+        let s = self.term_try_from_conn(inst, "S")?;
+        let dst = self.term_try_from_conn(inst, "O")?;
+        self.add_mach(inst, op, xir::Expr::default(), dst, vec![di, s])
+    }
+    // P drives the temporary wire _{dst}, PCIN comes from _{arg}_pcout and
+    // ACIN from _{arg}_acout
+    fn add_dsp(&mut self, inst: &Instance) -> Result<(), Error> {
+        let output = conn_try_from_instance(inst, "P")?;
+        let dst = match ref_id_from_expr(output) {
+            Some(id) if id.starts_with('_') => self.map.get_var(&id[1..])?,
+            _ => {
+                let err = format!("P port of {} must be a temporary wire", inst.id);
+                return Err(Error::new_xtract_error(&err));
+            }
+        };
+        let opmode = input_value_try_from_instance(inst, "OPMODE")?;
+        let alumode = input_value_try_from_instance(inst, "ALUMODE")?;
+        let preg = param_try_from_instance(inst, "PREG")? == 1;
+        let pcout = inst.conn("PCOUT").is_some();
+        let (op, arg) = match opmode {
+            VADD_OPMODE => {
+                let c = self.operand_try_from_conn(inst, "C")?;
+                let ab = self.operand_try_from_conn(inst, "B")?;
+                if preg {
+                    let cec = self.term_try_from_conn(inst, "CEC")?;
+                    let cea = self.term_try_from_conn(inst, "CEA1")?;
+                    let cep = self.term_try_from_conn(inst, "CEP")?;
+                    (xir::OpMach::VecAddRegA, vec![c, ab, cec, cea, cep])
+                } else if alumode == VSUB_ALUMODE {
+                    (xir::OpMach::VecSub, vec![c, ab])
+                } else {
+                    (xir::OpMach::VecAdd, vec![c, ab])
+                }
+            }
+            MUL_OPMODE => {
+                let a = self.operand_try_from_conn(inst, "A")?;
+                let b = self.operand_try_from_conn(inst, "B")?;
+                if dst.is_vector() {
+                    (xir::OpMach::VecMul, vec![a, b])
+                } else {
+                    (xir::OpMach::Mul, vec![a, b])
+                }
+            }
+            MULADD_OPMODE | MULADD_CASCADE_OPMODE => {
+                let a = self.a_operand_try_from_instance(inst)?;
+                let b = self.operand_try_from_conn(inst, "B")?;
+                let c = if opmode == MULADD_OPMODE {
+                    self.operand_try_from_conn(inst, "C")?
+                } else {
+                    let pcin = conn_try_from_instance(inst, "PCIN")?;
+                    let id = ref_id_from_expr(pcin)
+                        .and_then(|id| id.strip_prefix('_'))
+                        .and_then(|id| id.strip_suffix("_pcout"));
+                    if let Some(id) = id {
+                        self.map.get_var(id)?
+                    } else {
+                        let err = format!("PCIN port of {} must be a cascade wire", inst.id);
+                        return Err(Error::new_xtract_error(&err));
+                    }
+                };
+                if preg {
+                    let cea = self.term_try_from_conn(inst, "CEA1")?;
+                    let ceb = self.term_try_from_conn(inst, "CEB1")?;
+                    let cem = self.term_try_from_conn(inst, "CEM")?;
+                    let cep = self.term_try_from_conn(inst, "CEP")?;
+                    let op = match (opmode == MULADD_OPMODE, pcout) {
+                        (true, false) => xir::OpMach::MulAddRegA,
+                        (true, true) => xir::OpMach::MulAddRegACo,
+                        (false, false) => xir::OpMach::MulAddRegACi,
+                        (false, true) => xir::OpMach::MulAddRegACio,
+                    };
+                    (op, vec![a, b, c, cea, ceb, cem, cep])
+                } else if opmode == MULADD_OPMODE {
+                    (xir::OpMach::MulAdd, vec![a, b, c])
+                } else {
+                    let err = format!("cascade of {} must be registered", inst.id);
+                    return Err(Error::new_xtract_error(&err));
+                }
+            }
+            _ => {
+                let err = format!("opmode {} of {} is not supported", opmode, inst.id);
+                return Err(Error::new_xtract_error(&err));
+            }
+        };
+        self.add_mach(inst, op, xir::Expr::default(), dst, arg)
+    }
+    pub fn add_instance(&mut self, inst: &Instance) -> Result<(), Error> {
+        match inst.prim.as_str() {
+            "GND" | "VCC" => Ok(()),
+            "LUT1" => self.add_lut(inst, 1),
+            "LUT2" => self.add_lut(inst, 2),
+            "LUT3" => self.add_lut(inst, 3),
+            "LUT4" => self.add_lut(inst, 4),
+            "LUT5" => self.add_lut(inst, 5),
+            "LUT6" => self.add_lut(inst, 6),
+            "FDRE" | "FDCE" => self.add_reg(inst, xir::OpMach::Fdre),
+            "FDSE" | "FDPE" => self.add_reg(inst, xir::OpMach::Fdse),
+            "CARRY8" => self.add_carry(inst),
+            "DSP48E2" => self.add_dsp(inst),
+            _ => {
+                let err = format!("{} is not supported", inst.prim);
+                Err(Error::new_xtract_error(&err))
+            }
+        }
+    }
+}
+
+/// rebuild a xir program from the structural Verilog written by xpand
+pub fn try_from_module(module: &Module) -> Result<xir::Prog, Error> {
+    let mut decl: Vec<(Id, u64)> = Vec::new();
+    let mut tmp: Vec<Id> = Vec::new();
+    for p in module.port.iter() {
+        if p.id != CLOCK && p.id != RESET {
+            decl.push((p.id.clone(), p.width));
+        }
+    }
+    for s in module.stmt.iter() {
+        if let Stmt::Wire(wire) = s {
+            if wire.id.starts_with('_') {
+                tmp.push(wire.id.clone());
+            } else if wire.id != GND && wire.id != VCC {
+                decl.push((wire.id.clone(), wire.width));
+            }
+        }
+    }
+    let map = NetMap::new(&decl, &tmp);
+    let input = sig_term_try_from_port(&map, &module.port, Dir::Input)?;
+    let output = sig_term_try_from_port(&map, &module.port, Dir::Output)?;
+    let sig = xir::Sig {
+        id: module.id.clone(),
+        input: tup_from_terms(input),
+        output: tup_from_terms(output),
+    };
+    let mut builder = Builder::new(map);
+    for s in module.stmt.iter() {
+        if let Stmt::Instance(inst) = s {
+            builder.add_acout(inst);
+        }
+    }
+    for s in module.stmt.iter() {
+        match s {
+            Stmt::Assign(lhs, rhs) => builder.add_assign(lhs, rhs)?,
+            Stmt::Instance(inst) => builder.add_instance(inst)?,
+            Stmt::Wire(_) => (),
+        }
+    }
+    let mut prog = xir::Prog::default();
+    prog.set_sig(sig);
+    prog.set_body(builder.body);
+    Ok(prog)
+}
@@ -0,0 +1,251 @@
+use crate::ast::*;
+use crate::errors::Error;
+use io::file::read_to_string;
+use pest_consume::match_nodes;
+use pest_consume::Error as PestError;
+use pest_consume::Parser as PestParser;
+use std::path::Path;
+
+pub type ParseResult<T> = std::result::Result<T, PestError<Rule>>;
+type Node<'i> = pest_consume::Node<'i, Rule, ()>;
+
+const _GRAMMAR: &str = include_str!("syntax.pest");
+
+#[derive(PestParser)]
+#[grammar = "syntax.pest"]
+pub struct Parser;
+
+#[pest_consume::parser]
+impl Parser {
+    fn EOI(_input: Node) -> ParseResult<()> {
+        Ok(())
+    }
+
+    fn id(input: Node) -> ParseResult<Id> {
+        Ok(input.as_str().to_string())
+    }
+
+    fn int(input: Node) -> ParseResult<u64> {
+        input.as_str().parse::<u64>().map_err(|e| input.error(e))
+    }
+
+    fn str_inner(input: Node) -> ParseResult<String> {
+        Ok(input.as_str().to_string())
+    }
+
+    fn str(input: Node) -> ParseResult<String> {
+        Ok(match_nodes!(
+            input.into_children();
+            [str_inner(s)] => s,
+        ))
+    }
+
+    fn radix(input: Node) -> ParseResult<u32> {
+        match input.as_str() {
+            "b" => Ok(2),
+            "h" => Ok(16),
+            _ => Ok(10),
+        }
+    }
+
+    fn digits(input: Node) -> ParseResult<String> {
+        Ok(input.as_str().replace("_", ""))
+    }
+
+    fn lit(input: Node) -> ParseResult<Expr> {
+        let span = input.clone();
+        let (width, radix, digits) = match_nodes!(
+            input.into_children();
+            [int(width), radix(radix), digits(digits)] => (width, radix, digits),
+        );
+        let value = u64::from_str_radix(&digits, radix).map_err(|e| span.error(e))?;
+        Ok(Expr::Lit(width, value))
+    }
+
+    fn width(input: Node) -> ParseResult<u64> {
+        Ok(match_nodes!(
+            input.into_children();
+            [int(hi), int(lo)] => hi - lo + 1,
+        ))
+    }
+
+    fn bit(input: Node) -> ParseResult<Expr> {
+        Ok(match_nodes!(
+            input.into_children();
+            [id(id), int(index)] => Expr::Bit(id, index),
+        ))
+    }
+
+    fn slice(input: Node) -> ParseResult<Expr> {
+        Ok(match_nodes!(
+            input.into_children();
+            [id(id), int(hi), int(lo)] => Expr::Slice(id, hi, lo),
+        ))
+    }
+
+    fn concat(input: Node) -> ParseResult<Expr> {
+        Ok(match_nodes!(
+            input.into_children();
+            [expr(expr)..] => Expr::Concat(expr.collect()),
+        ))
+    }
+
+    fn expr(input: Node) -> ParseResult<Expr> {
+        Ok(match_nodes!(
+            input.into_children();
+            [lit(lit)] => lit,
+            [str(s)] => Expr::Str(s),
+            [int(int)] => Expr::Int(int),
+            [concat(concat)] => concat,
+            [slice(slice)] => slice,
+            [bit(bit)] => bit,
+            [id(id)] => Expr::Ref(id),
+        ))
+    }
+
+    fn dir(input: Node) -> ParseResult<Dir> {
+        match input.as_str() {
+            "input" => Ok(Dir::Input),
+            _ => Ok(Dir::Output),
+        }
+    }
+
+    fn port(input: Node) -> ParseResult<Port> {
+        Ok(match_nodes!(
+            input.into_children();
+            [dir(dir), id(id)] => Port { dir, id, width: 1 },
+            [dir(dir), width(width), id(id)] => Port { dir, id, width },
+        ))
+    }
+
+    fn ports(input: Node) -> ParseResult<Vec<Port>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [port(port)..] => port.collect(),
+        ))
+    }
+
+    fn wire(input: Node) -> ParseResult<Stmt> {
+        Ok(match_nodes!(
+            input.into_children();
+            [id(id)] => Stmt::Wire(Wire { id, width: 1 }),
+            [width(width), id(id)] => Stmt::Wire(Wire { id, width }),
+        ))
+    }
+
+    fn assign(input: Node) -> ParseResult<Stmt> {
+        Ok(match_nodes!(
+            input.into_children();
+            [expr(lhs), expr(rhs)] => Stmt::Assign(lhs, rhs),
+        ))
+    }
+
+    fn attr(input: Node) -> ParseResult<(Id, String)> {
+        Ok(match_nodes!(
+            input.into_children();
+            [id(id), str(value)] => (id, value),
+        ))
+    }
+
+    fn attrs(input: Node) -> ParseResult<Vec<(Id, String)>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [attr(attr)..] => attr.collect(),
+        ))
+    }
+
+    fn param(input: Node) -> ParseResult<(Id, Expr)> {
+        Ok(match_nodes!(
+            input.into_children();
+            [id(id), expr(expr)] => (id, expr),
+        ))
+    }
+
+    fn params(input: Node) -> ParseResult<Vec<(Id, Expr)>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [param(param)..] => param.collect(),
+        ))
+    }
+
+    fn conn(input: Node) -> ParseResult<(Id, Option<Expr>)> {
+        Ok(match_nodes!(
+            input.into_children();
+            [id(id)] => (id, None),
+            [id(id), expr(expr)] => (id, Some(expr)),
+        ))
+    }
+
+    fn instance(input: Node) -> ParseResult<Stmt> {
+        Ok(match_nodes!(
+            input.into_children();
+            [id(prim), id(id), conn(conn)..] => Stmt::Instance(Instance {
+                attr: Vec::new(),
+                prim,
+                param: Vec::new(),
+                id,
+                conn: conn.collect(),
+            }),
+            [id(prim), params(param), id(id), conn(conn)..] => Stmt::Instance(Instance {
+                attr: Vec::new(),
+                prim,
+                param,
+                id,
+                conn: conn.collect(),
+            }),
+            [attrs(attr), id(prim), id(id), conn(conn)..] => Stmt::Instance(Instance {
+                attr,
+                prim,
+                param: Vec::new(),
+                id,
+                conn: conn.collect(),
+            }),
+            [attrs(attr), id(prim), params(param), id(id), conn(conn)..] => Stmt::Instance(Instance {
+                attr,
+                prim,
+                param,
+                id,
+                conn: conn.collect(),
+            }),
+        ))
+    }
+
+    fn stmt(input: Node) -> ParseResult<Stmt> {
+        Ok(match_nodes!(
+            input.into_children();
+            [wire(wire)] => wire,
+            [assign(assign)] => assign,
+            [instance(instance)] => instance,
+        ))
+    }
+
+    fn module(input: Node) -> ParseResult<Module> {
+        Ok(match_nodes!(
+            input.into_children();
+            [id(id), ports(port), stmt(stmt)..] => Module {
+                id,
+                port,
+                stmt: stmt.collect(),
+            },
+        ))
+    }
+
+    fn file(input: Node) -> ParseResult<Module> {
+        Ok(match_nodes!(
+            input.into_children();
+            [module(module), _] => module,
+        ))
+    }
+}
+
+impl Parser {
+    pub fn parse_from_str(input_str: &str) -> Result<Module, Error> {
+        let inputs = Parser::parse(Rule::file, input_str)?;
+        let input = inputs.single()?;
+        Ok(Parser::file(input)?)
+    }
+    pub fn parse_from_file<P: AsRef<Path>>(path: P) -> Result<Module, Error> {
+        let content = read_to_string(path);
+        Parser::parse_from_str(&content)
+    }
+}
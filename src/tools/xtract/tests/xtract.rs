@@ -0,0 +1,87 @@
+use std::path::Path;
+use tb::stimulus::Stimulus;
+use xir::parser::Parser as XirParser;
+use xsim::try_from_xir_prog;
+use xtract::errors::Error;
+use xtract::parser::Parser;
+use xtract::try_from_module;
+
+// structural Verilog must behave like the xir program it was expanded from
+fn test(name: &str) -> Result<(), Error> {
+    test_from(name, name)
+}
+
+// golden expanded from xir with other clock and reset settings
+fn test_from(golden: &str, name: &str) -> Result<(), Error> {
+    let mut v = Path::new("../../../examples/struct").join(golden);
+    let mut x = Path::new("../../../examples/xir").join(name);
+    v.set_extension("v");
+    x.set_extension("xir");
+    let module = Parser::parse_from_file(v)?;
+    let res = try_from_module(&module)?;
+    let exp = XirParser::parse_from_file(x)?;
+    let s = Stimulus::new_random(exp.sig(), 32, 0).unwrap();
+    let r = try_from_xir_prog(&res, &s).unwrap();
+    let e = try_from_xir_prog(&exp, &s).unwrap();
+    assert_eq!(r, e);
+    Ok(())
+}
+
+#[test]
+fn add() -> Result<(), Error> {
+    test("add")
+}
+
+#[test]
+fn tadd() -> Result<(), Error> {
+    test("tadd")
+}
+
+#[test]
+fn tdot() -> Result<(), Error> {
+    test("tdot")
+}
+
+#[test]
+fn fsm_3() -> Result<(), Error> {
+    test("fsm_3")
+}
+
+#[test]
+fn reg_active_low() -> Result<(), Error> {
+    test_from("reg_active_low", "reg")
+}
+
+#[test]
+fn reg_async() -> Result<(), Error> {
+    test_from("reg_async", "reg")
+}
+
+#[test]
+fn reg_no_reset() -> Result<(), Error> {
+    test_from("reg_no_reset", "reg")
+}
+
+#[test]
+fn tadd_active_low() -> Result<(), Error> {
+    test_from("tadd_active_low", "tadd")
+}
+
+#[test]
+fn addreduce_placed() -> Result<(), Error> {
+    let module = Parser::parse_from_file("../../../examples/struct/addreduce_placed.v")?;
+    let res = try_from_module(&module)?;
+    let exp = XirParser::parse_from_file("../../../examples/xir/addreduce_placed.xir")?;
+    assert_eq!(res.to_string(), exp.to_string());
+    Ok(())
+}
+
+#[test]
+fn tdot_5_3_opt() -> Result<(), Error> {
+    test("tdot_5_3_opt")
+}
+
+#[test]
+fn macc() -> Result<(), Error> {
+    test("macc")
+}
@@ -0,0 +1,71 @@
+pub type Id = String;
+
+/// a net, or a constant where undefined bits are zero
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Bit {
+    Net(Id),
+    Zero,
+    One,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Dir {
+    Input,
+    Output,
+}
+
+/// bits are lsb first
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Port {
+    pub dir: Dir,
+    pub id: Id,
+    pub signed: bool,
+    pub bits: Vec<Bit>,
+}
+
+/// numbers are binary strings, msb first
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Param {
+    Bits(String),
+    Str(String),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cell {
+    pub id: Id,
+    pub ty: Id,
+    pub param: Vec<(Id, Param)>,
+    pub conn: Vec<(Id, Vec<Bit>)>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Module {
+    pub id: Id,
+    pub port: Vec<Port>,
+    pub cell: Vec<Cell>,
+}
+
+impl Param {
+    /// value of the lower 64 bits, strings are zero
+    pub fn value(&self) -> i64 {
+        match self {
+            Param::Bits(bits) => bits
+                .chars()
+                .rev()
+                .take(64)
+                .enumerate()
+                .filter(|(_, c)| *c == '1')
+                .fold(0, |acc, (i, _)| acc | (1 << i)),
+            Param::Str(_) => 0,
+        }
+    }
+}
+
+impl Cell {
+    pub fn param(&self, name: &str) -> Option<&Param> {
+        self.param.iter().find(|(k, _)| k == name).map(|(_, v)| v)
+    }
+    pub fn conn(&self, name: &str) -> Option<&Vec<Bit>> {
+        self.conn.iter().find(|(k, _)| k == name).map(|(_, v)| v)
+    }
+}
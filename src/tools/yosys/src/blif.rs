@@ -0,0 +1,308 @@
+use crate::ast::*;
+use crate::errors::Error;
+use io::file::read_to_string;
+use std::path::Path;
+
+// Netlists written by write_blif. Only the first model is read, covers
+// become $sop cells, latches become $dff cells, and subckt and gate
+// lines are cells connected bit by bit, e.g. A[3]=a[3].
+
+pub struct Parser;
+
+// constant nets written by write_blif
+const FALSE: &str = "$false";
+const TRUE: &str = "$true";
+const UNDEF: &str = "$undef";
+
+fn bit_from_name(name: &str) -> Bit {
+    match name {
+        TRUE => Bit::One,
+        FALSE | UNDEF => Bit::Zero,
+        _ => Bit::Net(name.to_string()),
+    }
+}
+
+// a[3] is bit 3 of a, a plain name is bit 0
+fn index_from_name(name: &str) -> (&str, u64) {
+    if name.ends_with(']') {
+        if let Some(open) = name.rfind('[') {
+            if let Ok(index) = name[open + 1..name.len() - 1].parse::<u64>() {
+                return (&name[..open], index);
+            }
+        }
+    }
+    (name, 0)
+}
+
+// bits grouped by name in order of appearance, lsb first
+fn group_from_bits(bits: &[(&str, Bit)]) -> Vec<(Id, Vec<Bit>)> {
+    let mut group: Vec<(Id, Vec<(u64, Bit)>)> = Vec::new();
+    for (name, bit) in bits.iter() {
+        let (base, index) = index_from_name(name);
+        if let Some((_, v)) = group.iter_mut().find(|(k, _)| k == base) {
+            v.push((index, bit.clone()));
+        } else {
+            group.push((base.to_string(), vec![(index, bit.clone())]));
+        }
+    }
+    group
+        .into_iter()
+        .map(|(k, mut v)| {
+            v.sort_by_key(|(i, _)| *i);
+            (k, v.into_iter().map(|(_, b)| b).collect())
+        })
+        .collect()
+}
+
+fn ports_from_names(names: &[String], dir: Dir) -> Vec<Port> {
+    let bits: Vec<(&str, Bit)> = names
+        .iter()
+        .map(|n| (n.as_str(), Bit::Net(n.to_string())))
+        .collect();
+    group_from_bits(&bits)
+        .into_iter()
+        .map(|(id, bits)| Port {
+            dir: dir.clone(),
+            id,
+            signed: false,
+            bits,
+        })
+        .collect()
+}
+
+// lines without comments, with continuations joined
+fn lines_from_str(input: &str) -> Vec<Vec<String>> {
+    let mut lines: Vec<Vec<String>> = Vec::new();
+    let mut cont = false;
+    for line in input.lines() {
+        let line = line.split('#').next().unwrap_or("");
+        let (line, next) = match line.trim_end().strip_suffix('\\') {
+            Some(line) => (line, true),
+            None => (line, false),
+        };
+        let words: Vec<String> = line.split_whitespace().map(|w| w.to_string()).collect();
+        if cont {
+            if let Some(last) = lines.last_mut() {
+                last.extend(words);
+            }
+        } else if !words.is_empty() {
+            lines.push(words);
+        }
+        cont = next;
+    }
+    lines
+}
+
+fn param_from_str(value: &str) -> Param {
+    if value.starts_with('"') {
+        Param::Str(value.trim_matches('"').to_string())
+    } else if !value.is_empty() && value.chars().all(|c| "01xz".contains(c)) {
+        Param::Bits(value.replace('x', "0").replace('z', "0"))
+    } else {
+        Param::Str(value.to_string())
+    }
+}
+
+fn bits_param(bits: &[bool]) -> Param {
+    Param::Bits(
+        bits.iter()
+            .rev()
+            .map(|b| if *b { '1' } else { '0' })
+            .collect(),
+    )
+}
+
+fn width_param(width: usize) -> Param {
+    Param::Bits(format!("{:b}", width))
+}
+
+// cover lines of a .names, an output of 0 means the cover is the off-set
+fn sop_try_from_cover(
+    id: &str,
+    input: &[String],
+    output: &str,
+    cover: &[Vec<String>],
+) -> Result<Vec<Cell>, Error> {
+    let mut table: Vec<bool> = Vec::new();
+    let mut offset = false;
+    for line in cover.iter() {
+        let (cube, value) = match line.as_slice() {
+            [value] if input.is_empty() => ("", value),
+            [cube, value] => (cube.as_str(), value),
+            _ => {
+                let err = format!("invalid cover line {} of {}", line.join(" "), output);
+                return Err(Error::new_yosys_error(&err));
+            }
+        };
+        if cube.len() != input.len() {
+            let err = format!("cover of {} must have {} inputs", output, input.len());
+            return Err(Error::new_yosys_error(&err));
+        }
+        offset = value == "0";
+        for c in cube.chars() {
+            table.push(c == '0');
+            table.push(c == '1');
+        }
+    }
+    let y = if offset {
+        format!("{}$sop", output)
+    } else {
+        output.to_string()
+    };
+    let sop = Cell {
+        id: id.to_string(),
+        ty: "$sop".to_string(),
+        param: vec![
+            ("WIDTH".to_string(), width_param(input.len())),
+            ("DEPTH".to_string(), width_param(cover.len())),
+            ("TABLE".to_string(), bits_param(&table)),
+        ],
+        conn: vec![
+            (
+                "A".to_string(),
+                input.iter().map(|i| bit_from_name(i)).collect(),
+            ),
+            ("Y".to_string(), vec![bit_from_name(&y)]),
+        ],
+    };
+    let mut cells = vec![sop];
+    if offset {
+        cells.push(Cell {
+            id: format!("{}$not", id),
+            ty: "$_NOT_".to_string(),
+            param: Vec::new(),
+            conn: vec![
+                ("A".to_string(), vec![bit_from_name(&y)]),
+                ("Y".to_string(), vec![bit_from_name(output)]),
+            ],
+        });
+    }
+    Ok(cells)
+}
+
+// .latch input output [type control] [init], only rising edges
+fn dff_try_from_latch(id: &str, word: &[String]) -> Result<Cell, Error> {
+    let (d, q, ctrl, init) = match word {
+        [d, q] => (d, q, None, None),
+        [d, q, init] => (d, q, None, Some(init)),
+        [d, q, ty, ctrl] => (d, q, Some((ty, ctrl)), None),
+        [d, q, ty, ctrl, init] => (d, q, Some((ty, ctrl)), Some(init)),
+        _ => return Err(Error::new_yosys_error("invalid latch")),
+    };
+    let mut cell = Cell {
+        id: id.to_string(),
+        ty: "$dff".to_string(),
+        ..Cell::default()
+    };
+    cell.conn.push(("D".to_string(), vec![bit_from_name(d)]));
+    cell.conn.push(("Q".to_string(), vec![bit_from_name(q)]));
+    if let Some((ty, ctrl)) = ctrl {
+        if ty != "re" {
+            let err = format!("{} latches are not supported", ty);
+            return Err(Error::new_yosys_error(&err));
+        }
+        if ctrl != "NIL" {
+            cell.conn
+                .push(("CLK".to_string(), vec![bit_from_name(ctrl)]));
+        }
+    }
+    let init = init.map_or(false, |i| i == "1");
+    cell.param.push(("INIT".to_string(), bits_param(&[init])));
+    Ok(cell)
+}
+
+fn cell_try_from_subckt(id: &str, word: &[String]) -> Result<Cell, Error> {
+    let mut conn: Vec<(&str, Bit)> = Vec::new();
+    for w in word.iter().skip(1) {
+        if let Some(eq) = w.find('=') {
+            conn.push((&w[..eq], bit_from_name(&w[eq + 1..])));
+        } else {
+            let err = format!("invalid connection {}", w);
+            return Err(Error::new_yosys_error(&err));
+        }
+    }
+    Ok(Cell {
+        id: id.to_string(),
+        ty: word[0].to_string(),
+        param: Vec::new(),
+        conn: group_from_bits(&conn),
+    })
+}
+
+impl Parser {
+    pub fn parse_from_str(input: &str) -> Result<Module, Error> {
+        let lines = lines_from_str(input);
+        let mut module = Module::default();
+        let mut i = 0;
+        while i < lines.len() {
+            let line = &lines[i];
+            let id = format!("$cell{}", module.cell.len());
+            i += 1;
+            match line[0].as_str() {
+                ".model" => {
+                    if !module.id.is_empty() {
+                        break;
+                    }
+                    module.id = line.get(1).cloned().unwrap_or_default();
+                }
+                ".inputs" => module.port.extend(ports_from_names(&line[1..], Dir::Input)),
+                ".outputs" => module
+                    .port
+                    .extend(ports_from_names(&line[1..], Dir::Output)),
+                ".names" => {
+                    let mut cover: Vec<Vec<String>> = Vec::new();
+                    while i < lines.len() && !lines[i][0].starts_with('.') {
+                        cover.push(lines[i].clone());
+                        i += 1;
+                    }
+                    if let Some((output, input)) = line[1..].split_last() {
+                        // constant nets are already constants
+                        if bit_from_name(output) != Bit::Net(output.to_string()) {
+                            continue;
+                        }
+                        module
+                            .cell
+                            .extend(sop_try_from_cover(&id, input, output, &cover)?);
+                    } else {
+                        return Err(Error::new_yosys_error(".names must have an output"));
+                    }
+                }
+                ".latch" => module.cell.push(dff_try_from_latch(&id, &line[1..])?),
+                ".subckt" | ".gate" if line.len() > 1 => {
+                    module.cell.push(cell_try_from_subckt(&id, &line[1..])?)
+                }
+                ".conn" if line.len() == 3 => module.cell.push(Cell {
+                    id,
+                    ty: "$_BUF_".to_string(),
+                    param: Vec::new(),
+                    conn: vec![
+                        ("A".to_string(), vec![bit_from_name(&line[1])]),
+                        ("Y".to_string(), vec![bit_from_name(&line[2])]),
+                    ],
+                }),
+                ".param" if line.len() == 3 => {
+                    if let Some(cell) = module.cell.last_mut() {
+                        cell.param
+                            .push((line[1].to_string(), param_from_str(&line[2])));
+                    }
+                }
+                ".cname" if line.len() == 2 => {
+                    if let Some(cell) = module.cell.last_mut() {
+                        cell.id = line[1].to_string();
+                    }
+                }
+                ".end" => break,
+                // attributes and the rest of the extensions
+                _ => (),
+            }
+        }
+        if module.id.is_empty() {
+            Err(Error::new_yosys_error("model not found"))
+        } else {
+            Ok(module)
+        }
+    }
+    pub fn parse_from_file<P: AsRef<Path>>(path: P) -> Result<Module, Error> {
+        Parser::parse_from_str(&read_to_string(path))
+    }
+}
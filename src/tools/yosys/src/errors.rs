@@ -0,0 +1,47 @@
+use ir::errors::Error as IrError;
+use serde_json::Error as JsonError;
+use std::fmt;
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+pub enum Error {
+    Ir(IrError),
+    Json(JsonError),
+    Yosys(String),
+    ParseInt(ParseIntError),
+}
+
+impl Error {
+    pub fn new_yosys_error(msg: &str) -> Self {
+        Error::Yosys(msg.to_string())
+    }
+}
+
+impl From<IrError> for Error {
+    fn from(e: IrError) -> Self {
+        Error::Ir(e)
+    }
+}
+
+impl From<JsonError> for Error {
+    fn from(e: JsonError) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Error::ParseInt(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Ir(msg) => write!(f, "{}", msg),
+            Error::Json(msg) => write!(f, "{}", msg),
+            Error::Yosys(msg) => write!(f, "{}", msg),
+            Error::ParseInt(msg) => write!(f, "{}", msg),
+        }
+    }
+}
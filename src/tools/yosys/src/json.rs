@@ -0,0 +1,145 @@
+use crate::ast::*;
+use crate::errors::Error;
+use io::file::read_to_string;
+use serde_json::{Map, Value};
+use std::path::Path;
+
+// Netlists written by write_json. Nets are numbers, constants are the
+// strings "0", "1", "x" and "z", and numeric parameters are binary
+// strings, or numbers with -compat-int.
+
+pub struct Parser;
+
+fn bit_try_from_value(value: &Value) -> Result<Bit, Error> {
+    match value {
+        Value::Number(n) => Ok(Bit::Net(n.to_string())),
+        Value::String(s) if s == "1" => Ok(Bit::One),
+        Value::String(s) if s == "0" || s == "x" || s == "z" => Ok(Bit::Zero),
+        _ => {
+            let err = format!("{} is not a valid bit", value);
+            Err(Error::new_yosys_error(&err))
+        }
+    }
+}
+
+fn bits_try_from_value(value: Option<&Value>) -> Result<Vec<Bit>, Error> {
+    if let Some(Value::Array(bits)) = value {
+        bits.iter().map(bit_try_from_value).collect()
+    } else {
+        Err(Error::new_yosys_error("bits must be an array"))
+    }
+}
+
+// strings that look like numbers end with a space
+fn param_from_value(value: &Value) -> Param {
+    match value {
+        Value::Number(n) => Param::Bits(format!("{:b}", n.as_i64().unwrap_or(0))),
+        Value::String(s) if !s.is_empty() && s.chars().all(|c| "01xz".contains(c)) => {
+            Param::Bits(s.replace('x', "0").replace('z', "0"))
+        }
+        Value::String(s) => Param::Str(s.strip_suffix(' ').unwrap_or(s).to_string()),
+        _ => Param::Str(value.to_string()),
+    }
+}
+
+fn is_true(value: Option<&Value>) -> bool {
+    match value {
+        Some(value) => param_from_value(value).value() != 0,
+        None => false,
+    }
+}
+
+fn object_try_from_value<'a>(
+    value: Option<&'a Value>,
+    name: &str,
+) -> Result<&'a Map<String, Value>, Error> {
+    if let Some(Value::Object(object)) = value {
+        Ok(object)
+    } else {
+        let err = format!("{} must be an object", name);
+        Err(Error::new_yosys_error(&err))
+    }
+}
+
+fn first_net(port: &Port) -> u64 {
+    port.bits
+        .iter()
+        .find_map(|b| match b {
+            Bit::Net(n) => n.parse::<u64>().ok(),
+            _ => None,
+        })
+        .unwrap_or(u64::MAX)
+}
+
+fn module_try_from_value(id: &str, value: &Value) -> Result<Module, Error> {
+    let mut module = Module {
+        id: id.to_string(),
+        ..Module::default()
+    };
+    let empty = Map::new();
+    let ports = object_try_from_value(value.get("ports"), "ports").unwrap_or(&empty);
+    for (name, port) in ports.iter() {
+        let dir = match port.get("direction").and_then(|d| d.as_str()) {
+            Some("input") => Dir::Input,
+            Some("output") => Dir::Output,
+            _ => {
+                let err = format!("port {} must be an input or an output", name);
+                return Err(Error::new_yosys_error(&err));
+            }
+        };
+        module.port.push(Port {
+            dir,
+            id: name.to_string(),
+            signed: is_true(port.get("signed")),
+            bits: bits_try_from_value(port.get("bits"))?,
+        });
+    }
+    // ports are sorted by name, nets are numbered in declaration order
+    module.port.sort_by_key(first_net);
+    let cells = object_try_from_value(value.get("cells"), "cells").unwrap_or(&empty);
+    for (name, cell) in cells.iter() {
+        let ty = if let Some(ty) = cell.get("type").and_then(|t| t.as_str()) {
+            ty.to_string()
+        } else {
+            let err = format!("cell {} does not have a type", name);
+            return Err(Error::new_yosys_error(&err));
+        };
+        let mut c = Cell {
+            id: name.to_string(),
+            ty,
+            ..Cell::default()
+        };
+        let param = object_try_from_value(cell.get("parameters"), "parameters").unwrap_or(&empty);
+        for (k, v) in param.iter() {
+            c.param.push((k.to_string(), param_from_value(v)));
+        }
+        let conn = object_try_from_value(cell.get("connections"), "connections")?;
+        for (k, v) in conn.iter() {
+            c.conn.push((k.to_string(), bits_try_from_value(Some(v))?));
+        }
+        module.cell.push(c);
+    }
+    Ok(module)
+}
+
+impl Parser {
+    /// the module with the top attribute, or the only one
+    pub fn parse_from_str(input: &str) -> Result<Module, Error> {
+        let value: Value = serde_json::from_str(input)?;
+        let modules = object_try_from_value(value.get("modules"), "modules")?;
+        let top = modules
+            .iter()
+            .find(|(_, m)| is_true(m.get("attributes").and_then(|a| a.get("top"))));
+        match top {
+            Some((id, m)) => module_try_from_value(id, m),
+            None if modules.len() == 1 => {
+                let (id, m) = modules.iter().next().unwrap();
+                module_try_from_value(id, m)
+            }
+            None => Err(Error::new_yosys_error("top module not found")),
+        }
+    }
+    pub fn parse_from_file<P: AsRef<Path>>(path: P) -> Result<Module, Error> {
+        Parser::parse_from_str(&read_to_string(path))
+    }
+}
@@ -0,0 +1,682 @@
+pub mod ast;
+pub mod blif;
+pub mod errors;
+pub mod json;
+
+use crate::ast::*;
+use crate::errors::Error;
+use ir::ast as ir;
+use std::collections::{HashMap, HashSet};
+
+// names of register ports, for word and single bit cells
+const CLOCK: &[&str] = &["CLK", "C"];
+const ENABLE: &[&str] = &["EN", "E"];
+const RESET: &[&str] = &["SRST", "ARST", "R"];
+
+const REG: &[&str] = &[
+    "$dff",
+    "$dffe",
+    "$sdff",
+    "$sdffe",
+    "$sdffce",
+    "$adff",
+    "$adffe",
+    "$_DFF_P_",
+    "$_DFFE_PP_",
+    "$_DFF_PP0_",
+    "$_DFF_PP1_",
+    "$_DFFE_PP0P_",
+    "$_DFFE_PP1P_",
+    "$_SDFF_PP0_",
+    "$_SDFF_PP1_",
+    "$_SDFFE_PP0P_",
+    "$_SDFFE_PP1P_",
+];
+
+// words are signed like the ones in the pattern libraries, operands
+// are extended explicitly so only comparisons care about the sign
+fn ty_from_width(width: u64) -> ir::Ty {
+    if width == 1 {
+        ir::Ty::Bool
+    } else {
+        ir::Ty::SInt(width)
+    }
+}
+
+// ir names start with a letter and only have letters, digits, _ and -
+fn id_from_name(name: &str) -> Id {
+    let id: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if id.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        id
+    } else {
+        format!("p{}", id)
+    }
+}
+
+fn is_reg(cell: &Cell) -> bool {
+    REG.contains(&cell.ty.as_str())
+}
+
+fn flag_from_cell(cell: &Cell, name: &str, default: bool) -> bool {
+    cell.param(name).map_or(default, |p| p.value() != 0)
+}
+
+// operands are only sign extended if all of them are signed
+fn is_signed(cell: &Cell) -> bool {
+    flag_from_cell(cell, "A_SIGNED", false)
+        && (cell.conn("B").is_none() || flag_from_cell(cell, "B_SIGNED", false))
+}
+
+fn conn_from_cell<'a>(cell: &'a Cell, port: &[&str]) -> Option<&'a Vec<Bit>> {
+    port.iter().find_map(|p| cell.conn(p))
+}
+
+fn conn_try_from_cell<'a>(cell: &'a Cell, port: &[&str]) -> Result<&'a Vec<Bit>, Error> {
+    if let Some(bits) = conn_from_cell(cell, port) {
+        Ok(bits)
+    } else {
+        let err = format!("{} port of {} is not connected", port[0], cell.id);
+        Err(Error::new_yosys_error(&err))
+    }
+}
+
+fn output_try_from_cell(cell: &Cell) -> Result<&Vec<Bit>, Error> {
+    if is_reg(cell) {
+        conn_try_from_cell(cell, &["Q"])
+    } else {
+        conn_try_from_cell(cell, &["Y"])
+    }
+}
+
+// bits extended with zeros, or with the msb if signed
+fn resize(bits: &[Bit], width: usize, signed: bool) -> Vec<Bit> {
+    let fill = if signed {
+        bits.last().cloned().unwrap_or(Bit::Zero)
+    } else {
+        Bit::Zero
+    };
+    let mut bits = bits.to_vec();
+    bits.resize(width, fill);
+    bits
+}
+
+fn value_from_bits(bits: &[Bit]) -> i64 {
+    bits.iter()
+        .take(64)
+        .enumerate()
+        .filter(|(_, b)| **b == Bit::One)
+        .fold(0, |acc, (i, _)| acc | (1 << i))
+}
+
+fn value_try_from_bits(cell: &Cell, bits: &[Bit]) -> Result<i64, Error> {
+    if bits.iter().all(|b| !matches!(b, Bit::Net(_))) {
+        Ok(value_from_bits(bits))
+    } else {
+        let err = format!("{} cells must have a constant B", cell.ty);
+        Err(Error::new_yosys_error(&err))
+    }
+}
+
+fn tup_from_terms(term: Vec<ir::ExprTerm>) -> ir::Expr {
+    ir::Expr::from(ir::ExprTup::from(term))
+}
+
+fn attr_from_values(value: &[i64]) -> ir::Expr {
+    tup_from_terms(value.iter().map(|v| ir::ExprTerm::Val(*v)).collect())
+}
+
+// bits of a term taken from a var, or constants, lsb first
+enum Run {
+    Var(ir::ExprTerm, u64, u64),
+    Const(Vec<Bit>),
+}
+
+#[derive(Clone, Debug, Default)]
+struct Builder {
+    next: u64,
+    used: HashSet<Id>,
+    bit: HashMap<Id, (ir::ExprTerm, u64)>,
+    memo: HashMap<(Vec<Bit>, ir::Ty), ir::ExprTerm>,
+    body: Vec<ir::Instr>,
+}
+
+impl Builder {
+    pub fn add_var(&mut self, id: &str, ty: ir::Ty) -> Result<ir::ExprTerm, Error> {
+        if self.used.insert(id.to_string()) {
+            Ok(ir::ExprTerm::Var(id.to_string(), ty))
+        } else {
+            let err = format!("{} is defined more than once", id);
+            Err(Error::new_yosys_error(&err))
+        }
+    }
+    pub fn new_var(&mut self, ty: ir::Ty) -> ir::ExprTerm {
+        loop {
+            let id = format!("t{}", self.next);
+            self.next += 1;
+            if let Ok(term) = self.add_var(&id, ty.clone()) {
+                return term;
+            }
+        }
+    }
+    // bits driven by term
+    pub fn define(&mut self, term: &ir::ExprTerm, bits: &[Bit]) {
+        for (i, b) in bits.iter().enumerate() {
+            if let Bit::Net(n) = b {
+                self.bit.insert(n.to_string(), (term.clone(), i as u64));
+            }
+        }
+    }
+    pub fn add_wire(
+        &mut self,
+        dst: &ir::ExprTerm,
+        op: ir::OpWire,
+        attr: &[i64],
+        arg: Vec<ir::ExprTerm>,
+    ) {
+        self.body.push(ir::Instr::from(ir::InstrWire {
+            op,
+            dst: ir::Expr::from(dst.clone()),
+            attr: attr_from_values(attr),
+            arg: tup_from_terms(arg),
+        }));
+    }
+    pub fn add_prim(
+        &mut self,
+        dst: &ir::ExprTerm,
+        op: ir::OpPrim,
+        attr: &[i64],
+        arg: Vec<ir::ExprTerm>,
+    ) {
+        self.body.push(ir::Instr::from(ir::InstrPrim {
+            op,
+            dst: ir::Expr::from(dst.clone()),
+            attr: attr_from_values(attr),
+            arg: tup_from_terms(arg),
+            prim: ir::Prim::Any,
+        }));
+    }
+    pub fn wire(
+        &mut self,
+        ty: ir::Ty,
+        op: ir::OpWire,
+        attr: &[i64],
+        arg: Vec<ir::ExprTerm>,
+    ) -> ir::ExprTerm {
+        let dst = self.new_var(ty);
+        self.add_wire(&dst, op, attr, arg);
+        dst
+    }
+    pub fn prim(&mut self, ty: ir::Ty, op: ir::OpPrim, arg: Vec<ir::ExprTerm>) -> ir::ExprTerm {
+        let dst = self.new_var(ty);
+        self.add_prim(&dst, op, &[], arg);
+        dst
+    }
+    /// term of type ty made of bits, with ext, const and cat if they do
+    /// not come from a single var
+    pub fn term_try_from_bits(&mut self, bits: &[Bit], ty: &ir::Ty) -> Result<ir::ExprTerm, Error> {
+        let key = (bits.to_vec(), ty.clone());
+        if let Some(term) = self.memo.get(&key) {
+            return Ok(term.clone());
+        }
+        let mut runs: Vec<Run> = Vec::new();
+        for b in bits.iter() {
+            match b {
+                Bit::Net(n) => {
+                    let (var, index) = if let Some(bit) = self.bit.get(n) {
+                        bit.clone()
+                    } else {
+                        let err = format!("net {} is not driven", n);
+                        return Err(Error::new_yosys_error(&err));
+                    };
+                    if let Some(Run::Var(v, lsb, len)) = runs.last_mut() {
+                        if *v == var && *lsb + *len == index {
+                            *len += 1;
+                            continue;
+                        }
+                    }
+                    runs.push(Run::Var(var, index, 1));
+                }
+                _ => {
+                    if let Some(Run::Const(c)) = runs.last_mut() {
+                        c.push(b.clone());
+                    } else {
+                        runs.push(Run::Const(vec![b.clone()]));
+                    }
+                }
+            }
+        }
+        let term = match runs.as_slice() {
+            [Run::Const(c)] => {
+                let value = value_from_bits(c);
+                self.wire(ty.clone(), ir::OpWire::Con, &[value], vec![])
+            }
+            _ => {
+                let mut arg: Vec<ir::ExprTerm> = Vec::new();
+                for run in runs.iter() {
+                    let term = match run {
+                        Run::Var(v, 0, len) if v.width() == Some(*len) => v.clone(),
+                        Run::Var(v, lsb, len) => {
+                            let ty = ty_from_width(*len);
+                            self.wire(ty, ir::OpWire::Ext, &[*lsb as i64], vec![v.clone()])
+                        }
+                        Run::Const(c) => {
+                            let ty = ty_from_width(c.len() as u64);
+                            self.wire(ty, ir::OpWire::Con, &[value_from_bits(c)], vec![])
+                        }
+                    };
+                    arg.push(term);
+                }
+                if arg.len() == 1 && arg[0].get_ty()? == ty {
+                    arg[0].clone()
+                } else if arg.len() == 1 {
+                    self.wire(ty.clone(), ir::OpWire::Id, &[], arg)
+                } else {
+                    self.wire(ty.clone(), ir::OpWire::Cat, &[], arg)
+                }
+            }
+        };
+        self.memo.insert(key, term.clone());
+        Ok(term)
+    }
+    pub fn bool_try_from_bit(&mut self, bit: &Bit) -> Result<ir::ExprTerm, Error> {
+        self.term_try_from_bits(std::slice::from_ref(bit), &ir::Ty::Bool)
+    }
+    // port of cell resized to the type of dst
+    pub fn operand_try_from_cell(
+        &mut self,
+        cell: &Cell,
+        port: &str,
+        ty: &ir::Ty,
+        signed: bool,
+    ) -> Result<ir::ExprTerm, Error> {
+        let width = ty.width().unwrap_or(1) as usize;
+        let bits = resize(conn_try_from_cell(cell, &[port])?, width, signed);
+        self.term_try_from_bits(&bits, ty)
+    }
+    // bool result zero extended to dst
+    pub fn add_bool(&mut self, dst: &ir::ExprTerm, term: ir::ExprTerm) -> Result<(), Error> {
+        let width = dst.width().unwrap_or(1);
+        if width == 1 {
+            self.add_wire(dst, ir::OpWire::Id, &[], vec![term]);
+        } else {
+            let zero = vec![Bit::Zero; (width - 1) as usize];
+            let zero = self.term_try_from_bits(&zero, &ty_from_width(width - 1))?;
+            self.add_wire(dst, ir::OpWire::Cat, &[], vec![term, zero]);
+        }
+        Ok(())
+    }
+    pub fn add_bool_prim(
+        &mut self,
+        dst: &ir::ExprTerm,
+        op: ir::OpPrim,
+        arg: Vec<ir::ExprTerm>,
+    ) -> Result<(), Error> {
+        if dst.width() == Some(1) {
+            self.add_prim(dst, op, &[], arg);
+            Ok(())
+        } else {
+            let term = self.prim(ir::Ty::Bool, op, arg);
+            self.add_bool(dst, term)
+        }
+    }
+    // port compared against zero, or against all ones
+    pub fn reduce_try_from_cell(
+        &mut self,
+        cell: &Cell,
+        port: &str,
+        op: ir::OpPrim,
+        ones: bool,
+    ) -> Result<(ir::OpPrim, Vec<ir::ExprTerm>), Error> {
+        let bits = conn_try_from_cell(cell, &[port])?;
+        let ty = ty_from_width(bits.len() as u64);
+        let a = self.term_try_from_bits(bits, &ty)?;
+        let fill = if ones { Bit::One } else { Bit::Zero };
+        let k = self.term_try_from_bits(&vec![fill; bits.len()], &ty)?;
+        Ok((op, vec![a, k]))
+    }
+    pub fn is_nonzero_try_from_cell(
+        &mut self,
+        cell: &Cell,
+        port: &str,
+    ) -> Result<ir::ExprTerm, Error> {
+        let (op, arg) = self.reduce_try_from_cell(cell, port, ir::OpPrim::Neql, false)?;
+        Ok(self.prim(ir::Ty::Bool, op, arg))
+    }
+    // or of products, where a product is a list of inputs and their values
+    pub fn sop_try_from_cubes(
+        &mut self,
+        input: &[Bit],
+        cube: &[Vec<(usize, bool)>],
+    ) -> Result<ir::ExprTerm, Error> {
+        let mut sum: Option<ir::ExprTerm> = None;
+        for c in cube.iter() {
+            let mut product: Option<ir::ExprTerm> = None;
+            for (j, value) in c.iter() {
+                let mut lit = self.bool_try_from_bit(&input[*j])?;
+                if !value {
+                    lit = self.prim(ir::Ty::Bool, ir::OpPrim::Not, vec![lit]);
+                }
+                product = Some(match product {
+                    Some(p) => self.prim(ir::Ty::Bool, ir::OpPrim::And, vec![p, lit]),
+                    None => lit,
+                });
+            }
+            let product = match product {
+                Some(p) => p,
+                None => self.bool_try_from_bit(&Bit::One)?,
+            };
+            sum = Some(match sum {
+                Some(s) => self.prim(ir::Ty::Bool, ir::OpPrim::Or, vec![s, product]),
+                None => product,
+            });
+        }
+        match sum {
+            Some(s) => Ok(s),
+            None => self.bool_try_from_bit(&Bit::Zero),
+        }
+    }
+    pub fn add_reg(&mut self, cell: &Cell, dst: &ir::ExprTerm) -> Result<(), Error> {
+        if !flag_from_cell(cell, "CLK_POLARITY", true) {
+            let err = format!("{} is a negative edge register", cell.id);
+            return Err(Error::new_yosys_error(&err));
+        }
+        let ty = dst.get_ty()?.clone();
+        let d = self.operand_try_from_cell(cell, "D", &ty, false)?;
+        let en = if let Some(bits) = conn_from_cell(cell, ENABLE) {
+            let en = self.bool_try_from_bit(&bits[0])?;
+            if flag_from_cell(cell, "EN_POLARITY", true) {
+                en
+            } else {
+                self.prim(ir::Ty::Bool, ir::OpPrim::Not, vec![en])
+            }
+        } else {
+            self.bool_try_from_bit(&Bit::One)?
+        };
+        let init = ["SRST_VALUE", "ARST_VALUE", "INIT"]
+            .iter()
+            .find_map(|p| cell.param(p))
+            .map_or(i64::from(cell.ty.contains("PP1")), |p| p.value());
+        self.add_prim(dst, ir::OpPrim::Reg, &[init], vec![d, en]);
+        Ok(())
+    }
+    pub fn add_cell(&mut self, cell: &Cell, dst: &ir::ExprTerm) -> Result<(), Error> {
+        let ty = dst.get_ty()?.clone();
+        let signed = is_signed(cell);
+        let binop = |op| match op {
+            "$add" => Some((ir::OpPrim::Add, false)),
+            "$sub" => Some((ir::OpPrim::Sub, false)),
+            "$mul" => Some((ir::OpPrim::Mul, false)),
+            "$and" | "$_AND_" => Some((ir::OpPrim::And, false)),
+            "$or" | "$_OR_" => Some((ir::OpPrim::Or, false)),
+            "$xor" | "$_XOR_" => Some((ir::OpPrim::Xor, false)),
+            "$_NAND_" => Some((ir::OpPrim::And, true)),
+            "$_NOR_" => Some((ir::OpPrim::Or, true)),
+            "$xnor" | "$_XNOR_" => Some((ir::OpPrim::Xor, true)),
+            _ => None,
+        };
+        let cmp = |op| match op {
+            "$eq" | "$eqx" => Some(ir::OpPrim::Eql),
+            "$ne" | "$nex" => Some(ir::OpPrim::Neql),
+            "$lt" => Some(ir::OpPrim::Lt),
+            "$le" => Some(ir::OpPrim::Le),
+            "$gt" => Some(ir::OpPrim::Gt),
+            "$ge" => Some(ir::OpPrim::Ge),
+            _ => None,
+        };
+        let op = cell.ty.as_str();
+        if is_reg(cell) {
+            self.add_reg(cell, dst)
+        } else if let Some((op, not)) = binop(op) {
+            let a = self.operand_try_from_cell(cell, "A", &ty, signed)?;
+            let b = self.operand_try_from_cell(cell, "B", &ty, signed)?;
+            if not {
+                let y = self.prim(ty, op, vec![a, b]);
+                self.add_prim(dst, ir::OpPrim::Not, &[], vec![y]);
+            } else {
+                self.add_prim(dst, op, &[], vec![a, b]);
+            }
+            Ok(())
+        } else if let Some(op) = cmp(op) {
+            let a = conn_try_from_cell(cell, &["A"])?;
+            let b = conn_try_from_cell(cell, &["B"])?;
+            // unsigned operands get a zero msb to compare as signed ones
+            let width = match op {
+                ir::OpPrim::Eql | ir::OpPrim::Neql => a.len().max(b.len()),
+                _ if signed => a.len().max(b.len()).max(2),
+                _ => a.len().max(b.len()) + 1,
+            };
+            let ty = ty_from_width(width as u64);
+            let a = self.term_try_from_bits(&resize(a, width, signed), &ty)?;
+            let b = self.term_try_from_bits(&resize(b, width, signed), &ty)?;
+            self.add_bool_prim(dst, op, vec![a, b])
+        } else {
+            match op {
+                "$_ANDNOT_" | "$_ORNOT_" => {
+                    let a = self.operand_try_from_cell(cell, "A", &ty, false)?;
+                    let b = self.operand_try_from_cell(cell, "B", &ty, false)?;
+                    let b = self.prim(ty, ir::OpPrim::Not, vec![b]);
+                    let op = if op == "$_ANDNOT_" {
+                        ir::OpPrim::And
+                    } else {
+                        ir::OpPrim::Or
+                    };
+                    self.add_prim(dst, op, &[], vec![a, b]);
+                }
+                "$not" | "$_NOT_" => {
+                    let a = self.operand_try_from_cell(cell, "A", &ty, signed)?;
+                    self.add_prim(dst, ir::OpPrim::Not, &[], vec![a]);
+                }
+                "$pos" | "$_BUF_" => {
+                    let a = self.operand_try_from_cell(cell, "A", &ty, signed)?;
+                    self.add_wire(dst, ir::OpWire::Id, &[], vec![a]);
+                }
+                "$neg" => {
+                    let a = self.operand_try_from_cell(cell, "A", &ty, signed)?;
+                    let width = ty.width().unwrap_or(1) as usize;
+                    let zero = self.term_try_from_bits(&vec![Bit::Zero; width], &ty)?;
+                    self.add_prim(dst, ir::OpPrim::Sub, &[], vec![zero, a]);
+                }
+                "$reduce_or" | "$reduce_bool" | "$logic_not" | "$reduce_and" => {
+                    let (op, ones) = match op {
+                        "$logic_not" => (ir::OpPrim::Eql, false),
+                        "$reduce_and" => (ir::OpPrim::Eql, true),
+                        _ => (ir::OpPrim::Neql, false),
+                    };
+                    let (op, arg) = self.reduce_try_from_cell(cell, "A", op, ones)?;
+                    self.add_bool_prim(dst, op, arg)?;
+                }
+                "$logic_and" | "$logic_or" => {
+                    let a = self.is_nonzero_try_from_cell(cell, "A")?;
+                    let b = self.is_nonzero_try_from_cell(cell, "B")?;
+                    let op = if op == "$logic_and" {
+                        ir::OpPrim::And
+                    } else {
+                        ir::OpPrim::Or
+                    };
+                    self.add_bool_prim(dst, op, vec![a, b])?;
+                }
+                "$reduce_xor" | "$reduce_xnor" => {
+                    let mut y: Option<ir::ExprTerm> = None;
+                    for b in conn_try_from_cell(cell, &["A"])?.iter() {
+                        let b = self.bool_try_from_bit(b)?;
+                        y = Some(match y {
+                            Some(y) => self.prim(ir::Ty::Bool, ir::OpPrim::Xor, vec![y, b]),
+                            None => b,
+                        });
+                    }
+                    let mut y = match y {
+                        Some(y) => y,
+                        None => self.bool_try_from_bit(&Bit::Zero)?,
+                    };
+                    if op == "$reduce_xnor" {
+                        y = self.prim(ir::Ty::Bool, ir::OpPrim::Not, vec![y]);
+                    }
+                    self.add_bool(dst, y)?;
+                }
+                "$shl" | "$sshl" | "$shr" | "$sshr" => {
+                    let a_signed = flag_from_cell(cell, "A_SIGNED", false);
+                    let shift = value_try_from_bits(cell, conn_try_from_cell(cell, &["B"])?)?;
+                    let op = match op {
+                        "$shl" | "$sshl" => ir::OpWire::Sll,
+                        "$sshr" if a_signed => ir::OpWire::Sra,
+                        _ => ir::OpWire::Srl,
+                    };
+                    let a = conn_try_from_cell(cell, &["A"])?;
+                    let width = ty.width().unwrap_or(1);
+                    // shift before truncating a wider operand
+                    if (a.len() as u64) > width {
+                        let wide = ty_from_width(a.len() as u64);
+                        let a = self.term_try_from_bits(a, &wide)?;
+                        let y = self.wire(wide, op, &[shift], vec![a]);
+                        self.add_wire(dst, ir::OpWire::Ext, &[0], vec![y]);
+                    } else {
+                        let a = resize(a, width as usize, a_signed);
+                        let a = self.term_try_from_bits(&a, &ty)?;
+                        self.add_wire(dst, op, &[shift], vec![a]);
+                    }
+                }
+                "$mux" | "$_MUX_" => {
+                    let a = self.operand_try_from_cell(cell, "A", &ty, false)?;
+                    let b = self.operand_try_from_cell(cell, "B", &ty, false)?;
+                    let s = self.bool_try_from_bit(&conn_try_from_cell(cell, &["S"])?[0])?;
+                    self.add_prim(dst, ir::OpPrim::Mux, &[], vec![s, b, a]);
+                }
+                // the last select that is set wins
+                "$pmux" => {
+                    let width = ty.width().unwrap_or(1) as usize;
+                    let mut y = self.operand_try_from_cell(cell, "A", &ty, false)?;
+                    let b = conn_try_from_cell(cell, &["B"])?.clone();
+                    let s = conn_try_from_cell(cell, &["S"])?.clone();
+                    for (i, s) in s.iter().enumerate() {
+                        let bits = resize(&b[(i * width).min(b.len())..], width, false);
+                        let b = self.term_try_from_bits(&bits, &ty)?;
+                        let s = self.bool_try_from_bit(s)?;
+                        y = self.prim(ty.clone(), ir::OpPrim::Mux, vec![s, b, y]);
+                    }
+                    self.add_wire(dst, ir::OpWire::Id, &[], vec![y]);
+                }
+                "$sop" => {
+                    let a = conn_try_from_cell(cell, &["A"])?.clone();
+                    let depth = cell.param("DEPTH").map_or(0, |p| p.value()) as usize;
+                    let table = match cell.param("TABLE") {
+                        Some(Param::Bits(table)) => table.chars().rev().collect::<Vec<char>>(),
+                        _ => Vec::new(),
+                    };
+                    let is_set = |i: usize| table.get(i) == Some(&'1');
+                    let mut cube: Vec<Vec<(usize, bool)>> = Vec::new();
+                    for i in 0..depth {
+                        let mut c: Vec<(usize, bool)> = Vec::new();
+                        for j in 0..a.len() {
+                            let k = 2 * (i * a.len() + j);
+                            if is_set(k) {
+                                c.push((j, false));
+                            }
+                            if is_set(k + 1) {
+                                c.push((j, true));
+                            }
+                        }
+                        cube.push(c);
+                    }
+                    let y = self.sop_try_from_cubes(&a, &cube)?;
+                    self.add_bool(dst, y)?;
+                }
+                "$lut" => {
+                    let a = conn_try_from_cell(cell, &["A"])?.clone();
+                    let lut = cell.param("LUT").map_or(0, |p| p.value());
+                    let cube: Vec<Vec<(usize, bool)>> = (0..1_usize << a.len().min(6))
+                        .filter(|m| (lut >> m) & 1 == 1)
+                        .map(|m| (0..a.len()).map(|j| (j, (m >> j) & 1 == 1)).collect())
+                        .collect();
+                    let y = self.sop_try_from_cubes(&a, &cube)?;
+                    self.add_bool(dst, y)?;
+                }
+                _ => {
+                    let err = format!("{} cells are not supported", cell.ty);
+                    return Err(Error::new_yosys_error(&err));
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// ir program with the logic of module as main. The clock and reset of
+/// registers become the implicit ones of ir, and registers without a
+/// reset value start from zero.
+pub fn try_from_module(module: &Module) -> Result<ir::Prog, Error> {
+    let mut clock: HashSet<&Id> = HashSet::new();
+    let mut reset: HashSet<&Id> = HashSet::new();
+    for cell in module.cell.iter().filter(|c| is_reg(c)) {
+        for (port, set) in [(CLOCK, &mut clock), (RESET, &mut reset)].iter_mut() {
+            if let Some(bits) = conn_from_cell(cell, port) {
+                for b in bits.iter() {
+                    if let Bit::Net(n) = b {
+                        set.insert(n);
+                    }
+                }
+            }
+        }
+    }
+    if clock.len() > 1 || reset.len() > 1 {
+        let err = format!("{} must have only one clock and reset", module.id);
+        return Err(Error::new_yosys_error(&err));
+    }
+    let is_implicit = |port: &Port| match port.bits.as_slice() {
+        [Bit::Net(n)] => clock.contains(n) || reset.contains(n),
+        _ => false,
+    };
+    let mut builder = Builder::default();
+    let mut input: Vec<ir::ExprTerm> = Vec::new();
+    let mut output: Vec<(ir::ExprTerm, &Port)> = Vec::new();
+    for port in module.port.iter() {
+        if port.dir == Dir::Input && is_implicit(port) {
+            continue;
+        }
+        let ty = ty_from_width(port.bits.len() as u64);
+        let term = builder.add_var(&id_from_name(&port.id), ty)?;
+        if port.dir == Dir::Input {
+            builder.define(&term, &port.bits);
+            input.push(term);
+        } else {
+            output.push((term, port));
+        }
+    }
+    // every cell output is a var before any of them is used
+    let mut dst: Vec<(ir::ExprTerm, &Cell)> = Vec::new();
+    for cell in module.cell.iter() {
+        let bits = output_try_from_cell(cell)?;
+        let ty = ty_from_width(bits.len() as u64);
+        let term = builder.new_var(ty);
+        builder.define(&term, bits);
+        dst.push((term, cell));
+    }
+    for (term, cell) in dst.iter() {
+        builder.add_cell(cell, term)?;
+    }
+    for (term, port) in output.iter() {
+        let ty = term.get_ty()?.clone();
+        let y = builder.term_try_from_bits(&port.bits, &ty)?;
+        builder.add_wire(term, ir::OpWire::Id, &[], vec![y]);
+    }
+    let sig = ir::Sig {
+        id: "main".to_string(),
+        input: tup_from_terms(input),
+        output: tup_from_terms(output.into_iter().map(|(t, _)| t).collect()),
+    };
+    let mut def = ir::Def::default();
+    def.set_sig(sig);
+    def.set_body(builder.body);
+    def.sort_body()?;
+    let mut prog = ir::Prog::default();
+    prog.insert("main", def);
+    Ok(prog)
+}
@@ -0,0 +1,39 @@
+use ir::parser::Parser as IrParser;
+use std::path::Path;
+use tb::stimulus::Stimulus;
+use tb::trace::try_from_ir_prog;
+use yosys::ast::Module;
+use yosys::errors::Error;
+use yosys::{blif, json, try_from_module};
+
+// imported netlists must behave like the ir program they were made from
+fn test(module: &Module, name: &str) -> Result<(), Error> {
+    let mut path = Path::new("../../../examples/ir").join(name);
+    path.set_extension("ir");
+    let res = try_from_module(module)?;
+    let exp = IrParser::parse_from_file(path)?;
+    let sig = exp.get("main").unwrap().sig();
+    let s = Stimulus::new_random(sig, 32, 0).unwrap();
+    let r = try_from_ir_prog(&res, &s).unwrap();
+    let e = try_from_ir_prog(&exp, &s).unwrap();
+    assert_eq!(r.cycle(), e.cycle());
+    Ok(())
+}
+
+#[test]
+fn add_json() -> Result<(), Error> {
+    let module = json::Parser::parse_from_file("../../../examples/yosys/add.json")?;
+    test(&module, "add")
+}
+
+#[test]
+fn add_blif() -> Result<(), Error> {
+    let module = blif::Parser::parse_from_file("../../../examples/yosys/add.blif")?;
+    test(&module, "add")
+}
+
+#[test]
+fn cells_blif() -> Result<(), Error> {
+    let module = blif::Parser::parse_from_file("../../../examples/yosys/cells.blif")?;
+    test(&module, "cells")
+}